@@ -1,8 +1,12 @@
-use crate::{error::AnyError, history::DBHistory, project::discover_project};
+use crate::{
+    error::AnyError,
+    history::{DBHistory, Version},
+    project::discover_project,
+};
 use colored::Colorize;
 use postgres::{Client, NoTls};
 
-pub fn down(profile_name: &str, version: Option<&str>) -> Result<(), AnyError> {
+pub fn down(profile_name: &str, version: Option<&str>, force: bool) -> Result<(), AnyError> {
     let cwd = std::env::current_dir()?;
     let project = discover_project(cwd.clone())?;
 
@@ -36,10 +40,16 @@ pub fn down(profile_name: &str, version: Option<&str>) -> Result<(), AnyError> {
         return Ok(());
     }
 
-    for name in versions {
-        let v = dbh.get_version(&mut tx, &name).unwrap();
+    // nothing is reverted unless every version in the range can be
+    let versions = versions
+        .iter()
+        .map(|name| dbh.get_version(&mut tx, name))
+        .collect::<Result<Vec<Version>, _>>()?;
+    ensure_reversible(&versions, force)?;
+
+    for v in versions {
         println!("  Reverting version {}", v.name.green());
-        for stmt in &v.down {
+        for stmt in v.get_down() {
             tx.execute(stmt, &[]).unwrap();
             println!("    {}", stmt.dimmed());
         }
@@ -50,3 +60,39 @@ pub fn down(profile_name: &str, version: Option<&str>) -> Result<(), AnyError> {
 
     Ok(())
 }
+
+fn ensure_reversible(versions: &[Version], force: bool) -> Result<(), AnyError> {
+    if force {
+        return Ok(());
+    }
+    match versions.iter().find(|v| !v.is_reversible()) {
+        Some(v) => Err(format!(
+            "version \"{}\" is irreversible, give it a custom_down or pass --force to revert it anyway",
+            v.name
+        )
+        .into()),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ensure_reversible;
+    use crate::{history::Version, snapshot::changes::Hazard};
+
+    #[test]
+    fn test_down_refuses_irreversible_versions() {
+        let reversible = Version::new_stage("init");
+        let mut irreversible = Version::new_stage("init");
+        irreversible.add_hazards(&[Hazard::Irreversible]);
+        let versions = vec![reversible, irreversible];
+
+        let err = ensure_reversible(&versions, false).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "version \"stage\" is irreversible, give it a custom_down or pass --force to revert it anyway"
+        );
+        assert!(ensure_reversible(&versions, true).is_ok());
+        assert!(ensure_reversible(&versions[..1], false).is_ok());
+    }
+}
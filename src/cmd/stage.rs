@@ -1,11 +1,16 @@
 use crate::definition::load_snpashot;
 use crate::error::AnyError;
 use crate::history::{calculate_snapshot, FSHistory, STAGE};
+use crate::introspection::introspect_cast_is_implicit;
 use crate::project::discover_project;
-use crate::snapshot::compare_diff;
+use crate::snapshot::changes::{Change, Hazard};
+use crate::snapshot::{compare_diff, propose_renames, Database};
 use colored::Colorize;
+use postgres::{Config, NoTls};
+use std::io::Write;
+use std::time::Duration;
 
-pub fn stage(profile_name: &str) -> Result<(), AnyError> {
+pub fn stage(profile_name: &str, detect_renames: bool) -> Result<(), AnyError> {
     let cwd = std::env::current_dir()?;
     let project = discover_project(cwd.clone())?;
     let history_dir = project.resolve_history_dir(profile_name)?;
@@ -15,7 +20,24 @@ pub fn stage(profile_name: &str) -> Result<(), AnyError> {
     let mut target_snapshot = load_snpashot(&definition_dir)?;
     let mut source_snapshot = calculate_snapshot(&fsh, STAGE)?;
 
+    target_snapshot.apply_ownership(
+        &mut source_snapshot,
+        project.resolve_ignore_ownership(profile_name)?,
+        project.resolve_default_owner(profile_name)?.as_deref(),
+    );
+
+    if detect_renames {
+        for rename in propose_renames(&source_snapshot, &target_snapshot) {
+            if confirm(&format!("Rename {}?", rename))? {
+                target_snapshot.add_rename(rename);
+            }
+        }
+    }
+
     let changes = compare_diff(&mut source_snapshot, &mut target_snapshot);
+    let database_url = project.resolve_database_url(profile_name)?;
+    warn_unconverted_type_changes(&database_url, &source_snapshot, &changes);
+
     let mut stage = fsh.get_version(STAGE)?;
 
     println!("Staging changes:");
@@ -23,22 +45,106 @@ pub fn stage(profile_name: &str) -> Result<(), AnyError> {
         println!("    No changes to stage.");
     }
     for change in changes {
+        let up = change.render_sql();
+        let down = change.revert(&source_snapshot).map(|c| c.render_sql());
+        let mut hazards = change.hazards();
+        // a change that cannot be reverted at all is irreversible too
+        if down.is_err() && !hazards.contains(&Hazard::Irreversible) {
+            hazards.insert(0, Hazard::Irreversible);
+        }
+
         println!(
-            "    {} {}",
-            change.render_sql().green(),
+            "    {} {}{}",
+            up.green(),
             format!("-- {}", change.display_name()).dimmed(),
+            hazards
+                .iter()
+                .map(|hazard| format!(" [{}]", hazard))
+                .collect::<String>()
+                .yellow(),
         );
 
-        let up = change.render_sql();
-        let down = change.revert(&source_snapshot).unwrap().render_sql();
         change.apply(&mut source_snapshot)?;
 
         stage.add_change(change);
         stage.add_up(&up);
-        stage.add_down(&down);
+        stage.add_hazards(&hazards);
+        if let Ok(down) = down {
+            stage.add_down(&down);
+        }
+    }
+
+    if !stage.is_reversible() {
+        println!(
+            "{}",
+            concat!(
+                "Warning: the stage holds irreversible changes. Reverting it will need ",
+                "`down --force` unless the version carries a `custom_down`."
+            )
+            .yellow()
+        );
     }
 
     fsh.save_version(stage)?;
 
     return Ok(());
 }
+
+/// Warns about type changes without a `USING` expression whose cast is not
+/// implicit, as the database would refuse them. Needs the database to look
+/// up `pg_cast`, so it connects only when there are such changes, and skips
+/// the check when the database cannot be reached within a few seconds.
+fn warn_unconverted_type_changes(database_url: &str, source: &Database, changes: &[Change]) {
+    let pending: Vec<(&str, String)> = changes
+        .iter()
+        .filter_map(|change| match change {
+            Change::AlterColumnSetDataTypeChange(c) if c.using.is_none() => {
+                let column = source
+                    .get_schema(&c.schema)
+                    .and_then(|s| s.get_relation(&c.table))
+                    .and_then(|r| r.as_table())
+                    .and_then(|t| t.get_column(&c.column))
+                    .ok()?;
+                Some((column.data_type.as_str(), c.data_type.clone()))
+            }
+            _ => None,
+        })
+        .collect();
+    if pending.is_empty() {
+        return;
+    }
+
+    let connected = database_url.parse::<Config>().and_then(|mut config| {
+        config
+            .connect_timeout(Duration::from_secs(3))
+            .connect(NoTls)
+    });
+    let Ok(mut client) = connected else {
+        println!("{}", "Could not check the casts of type changes.".dimmed());
+        return;
+    };
+    let Ok(mut tx) = client.transaction() else {
+        return;
+    };
+    for (source_type, target_type) in pending {
+        if let Ok(Some(false)) = introspect_cast_is_implicit(&mut tx, source_type, &target_type) {
+            println!(
+                "{}",
+                format!(
+                    "Warning: {} does not cast to {} implicitly, consider a `using` expression.",
+                    source_type, target_type
+                )
+                .yellow()
+            );
+        }
+    }
+}
+
+fn confirm(question: &str) -> Result<bool, AnyError> {
+    print!("{} [y/N] ", question);
+    std::io::stdout().flush()?;
+
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer)?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
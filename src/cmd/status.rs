@@ -20,10 +20,16 @@ pub fn status(profile_name: &str) -> Result<(), AnyError> {
     let database_url = project.resolve_database_url(profile_name)?;
 
     let fsh = FSHistory::from_dir(&history_dir)?;
-    let definition_snapshot = load_snpashot(&definition_dir)?;
+    let mut definition_snapshot = load_snpashot(&definition_dir)?;
 
     let fsh_stage_version = fsh.get_version(STAGE)?;
-    let fsh_stage_snapshot = calculate_snapshot(&fsh, STAGE)?;
+    let mut fsh_stage_snapshot = calculate_snapshot(&fsh, STAGE)?;
+
+    definition_snapshot.apply_ownership(
+        &mut fsh_stage_snapshot,
+        project.resolve_ignore_ownership(profile_name)?,
+        project.resolve_default_owner(profile_name)?.as_deref(),
+    );
 
     let metadata_schema = project.resolve_metadata_schema(&profile_name)?;
     let db_version_result = try_get_db_current_version(&database_url, &metadata_schema);
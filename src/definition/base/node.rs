@@ -25,7 +25,11 @@ impl Node {
         }
     }
     pub fn get_name(&self) -> Option<String> {
-        return self.name.clone();
+        return Some(split_rename_hint(self.name.as_ref()?).0.to_string());
+    }
+    // from an inline `new_name (was old_name)` hint
+    pub fn get_renamed_from(&self) -> Option<String> {
+        return Some(split_rename_hint(self.name.as_ref()?).1?.to_string());
     }
     pub fn get_nodes(&self) -> Option<&Vec<Node>> {
         if self.child.is_none() {
@@ -89,6 +93,18 @@ impl Node {
     }
 }
 
+fn split_rename_hint(name: &str) -> (&str, Option<&str>) {
+    if let Some((new_name, old_name)) = name
+        .strip_suffix(')')
+        .and_then(|rest| rest.rsplit_once("(was "))
+    {
+        if new_name.ends_with(' ') {
+            return (new_name.trim_end(), Some(old_name.trim()));
+        }
+    }
+    (name, None)
+}
+
 #[derive(PartialEq, Debug)]
 pub enum NodeChild {
     Value(Value),
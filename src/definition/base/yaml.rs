@@ -5,40 +5,83 @@ use super::{
     error::Error,
 };
 
-static KEYWORDS: [&str; 33] = [
+static KEYWORDS: [&str; 76] = [
+    "default privileges on",
+    "force row level security",
     "foreign key",
     "initially deferred",
+    "materialized view",
     "not null",
+    "nulls not distinct",
     "on delete",
     "on update",
     "owned by",
+    "partition by",
+    "partition of",
     "primary key",
+    "renamed from",
+    "replica identity",
+    "revert using",
+    "row level security",
+    "with check",
+    "with data",
+    "arguments",
     "as",
     "cache",
     "check",
+    "collation",
+    "columns", // ahead of "column", which it starts with
     "column",
+    "comment",
+    "compression",
     "constraint",
     "cycle",
     "default",
     "deferrable",
+    "exclude",
+    "extension",
+    "for",
     "function",
+    "generated",
+    "grant",
+    "identity",
+    "include",
+    "increment",
     "volatility",
     "index",
+    "inherits",
+    "kinds",
     "language",
     "match",
     "maxvalue",
     "method",
     "minvalue",
     "on",
+    "owner",
+    "policy",
+    "procedure",
+    "publication",
     "references",
+    "refresh",
     "returns",
+    "revoke",
     "schema",
     "sequence",
     "start",
+    "statistics",
+    "storage",
+    "tablespace", // ahead of "table", which it starts with
     "table",
+    "target",
+    "to",
     "type",
     "unique",
+    "unlogged",
+    "using",
+    "version",
     "view",
+    "where",
+    "with",
 ];
 
 pub fn parse_mapping_to_nodes(val: &serde_yaml::Mapping) -> Result<Vec<Node>, Error> {
@@ -23,6 +23,7 @@ pub fn parse_check_constraint_definition(
     let mut expression: Option<String> = None;
     let mut deferrable: Option<bool> = None;
     let mut initially_deferred: Option<bool> = None;
+    let mut comment: Option<String> = None;
 
     for cn in child_nodes {
         if let Err(e) = match cn.r#type {
@@ -35,6 +36,8 @@ pub fn parse_check_constraint_definition(
             "initially deferred" => {
                 coerce_boolean_value(cn).and_then(|v| Ok(initially_deferred = Some(v)))
             }
+            "comment" => coerce_string_value(cn).and_then(|v| Ok(comment = Some(v))),
+            "renamed from" => coerce_string_value(cn).and_then(|_| Ok(())),
             _ => Err(Error::new_unexpected_node(cn)),
         } {
             errors.push(e);
@@ -50,6 +53,9 @@ pub fn parse_check_constraint_definition(
     if initially_deferred.is_none() {
         initially_deferred = Some(defaults::get_constraint_initially_deferred());
     }
+    if comment.is_none() {
+        comment = Some(defaults::get_default_description());
+    }
 
     if !errors.is_empty() {
         return Err(Error::new_has_errors(n, errors));
@@ -62,6 +68,7 @@ pub fn parse_check_constraint_definition(
         expression: expression.unwrap(),
         deferrable: deferrable.unwrap(),
         initially_deferred: initially_deferred.unwrap(),
+        comment: comment.unwrap(),
     })
 }
 
@@ -78,6 +85,9 @@ pub fn render_check_constraint_definition(ctx: &Context, def: &Check) -> Node {
         let subnode = Node::new("initially deferred").with_boolean_value(def.initially_deferred);
         subnodes.push(subnode);
     }
+    if def.comment != defaults::get_default_description() {
+        subnodes.push(Node::new("comment").with_string_value(def.comment.clone()));
+    }
 
     return Node::new("constraint")
         .with_name(def.name.clone())
@@ -104,6 +114,7 @@ mod test {
             expression: "a > 0".to_owned(),
             deferrable: true,
             initially_deferred: false,
+            comment: defaults::get_default_description(),
         };
         let ctx = Context {
             schema_name: "public".to_owned(),
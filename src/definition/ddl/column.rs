@@ -1,35 +1,360 @@
 use crate::{
-    definition::{base::Node, ddl::coerce::coerce_string_value},
-    snapshot::Column,
+    definition::{
+        base::Node,
+        ddl::coerce::{
+            coerce_boolean_value, coerce_integer_value, coerce_name_and_string_value,
+            coerce_nodes, coerce_string_value,
+        },
+        defaults,
+    },
+    snapshot::{insert_grant, Column, Grant, Identity},
 };
 
-use super::{coerce::coerce_name, context::Context, error::Error};
+use super::{
+    coerce::coerce_name,
+    context::Context,
+    error::Error,
+    grant::{
+        parse_grant_definition, parse_revoke_definition, render_grant_definitions,
+        render_revoke_definitions,
+    },
+    serial::{get_serial_default, get_serial_sequence_name, parse_serial_type},
+};
+use crate::util::sqlfmt::sql_collation;
 use regex::Regex;
 
 pub fn parse_column_definition(schema: &str, table: &str, n: &Node) -> Result<Column, Error> {
     assert_eq!(n.r#type, "column");
 
     let name = coerce_name(n)?;
-    let type_expr_str = coerce_string_value(n)?;
-    let (data_type, not_null, default) = parse_column_expression(&type_expr_str)
+
+    // `column <name>: <type expression>` is a shorthand for a column with
+    // nothing but a `type` attribute.
+    let mut type_expr_str: Option<String> = n.get_string_value();
+    let mut generated: Option<String> = None;
+    let mut identity_node: Option<&Node> = None;
+    let mut collation: Option<String> = None;
+    let mut storage: Option<String> = None;
+    let mut compression: Option<String> = None;
+    let mut statistics: Option<i64> = None;
+    let mut comment: Option<String> = None;
+    let mut grants: Vec<Grant> = vec![];
+    let mut revokes: Vec<Grant> = vec![];
+    let mut using: Option<String> = None;
+    let mut using_from: Option<String> = None;
+    let mut revert_using: Option<String> = None;
+
+    if type_expr_str.is_none() {
+        let child_nodes = coerce_nodes(n)?;
+        let mut errors: Vec<Error> = Vec::new();
+
+        for cn in child_nodes {
+            if let Err(e) = match cn.r#type {
+                "type" => coerce_string_value(cn).and_then(|v| Ok(type_expr_str = Some(v))),
+                "generated" => coerce_string_value(cn).and_then(|v| Ok(generated = Some(v))),
+                "identity" => Ok(identity_node = Some(cn)),
+                "collation" => coerce_string_value(cn).and_then(|v| Ok(collation = Some(v))),
+                "storage" => coerce_string_value(cn).and_then(|v| {
+                    let v = v.to_lowercase();
+                    match v.as_str() {
+                        "plain" | "external" | "extended" | "main" => Ok(storage = Some(v)),
+                        _ => Err(Error::new_unexpected_node(cn)),
+                    }
+                }),
+                "compression" => coerce_string_value(cn).and_then(|v| {
+                    let v = v.to_lowercase();
+                    match v.as_str() {
+                        "pglz" | "lz4" => Ok(compression = Some(v)),
+                        _ => Err(Error::new_unexpected_node(cn)),
+                    }
+                }),
+                "statistics" => coerce_integer_value(cn).and_then(|v| Ok(statistics = Some(v))),
+                "comment" => coerce_string_value(cn).and_then(|v| Ok(comment = Some(v))),
+                "renamed from" => coerce_string_value(cn).and_then(|_| Ok(())),
+                // `using <type>: ...`, keyed to the type it converts from
+                "using" => coerce_name_and_string_value(cn).and_then(|(from, v)| {
+                    using_from = Some(from);
+                    Ok(using = Some(v))
+                }),
+                "revert using" => coerce_string_value(cn).and_then(|v| Ok(revert_using = Some(v))),
+                "grant" => parse_grant_definition("column", cn).and_then(|v| {
+                    for grant in v {
+                        insert_grant(&mut grants, grant);
+                    }
+                    Ok(())
+                }),
+                "revoke" => parse_revoke_definition("column", cn).and_then(|v| {
+                    for revoke in v {
+                        insert_grant(&mut revokes, revoke);
+                    }
+                    Ok(())
+                }),
+                _ => Err(Error::new_unexpected_node(cn)),
+            } {
+                errors.push(e);
+            }
+        }
+
+        if type_expr_str.is_none() {
+            errors.push(Error::new_attribute_required(n, "type"));
+        }
+
+        if !errors.is_empty() {
+            return Err(Error::new_has_errors(n, errors));
+        }
+    }
+    if comment.is_none() {
+        comment = Some(defaults::get_default_description());
+    }
+
+    let (data_type, mut not_null, mut default) = parse_column_expression(type_expr_str.as_ref().unwrap())
         .or_else(|err| Err(Error::new_has_errors(n, vec![err])))?;
+    let (data_type, expr_collation) = split_collation(&data_type);
+    if expr_collation.is_some() {
+        collation = expr_collation;
+    }
+
+    // serial columns take their values from a sequence of their own, which
+    // parse_serial_sequence_definitions adds next to the table
+    let data_type = match parse_serial_type(&data_type) {
+        Some(serial_type) => {
+            if default.is_some() {
+                return Err(Error::new_has_errors(
+                    n,
+                    vec![Error::new_invalid_type_expression(type_expr_str.unwrap())],
+                ));
+            }
+            not_null = true;
+            default = Some(get_serial_default(
+                schema,
+                &get_serial_sequence_name(table, &name),
+            ));
+            serial_type.to_string()
+        }
+        None => data_type,
+    };
+
+    // identity columns are implicitly NOT NULL
+    let identity = match identity_node {
+        Some(cn) => {
+            not_null = true;
+            Some(
+                parse_identity_definition(&data_type, cn)
+                    .or_else(|err| Err(Error::new_has_errors(n, vec![err])))?,
+            )
+        }
+        None => None,
+    };
 
     return Ok(Column {
         schema_name: schema.to_owned(),
         table_name: table.to_owned(),
         name,
         data_type,
+        collation,
         not_null,
         default,
+        generated,
+        identity,
+        storage,
+        compression,
+        statistics,
+        comment: comment.unwrap(),
+        grants,
+        revokes,
+        using,
+        using_from,
+        revert_using,
     });
 }
 
 pub fn render_column_definition(ctx: &Context, def: &Column) -> Node {
-    let type_expr_str = render_column_expression(&def.data_type, def.not_null, &def.default);
+    // NOT NULL goes without saying on identity columns
+    let not_null = def.not_null && def.identity.is_none();
+    let data_type = match &def.collation {
+        Some(collation) => format!("{} COLLATE {}", def.data_type, sql_collation(collation)),
+        None => def.data_type.clone(),
+    };
+    let type_expr_str = render_column_expression(&data_type, not_null, &def.default);
+
+    if def.generated.is_none()
+        && def.identity.is_none()
+        && def.storage.is_none()
+        && def.compression.is_none()
+        && def.statistics.is_none()
+        && def.comment == defaults::get_default_description()
+        && def.grants.is_empty()
+        && def.revokes.is_empty()
+        && def.using.is_none()
+        && def.revert_using.is_none()
+    {
+        return Node::new("column")
+            .with_name(def.name.clone())
+            .with_string_value(type_expr_str);
+    }
+
+    let mut subnodes: Vec<Node> = Vec::new();
+
+    subnodes.push(Node::new("type").with_string_value(type_expr_str));
+    if let Some(generated) = &def.generated {
+        subnodes.push(Node::new("generated").with_string_value(generated.clone()));
+    }
+    if let Some(identity) = &def.identity {
+        subnodes.push(render_identity_definition(&def.data_type, identity));
+    }
+    if let Some(storage) = &def.storage {
+        subnodes.push(Node::new("storage").with_string_value(storage.clone()));
+    }
+    if let Some(compression) = &def.compression {
+        subnodes.push(Node::new("compression").with_string_value(compression.clone()));
+    }
+    if let Some(statistics) = def.statistics {
+        subnodes.push(Node::new("statistics").with_number_integer_value(statistics));
+    }
+    if def.comment != defaults::get_default_description() {
+        subnodes.push(Node::new("comment").with_string_value(def.comment.clone()));
+    }
+    subnodes.extend(render_grant_definitions("column", &def.grants));
+    subnodes.extend(render_revoke_definitions("column", &def.revokes));
+    if let (Some(using), Some(using_from)) = (&def.using, &def.using_from) {
+        subnodes.push(
+            Node::new("using")
+                .with_name(using_from.clone())
+                .with_string_value(using.clone()),
+        );
+    }
+    if let Some(revert_using) = &def.revert_using {
+        subnodes.push(Node::new("revert using").with_string_value(revert_using.clone()));
+    }
 
     return Node::new("column")
         .with_name(def.name.clone())
-        .with_string_value(type_expr_str);
+        .with_nodes(subnodes);
+}
+
+// `identity: always` or `identity: by default`, with the sequence options
+// spelled out in the mapping form:
+//
+//     identity:
+//         generated: by default
+//         start: 1000
+fn parse_identity_definition(data_type: &str, n: &Node) -> Result<Identity, Error> {
+    assert_eq!(n.r#type, "identity");
+
+    let mut generated: Option<String> = n.get_string_value();
+    let mut increment: Option<i64> = None;
+    let mut min_value: Option<i64> = None;
+    let mut max_value: Option<i64> = None;
+    let mut start: Option<i64> = None;
+    let mut cache: Option<i64> = None;
+    let mut cycle: Option<bool> = None;
+
+    if generated.is_none() {
+        let child_nodes = coerce_nodes(n)?;
+        let mut errors: Vec<Error> = Vec::new();
+
+        for cn in child_nodes {
+            if let Err(e) = match cn.r#type {
+                "generated" => coerce_string_value(cn).and_then(|v| Ok(generated = Some(v))),
+                "increment" => coerce_integer_value(cn).and_then(|v| Ok(increment = Some(v))),
+                "minvalue" => coerce_integer_value(cn).and_then(|v| Ok(min_value = Some(v))),
+                "maxvalue" => coerce_integer_value(cn).and_then(|v| Ok(max_value = Some(v))),
+                "start" => coerce_integer_value(cn).and_then(|v| Ok(start = Some(v))),
+                "cache" => coerce_integer_value(cn).and_then(|v| Ok(cache = Some(v))),
+                "cycle" => coerce_boolean_value(cn).and_then(|v| Ok(cycle = Some(v))),
+                _ => Err(Error::new_unexpected_node(cn)),
+            } {
+                errors.push(e);
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(Error::new_has_errors(n, errors));
+        }
+    }
+
+    let always = match generated.as_deref().map(|v| v.to_lowercase()).as_deref() {
+        Some("always") | None => true,
+        Some("by default") => false,
+        _ => return Err(Error::new_unexpected_node(n)),
+    };
+
+    let sequence_type = defaults::get_identity_data_type(data_type);
+    let increment = increment.unwrap_or_else(defaults::get_sequence_increment);
+    let min_value =
+        min_value.unwrap_or_else(|| defaults::get_sequence_min_value(increment, &sequence_type));
+    let max_value =
+        max_value.unwrap_or_else(|| defaults::get_sequence_max_value(increment, &sequence_type));
+
+    Ok(Identity {
+        always,
+        increment,
+        min_value,
+        max_value,
+        start: start
+            .unwrap_or_else(|| defaults::get_sequence_start(increment, min_value, max_value)),
+        cache: cache.unwrap_or_else(defaults::get_sequence_cache),
+        cycle: cycle.unwrap_or_else(defaults::get_sequence_cycle),
+    })
+}
+
+fn render_identity_definition(data_type: &str, def: &Identity) -> Node {
+    let generated = if def.always { "always" } else { "by default" }.to_string();
+
+    let sequence_type = defaults::get_identity_data_type(data_type);
+    let default_min_value = defaults::get_sequence_min_value(def.increment, &sequence_type);
+    let default_max_value = defaults::get_sequence_max_value(def.increment, &sequence_type);
+
+    let mut subnodes: Vec<Node> = Vec::new();
+    if def.increment != defaults::get_sequence_increment() {
+        subnodes.push(Node::new("increment").with_number_integer_value(def.increment));
+    }
+    if def.min_value != default_min_value {
+        subnodes.push(Node::new("minvalue").with_number_integer_value(def.min_value));
+    }
+    if def.max_value != default_max_value {
+        subnodes.push(Node::new("maxvalue").with_number_integer_value(def.max_value));
+    }
+    if def.start != defaults::get_sequence_start(def.increment, def.min_value, def.max_value) {
+        subnodes.push(Node::new("start").with_number_integer_value(def.start));
+    }
+    if def.cache != defaults::get_sequence_cache() {
+        subnodes.push(Node::new("cache").with_number_integer_value(def.cache));
+    }
+    if def.cycle != defaults::get_sequence_cycle() {
+        subnodes.push(Node::new("cycle").with_boolean_value(def.cycle));
+    }
+
+    if subnodes.is_empty() {
+        return Node::new("identity").with_string_value(generated);
+    }
+    subnodes.insert(0, Node::new("generated").with_string_value(generated));
+    Node::new("identity").with_nodes(subnodes)
+}
+
+// `text COLLATE "und-x-icu"`, the collation being kept unquoted
+fn split_collation(data_type: &str) -> (String, Option<String>) {
+    let re = Regex::new(r"(?i)^(?P<type>.+?)\s+collate\s+(?P<collation>.+)$").unwrap();
+    match re.captures(data_type) {
+        Some(caps) => (
+            caps.name("type").unwrap().as_str().to_string(),
+            Some(caps.name("collation").unwrap().as_str().replace('"', "")),
+        ),
+        None => (data_type.to_string(), None),
+    }
+}
+
+/// The integer type of a serial column node, if it is one.
+pub fn parse_column_serial_type(n: &Node) -> Option<&'static str> {
+    let type_expr_str = n.get_string_value().or_else(|| {
+        n.get_nodes()?
+            .iter()
+            .find(|cn| cn.r#type == "type")
+            .and_then(|cn| cn.get_string_value())
+    })?;
+    let (data_type, _, _) = parse_column_expression(&type_expr_str).ok()?;
+    let (data_type, _) = split_collation(&data_type);
+    parse_serial_type(&data_type)
 }
 
 fn parse_column_expression(input: &str) -> Result<(String, bool, Option<String>), Error> {
@@ -121,8 +446,196 @@ mod tests {
             table_name: "user".to_owned(),
             name: "id".to_owned(),
             data_type: "integer".to_owned(),
+            collation: None,
+            default: None,
+            not_null: false,
+            generated: None,
+            identity: None,
+            storage: None,
+            compression: None,
+            statistics: None,
+            comment: defaults::get_default_description(),
+            grants: vec![],
+            revokes: vec![],
+            using: None,
+            using_from: None,
+            revert_using: None,
+        };
+        let ctx = Context {
+            schema_name: "public".to_owned(),
+            table_name: Some("user".to_owned()),
+        };
+
+        let got_def = parse_column_definition("public", "user", &node).unwrap();
+        assert_eq!(got_def, def);
+
+        let got_node = render_column_definition(&ctx, &def);
+        assert_eq!(got_node, node);
+    }
+
+    #[test]
+    fn test_parse_and_render_commented_column_definition() {
+        let node = Node::new("column")
+            .with_name("email".to_owned())
+            .with_nodes(vec![
+                Node::new("type").with_string_value("text!".to_owned()),
+                Node::new("comment").with_string_value("Login address".to_owned()),
+            ]);
+        let def = Column {
+            schema_name: "public".to_owned(),
+            table_name: "user".to_owned(),
+            name: "email".to_owned(),
+            data_type: "text".to_owned(),
+            collation: None,
+            default: None,
+            not_null: true,
+            generated: None,
+            identity: None,
+            storage: None,
+            compression: None,
+            statistics: None,
+            comment: "Login address".to_owned(),
+            grants: vec![],
+            revokes: vec![],
+            using: None,
+            using_from: None,
+            revert_using: None,
+        };
+        let ctx = Context {
+            schema_name: "public".to_owned(),
+            table_name: Some("user".to_owned()),
+        };
+
+        let got_def = parse_column_definition("public", "user", &node).unwrap();
+        assert_eq!(got_def, def);
+
+        let got_node = render_column_definition(&ctx, &def);
+        assert_eq!(got_node, node);
+    }
+
+    #[test]
+    fn test_parse_and_render_identity_column_definition() {
+        let node = Node::new("column")
+            .with_name("id".to_owned())
+            .with_nodes(vec![
+                Node::new("type").with_string_value("int4".to_owned()),
+                Node::new("identity").with_nodes(vec![
+                    Node::new("generated").with_string_value("by default".to_owned()),
+                    Node::new("start").with_number_integer_value(1000),
+                ]),
+            ]);
+        let def = Column {
+            schema_name: "public".to_owned(),
+            table_name: "user".to_owned(),
+            name: "id".to_owned(),
+            data_type: "int4".to_owned(),
+            collation: None,
+            default: None,
+            not_null: true,
+            generated: None,
+            identity: Some(Identity {
+                always: false,
+                increment: 1,
+                min_value: 1,
+                max_value: 2147483647,
+                start: 1000,
+                cache: 1,
+                cycle: false,
+            }),
+            storage: None,
+            compression: None,
+            statistics: None,
+            comment: defaults::get_default_description(),
+            grants: vec![],
+            revokes: vec![],
+            using: None,
+            using_from: None,
+            revert_using: None,
+        };
+        let ctx = Context {
+            schema_name: "public".to_owned(),
+            table_name: Some("user".to_owned()),
+        };
+
+        let got_def = parse_column_definition("public", "user", &node).unwrap();
+        assert_eq!(got_def, def);
+
+        let got_node = render_column_definition(&ctx, &def);
+        assert_eq!(got_node, node);
+    }
+
+    #[test]
+    fn test_parse_and_render_column_storage_settings() {
+        let node = Node::new("column")
+            .with_name("body".to_owned())
+            .with_nodes(vec![
+                Node::new("type").with_string_value("text COLLATE \"und-x-icu\"!".to_owned()),
+                Node::new("storage").with_string_value("external".to_owned()),
+                Node::new("compression").with_string_value("lz4".to_owned()),
+                Node::new("statistics").with_number_integer_value(500),
+            ]);
+        let def = Column {
+            schema_name: "public".to_owned(),
+            table_name: "document".to_owned(),
+            name: "body".to_owned(),
+            data_type: "text".to_owned(),
+            collation: Some("und-x-icu".to_owned()),
+            default: None,
+            not_null: true,
+            generated: None,
+            identity: None,
+            storage: Some("external".to_owned()),
+            compression: Some("lz4".to_owned()),
+            statistics: Some(500),
+            comment: defaults::get_default_description(),
+            grants: vec![],
+            revokes: vec![],
+            using: None,
+            using_from: None,
+            revert_using: None,
+        };
+        let ctx = Context {
+            schema_name: "public".to_owned(),
+            table_name: Some("document".to_owned()),
+        };
+
+        let got_def = parse_column_definition("public", "document", &node).unwrap();
+        assert_eq!(got_def, def);
+
+        let got_node = render_column_definition(&ctx, &def);
+        assert_eq!(got_node, node);
+    }
+
+    #[test]
+    fn test_parse_and_render_column_conversion() {
+        let node = Node::new("column")
+            .with_name("age".to_owned())
+            .with_nodes(vec![
+                Node::new("type").with_string_value("int4".to_owned()),
+                Node::new("using")
+                    .with_name("text".to_owned())
+                    .with_string_value("age::int4".to_owned()),
+                Node::new("revert using").with_string_value("age::text".to_owned()),
+            ]);
+        let def = Column {
+            schema_name: "public".to_owned(),
+            table_name: "user".to_owned(),
+            name: "age".to_owned(),
+            data_type: "int4".to_owned(),
+            collation: None,
             default: None,
             not_null: false,
+            generated: None,
+            identity: None,
+            storage: None,
+            compression: None,
+            statistics: None,
+            comment: defaults::get_default_description(),
+            grants: vec![],
+            revokes: vec![],
+            using: Some("age::int4".to_owned()),
+            using_from: Some("text".to_owned()),
+            revert_using: Some("age::text".to_owned()),
         };
         let ctx = Context {
             schema_name: "public".to_owned(),
@@ -10,6 +10,7 @@ use super::{
     check::render_check_constraint_definition,
     context::Context,
     error::Error,
+    exclusion::{parse_exclusion_definition, render_exclusion_definition},
     foreignkey::{
         parse_foreign_key_constraint_definition, render_foreign_key_constraint_definition,
     },
@@ -42,10 +43,17 @@ pub fn parse_constraint_definition(
                 let def = parse_check_constraint_definition(schema_name, table_name, n)?;
                 return Ok(Constraint::Check(def));
             }
-            _ => unreachable!(),
+            "exclude" => {
+                let def = parse_exclusion_definition(schema_name, table_name, n)?;
+                return Ok(Constraint::Exclusion(def));
+            }
+            _ => continue,
         }
     }
-    unreachable!()
+    Err(Error::new_attribute_required(
+        n,
+        "primary key, unique, foreign key, check or exclude",
+    ))
 }
 
 pub fn render_constraint_definition(ctx: &Context, c: &Constraint) -> Node {
@@ -54,5 +62,6 @@ pub fn render_constraint_definition(ctx: &Context, c: &Constraint) -> Node {
         Constraint::Unique(def) => render_unique_definition(ctx, def),
         Constraint::ForeignKey(def) => render_foreign_key_constraint_definition(&ctx, def),
         Constraint::Check(def) => render_check_constraint_definition(&ctx, def),
+        Constraint::Exclusion(def) => render_exclusion_definition(ctx, def),
     }
 }
@@ -0,0 +1,173 @@
+use crate::{
+    definition::{
+        base::Node,
+        ddl::coerce::{
+            coerce_boolean_value, coerce_name, coerce_nodes, coerce_string_value,
+            coerce_string_varargs_value,
+        },
+        defaults,
+    },
+    snapshot::{Exclusion, ExclusionElement},
+};
+
+use super::{context::Context, error::Error};
+
+pub fn parse_exclusion_definition(
+    schema_name: &str,
+    table_name: &str,
+    n: &Node,
+) -> Result<Exclusion, Error> {
+    assert_eq!(n.r#type, "constraint");
+
+    let name = coerce_name(n)?;
+    let child_nodes = coerce_nodes(n)?;
+    let mut errors: Vec<Error> = Vec::new();
+
+    let mut elements: Vec<ExclusionElement> = Vec::new();
+    let mut method: Option<String> = None;
+    let mut predicate: Option<String> = None;
+    let mut deferrable: Option<bool> = None;
+    let mut initially_deferred: Option<bool> = None;
+    let mut comment: Option<String> = None;
+
+    for cn in child_nodes {
+        if let Err(e) = match cn.r#type {
+            "exclude" => coerce_string_varargs_value(cn).and_then(|v| {
+                for element in v {
+                    elements.push(parse_exclusion_element(&element)?);
+                }
+                Ok(())
+            }),
+            "method" => coerce_string_value(cn).and_then(|v| Ok(method = Some(v))),
+            "where" => coerce_string_value(cn).and_then(|v| Ok(predicate = Some(v))),
+            "deferrable" => coerce_boolean_value(cn).and_then(|v| Ok(deferrable = Some(v))),
+            "initially deferred" => {
+                coerce_boolean_value(cn).and_then(|v| Ok(initially_deferred = Some(v)))
+            }
+            "comment" => coerce_string_value(cn).and_then(|v| Ok(comment = Some(v))),
+            "renamed from" => coerce_string_value(cn).and_then(|_| Ok(())),
+            _ => Err(Error::new_unexpected_node(cn)),
+        } {
+            errors.push(e);
+        }
+    }
+
+    if elements.is_empty() {
+        errors.push(Error::new_attribute_required(n, "exclude"));
+    }
+    if method.is_none() {
+        method = Some(defaults::get_exclusion_method());
+    }
+    if deferrable.is_none() {
+        deferrable = Some(defaults::get_constraint_deferrable());
+    }
+    if initially_deferred.is_none() {
+        initially_deferred = Some(defaults::get_constraint_initially_deferred());
+    }
+    if comment.is_none() {
+        comment = Some(defaults::get_default_description());
+    }
+
+    if !errors.is_empty() {
+        return Err(Error::new_has_errors(n, errors));
+    }
+
+    Ok(Exclusion {
+        schema_name: schema_name.to_owned(),
+        table_name: table_name.to_owned(),
+        name,
+        method: method.unwrap(),
+        elements,
+        predicate,
+        deferrable: deferrable.unwrap(),
+        initially_deferred: initially_deferred.unwrap(),
+        comment: comment.unwrap(),
+    })
+}
+
+// `room WITH =`, the operator being whatever follows the last `WITH`.
+fn parse_exclusion_element(input: &str) -> Result<ExclusionElement, Error> {
+    let lowered = input.to_lowercase();
+    match lowered.rfind(" with ") {
+        Some(pos) => Ok(ExclusionElement {
+            expression: input[..pos].trim().to_string(),
+            operator: input[pos + " with ".len()..].trim().to_string(),
+        }),
+        None => Err(Error::new_invalid_type_expression(input.to_string())),
+    }
+}
+
+pub fn render_exclusion_definition(_ctx: &Context, def: &Exclusion) -> Node {
+    let mut subnodes = vec![Node::new("exclude").with_string_varargs_value(
+        def.elements
+            .iter()
+            .map(|e| format!("{} WITH {}", e.expression, e.operator))
+            .collect(),
+    )];
+
+    if def.method != defaults::get_exclusion_method() {
+        subnodes.push(Node::new("method").with_string_value(def.method.clone()));
+    }
+    if let Some(predicate) = &def.predicate {
+        subnodes.push(Node::new("where").with_string_value(predicate.clone()));
+    }
+    if def.deferrable != defaults::get_constraint_deferrable() {
+        subnodes.push(Node::new("deferrable").with_boolean_value(def.deferrable))
+    }
+    if def.initially_deferred != defaults::get_constraint_initially_deferred() {
+        subnodes.push(Node::new("initially deferred").with_boolean_value(def.initially_deferred))
+    }
+    if def.comment != defaults::get_default_description() {
+        subnodes.push(Node::new("comment").with_string_value(def.comment.clone()));
+    }
+
+    Node::new("constraint")
+        .with_name(def.name.clone())
+        .with_nodes(subnodes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_render_exclusion_definition() {
+        let node = Node::new("constraint")
+            .with_name("booking_no_overlap".to_owned())
+            .with_nodes(vec![
+                Node::new("exclude").with_string_varargs_value(vec![
+                    "room WITH =".to_owned(),
+                    "during WITH &&".to_owned(),
+                ]),
+                Node::new("where").with_string_value("NOT cancelled".to_owned()),
+            ]);
+        let def = Exclusion {
+            schema_name: "public".to_owned(),
+            table_name: "booking".to_owned(),
+            name: "booking_no_overlap".to_owned(),
+            method: "gist".to_owned(),
+            elements: vec![
+                ExclusionElement {
+                    expression: "room".to_owned(),
+                    operator: "=".to_owned(),
+                },
+                ExclusionElement {
+                    expression: "during".to_owned(),
+                    operator: "&&".to_owned(),
+                },
+            ],
+            predicate: Some("NOT cancelled".to_owned()),
+            deferrable: false,
+            initially_deferred: false,
+            comment: defaults::get_default_description(),
+        };
+        let ctx = Context {
+            schema_name: "public".to_owned(),
+            table_name: Some("booking".to_owned()),
+        };
+        let got_def = parse_exclusion_definition("public", "booking", &node).unwrap();
+        assert_eq!(def, got_def);
+        let got_node = render_exclusion_definition(&ctx, &def);
+        assert_eq!(node, got_node);
+    }
+}
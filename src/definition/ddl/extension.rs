@@ -0,0 +1,84 @@
+use crate::{
+    definition::{
+        base::Node,
+        ddl::coerce::{coerce_name, coerce_nodes, coerce_string_value},
+        defaults,
+    },
+    snapshot::Extension,
+};
+
+use super::error::Error;
+
+pub fn parse_extension_definition(n: &Node) -> Result<Extension, Error> {
+    assert_eq!(n.r#type, "extension");
+
+    let name = coerce_name(n)?;
+    let child_nodes = coerce_nodes(n)?;
+    let mut errors: Vec<Error> = Vec::new();
+
+    let mut schema_name: Option<String> = None;
+    let mut version: Option<String> = None;
+
+    for cn in child_nodes {
+        if let Err(e) = match cn.r#type {
+            "schema" => coerce_string_value(cn).and_then(|v| Ok(schema_name = Some(v))),
+            "version" => coerce_string_value(cn).and_then(|v| Ok(version = Some(v))),
+            _ => Err(Error::new_unexpected_node(cn)),
+        } {
+            errors.push(e);
+        }
+    }
+
+    if schema_name.is_none() {
+        schema_name = Some(defaults::get_extension_schema());
+    }
+
+    if !errors.is_empty() {
+        return Err(Error::new_has_errors(n, errors));
+    }
+
+    Ok(Extension {
+        name,
+        schema_name: schema_name.unwrap(),
+        version,
+    })
+}
+
+pub fn render_extension_definition(def: &Extension) -> Node {
+    let mut subnodes: Vec<Node> = Vec::new();
+
+    if def.schema_name != defaults::get_extension_schema() {
+        subnodes.push(Node::new("schema").with_string_value(def.schema_name.clone()));
+    }
+    if let Some(version) = &def.version {
+        subnodes.push(Node::new("version").with_string_value(version.clone()));
+    }
+
+    Node::new("extension")
+        .with_name(def.name.clone())
+        .with_nodes(subnodes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_render_extension_definition() {
+        let node = Node::new("extension")
+            .with_name("pgcrypto".to_owned())
+            .with_nodes(vec![
+                Node::new("schema").with_string_value("extensions".to_owned()),
+                Node::new("version").with_string_value("1.3".to_owned()),
+            ]);
+        let def = Extension {
+            name: "pgcrypto".to_owned(),
+            schema_name: "extensions".to_owned(),
+            version: Some("1.3".to_owned()),
+        };
+        let got_def = parse_extension_definition(&node).unwrap();
+        assert_eq!(got_def, def);
+        let got_node = render_extension_definition(&def);
+        assert_eq!(got_node, node);
+    }
+}
@@ -33,6 +33,7 @@ pub fn parse_foreign_key_constraint_definition(
     let mut delete_rule: Option<String> = None;
 
     let mut errors: Vec<Error> = Vec::new();
+    let mut comment: Option<String> = None;
 
     for cn in child_nodes {
         if let Err(e) = match cn.r#type {
@@ -51,6 +52,8 @@ pub fn parse_foreign_key_constraint_definition(
             "match" => coerce_string_value(cn).and_then(|v| Ok(match_option = Some(v))),
             "on update" => coerce_string_value(cn).and_then(|v| Ok(update_rule = Some(v))),
             "on delete" => coerce_string_value(cn).and_then(|v| Ok(delete_rule = Some(v))),
+            "comment" => coerce_string_value(cn).and_then(|v| Ok(comment = Some(v))),
+            "renamed from" => coerce_string_value(cn).and_then(|_| Ok(())),
             _ => Err(Error::new_unexpected_node(cn)),
         } {
             errors.push(e);
@@ -77,6 +80,9 @@ pub fn parse_foreign_key_constraint_definition(
     if delete_rule.is_none() {
         delete_rule = Some(defaults::get_foreign_key_delete_rule());
     }
+    if comment.is_none() {
+        comment = Some(defaults::get_default_description());
+    }
 
     if !errors.is_empty() {
         return Err(Error::new_has_errors(n, errors));
@@ -95,10 +101,11 @@ pub fn parse_foreign_key_constraint_definition(
         match_option: match_option.unwrap(),
         update_rule: update_rule.unwrap(),
         delete_rule: delete_rule.unwrap(),
+        comment: comment.unwrap(),
     });
 }
 
-fn parse_table_reference(search_path: &str, input: &str) -> Result<(String, String), Error> {
+pub(super) fn parse_table_reference(search_path: &str, input: &str) -> Result<(String, String), Error> {
     let parts: Vec<&str> = input.split('.').collect();
     match parts.len() {
         1 => Ok((search_path.to_owned(), parts[0].to_owned())),
@@ -107,7 +114,7 @@ fn parse_table_reference(search_path: &str, input: &str) -> Result<(String, Stri
     }
 }
 
-fn render_table_reference(ctx: &Context, schema_name: &str, table_name: &str) -> String {
+pub(super) fn render_table_reference(ctx: &Context, schema_name: &str, table_name: &str) -> String {
     if schema_name == ctx.schema_name {
         table_name.to_owned()
     } else {
@@ -144,6 +151,9 @@ pub fn render_foreign_key_constraint_definition(ctx: &Context, def: &ForeignKey)
     if def.delete_rule != defaults::get_foreign_key_delete_rule() {
         subnodes.push(Node::new("on delete").with_string_value(def.delete_rule.clone()));
     }
+    if def.comment != defaults::get_default_description() {
+        subnodes.push(Node::new("comment").with_string_value(def.comment.clone()));
+    }
 
     return Node::new("constraint")
         .with_name(def.name.clone())
@@ -183,6 +193,7 @@ mod test {
             match_option: "full".to_owned(),
             update_rule: "cascade".to_owned(),
             delete_rule: "cascade".to_owned(),
+            comment: defaults::get_default_description(),
         };
         let ctx = Context {
             schema_name: "public".to_owned(),
@@ -4,10 +4,17 @@ use crate::{
         ddl::coerce::{coerce_name, coerce_nodes, coerce_string_value},
         defaults,
     },
-    snapshot::Function,
+    snapshot::{insert_grant, Function, Grant},
 };
 
-use super::{context::Context, error::Error};
+use super::{
+    context::Context,
+    error::Error,
+    grant::{
+        parse_grant_definition, parse_revoke_definition, render_grant_definitions,
+        render_revoke_definitions,
+    },
+};
 
 pub fn parse_function_definition(ctx: &Context, n: &Node) -> Result<Function, Error> {
     assert_eq!(n.r#type, "function");
@@ -20,6 +27,10 @@ pub fn parse_function_definition(ctx: &Context, n: &Node) -> Result<Function, Er
     let mut language: Option<String> = None;
     let mut returns: Option<String> = None;
     let mut volatility: Option<String> = None;
+    let mut owner: Option<String> = None;
+    let mut comment: Option<String> = None;
+    let mut grants: Vec<Grant> = vec![];
+    let mut revokes: Vec<Grant> = vec![];
 
     for cn in child_nodes {
         if let Err(e) = match cn.r#type {
@@ -27,6 +38,20 @@ pub fn parse_function_definition(ctx: &Context, n: &Node) -> Result<Function, Er
             "language" => coerce_string_value(cn).and_then(|v| Ok(language = Some(v))),
             "returns" => coerce_string_value(cn).and_then(|v| Ok(returns = Some(v))),
             "volatility" => coerce_string_value(cn).and_then(|v| Ok(volatility = Some(v))),
+            "owner" => coerce_string_value(cn).and_then(|v| Ok(owner = Some(v))),
+            "comment" => coerce_string_value(cn).and_then(|v| Ok(comment = Some(v))),
+            "grant" => parse_grant_definition("function", cn).and_then(|v| {
+                for grant in v {
+                    insert_grant(&mut grants, grant);
+                }
+                Ok(())
+            }),
+            "revoke" => parse_revoke_definition("function", cn).and_then(|v| {
+                for revoke in v {
+                    insert_grant(&mut revokes, revoke);
+                }
+                Ok(())
+            }),
             _ => Err(Error::new_unexpected_node(cn)),
         } {
             errors.push(e);
@@ -45,6 +70,9 @@ pub fn parse_function_definition(ctx: &Context, n: &Node) -> Result<Function, Er
     if volatility.is_none() {
         volatility = Some(defaults::get_function_volatility());
     }
+    if comment.is_none() {
+        comment = Some(defaults::get_default_description());
+    }
 
     if !errors.is_empty() {
         return Err(Error::new_has_errors(n, errors));
@@ -57,6 +85,10 @@ pub fn parse_function_definition(ctx: &Context, n: &Node) -> Result<Function, Er
         language: language.unwrap(),
         returns: returns.unwrap(),
         volatility: volatility.unwrap(),
+        owner,
+        comment: comment.unwrap(),
+        grants,
+        revokes,
     })
 }
 
@@ -74,6 +106,14 @@ pub fn render_function_definition(_ctx: &Context, def: &Function) -> Node {
     if def.volatility != defaults::get_function_volatility() {
         subnodes.push(Node::new("volatility").with_string_value(def.volatility.clone()));
     }
+    if let Some(owner) = &def.owner {
+        subnodes.push(Node::new("owner").with_string_value(owner.clone()));
+    }
+    if def.comment != defaults::get_default_description() {
+        subnodes.push(Node::new("comment").with_string_value(def.comment.clone()));
+    }
+    subnodes.extend(render_grant_definitions("function", &def.grants));
+    subnodes.extend(render_revoke_definitions("function", &def.revokes));
 
     Node::new("function")
         .with_name(def.name.clone())
@@ -101,6 +141,10 @@ mod tests {
             language: "plpgsql".to_owned(),
             returns: "integer".to_owned(),
             volatility: "immutable".to_owned(),
+            owner: None,
+            comment: defaults::get_default_description(),
+            grants: vec![],
+            revokes: vec![],
         };
         let ctx = Context {
             schema_name: "public".to_owned(),
@@ -0,0 +1,244 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    definition::{
+        base::Node,
+        ddl::coerce::{coerce_name, coerce_name_and_string_varargs_value, coerce_nodes},
+    },
+    snapshot::{insert_grant, DefaultGrant, Grant},
+};
+
+use super::error::Error;
+
+/// Privileges that can be granted on each kind of object, `all` standing for
+/// every one of them.
+fn get_privileges(object: &str) -> &'static [&'static str] {
+    match object {
+        "schema" => &["create", "usage"],
+        "table" => &[
+            "delete",
+            "insert",
+            "references",
+            "select",
+            "trigger",
+            "truncate",
+            "update",
+        ],
+        "column" => &["insert", "references", "select", "update"],
+        "sequence" => &["select", "update", "usage"],
+        "function" => &["execute"],
+        "type" => &["usage"],
+        _ => &[],
+    }
+}
+
+// `grant select, insert: [app_rw]`
+pub fn parse_grant_definition(object: &str, n: &Node) -> Result<Vec<Grant>, Error> {
+    assert_eq!(n.r#type, "grant");
+    parse_privileges(object, n)
+}
+
+// `revoke execute: [public]`, taking away privileges the object starts out
+// with, such as the `EXECUTE` every role holds on a new function.
+pub fn parse_revoke_definition(object: &str, n: &Node) -> Result<Vec<Grant>, Error> {
+    assert_eq!(n.r#type, "revoke");
+    parse_privileges(object, n)
+}
+
+fn parse_privileges(object: &str, n: &Node) -> Result<Vec<Grant>, Error> {
+    let (privileges, grantees) = coerce_name_and_string_varargs_value(n)?;
+    let allowed = get_privileges(object);
+
+    let mut expanded: Vec<String> = vec![];
+    for privilege in privileges.split(',') {
+        let privilege = privilege.trim().to_lowercase();
+        if privilege == "all" || privilege == "all privileges" {
+            expanded.extend(allowed.iter().map(|p| p.to_string()));
+        } else if allowed.contains(&privilege.as_str()) {
+            expanded.push(privilege);
+        } else {
+            return Err(Error::new_unexpected_node(n));
+        }
+    }
+
+    let mut grants = vec![];
+    for grantee in &grantees {
+        for privilege in &expanded {
+            insert_grant(&mut grants, Grant::new(grantee, privilege));
+        }
+    }
+    Ok(grants)
+}
+
+// `default privileges on tables:` holding `grant` nodes
+pub fn parse_default_privileges_definition(n: &Node) -> Result<Vec<DefaultGrant>, Error> {
+    assert_eq!(n.r#type, "default privileges on");
+
+    let object_type = coerce_name(n)?.to_lowercase();
+    let child_nodes = coerce_nodes(n)?;
+    let mut errors: Vec<Error> = Vec::new();
+
+    let object = match object_type.as_str() {
+        "tables" => "table",
+        "sequences" => "sequence",
+        "functions" => "function",
+        "types" => "type",
+        _ => return Err(Error::new_unexpected_node(n)),
+    };
+
+    let mut default_grants = vec![];
+    for cn in child_nodes {
+        if let Err(e) = match cn.r#type {
+            "grant" => parse_grant_definition(object, cn).and_then(|grants| {
+                for grant in grants {
+                    insert_grant(
+                        &mut default_grants,
+                        DefaultGrant::new(&object_type, &grant.grantee, &grant.privilege),
+                    );
+                }
+                Ok(())
+            }),
+            _ => Err(Error::new_unexpected_node(cn)),
+        } {
+            errors.push(e);
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(Error::new_has_errors(n, errors));
+    }
+
+    Ok(default_grants)
+}
+
+pub fn render_grant_definitions(object: &str, grants: &[Grant]) -> Vec<Node> {
+    render_privileges("grant", object, grants)
+}
+
+pub fn render_revoke_definitions(object: &str, revokes: &[Grant]) -> Vec<Node> {
+    render_privileges("revoke", object, revokes)
+}
+
+// Grantees holding the same privileges share a single node.
+fn render_privileges(node_type: &'static str, object: &str, grants: &[Grant]) -> Vec<Node> {
+    let mut by_grantee: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for grant in grants {
+        by_grantee
+            .entry(&grant.grantee)
+            .or_default()
+            .push(grant.privilege.clone());
+    }
+
+    let mut by_privileges: BTreeMap<Vec<String>, Vec<String>> = BTreeMap::new();
+    for (grantee, privileges) in by_grantee {
+        by_privileges
+            .entry(privileges)
+            .or_default()
+            .push(grantee.to_string());
+    }
+
+    let all = get_privileges(object);
+    by_privileges
+        .into_iter()
+        .map(|(privileges, grantees)| {
+            let name = if privileges.len() == all.len()
+                && all.iter().all(|p| privileges.iter().any(|q| q == p))
+            {
+                "all".to_string()
+            } else {
+                privileges.join(", ")
+            };
+            Node::new(node_type)
+                .with_name(name)
+                .with_string_varargs_value(grantees)
+        })
+        .collect()
+}
+
+pub fn render_default_privileges_definitions(default_grants: &[DefaultGrant]) -> Vec<Node> {
+    let mut by_object_type: BTreeMap<&str, Vec<Grant>> = BTreeMap::new();
+    for grant in default_grants {
+        by_object_type
+            .entry(&grant.object_type)
+            .or_default()
+            .push(Grant::new(&grant.grantee, &grant.privilege));
+    }
+
+    by_object_type
+        .into_iter()
+        .map(|(object_type, grants)| {
+            let object = object_type.trim_end_matches('s');
+            Node::new("default privileges on")
+                .with_name(object_type.to_string())
+                .with_nodes(render_grant_definitions(object, &grants))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_render_grant_definition() {
+        let nodes = vec![
+            Node::new("grant")
+                .with_name("insert, select".to_owned())
+                .with_string_varargs_value(vec!["app_rw".to_owned()]),
+            Node::new("grant")
+                .with_name("select".to_owned())
+                .with_string_varargs_value(vec!["app_ro".to_owned(), "public".to_owned()]),
+        ];
+
+        let mut grants = vec![];
+        for node in &nodes {
+            for grant in parse_grant_definition("table", node).unwrap() {
+                insert_grant(&mut grants, grant);
+            }
+        }
+        assert_eq!(
+            grants,
+            vec![
+                Grant::new("app_ro", "select"),
+                Grant::new("app_rw", "insert"),
+                Grant::new("app_rw", "select"),
+                Grant::new("public", "select"),
+            ]
+        );
+
+        let rendered = render_grant_definitions("table", &grants);
+        assert_eq!(rendered, nodes);
+    }
+
+    #[test]
+    fn test_parse_grant_all_privileges() {
+        let node = Node::new("grant")
+            .with_name("ALL".to_owned())
+            .with_string_varargs_value(vec!["app_rw".to_owned()]);
+
+        let grants = parse_grant_definition("sequence", &node).unwrap();
+        assert_eq!(
+            grants,
+            vec![
+                Grant::new("app_rw", "select"),
+                Grant::new("app_rw", "update"),
+                Grant::new("app_rw", "usage"),
+            ]
+        );
+        assert_eq!(
+            render_grant_definitions("sequence", &grants),
+            vec![node.with_name("all".to_owned())]
+        );
+    }
+
+    #[test]
+    fn test_parse_and_render_revoke_definition() {
+        let node = Node::new("revoke")
+            .with_name("truncate".to_owned())
+            .with_string_varargs_value(vec!["app_owner".to_owned()]);
+
+        let revokes = parse_revoke_definition("table", &node).unwrap();
+        assert_eq!(revokes, vec![Grant::new("app_owner", "truncate")]);
+        assert_eq!(render_revoke_definitions("table", &revokes), vec![node]);
+    }
+}
@@ -3,14 +3,16 @@ use crate::{
         base::Node,
         ddl::coerce::{
             coerce_boolean_value, coerce_name, coerce_name_and_string_varargs_value, coerce_nodes,
-            coerce_string_value,
+            coerce_string_value, coerce_string_varargs_value,
         },
         defaults,
     },
-    snapshot::Index,
+    snapshot::{Index, IndexKey},
+    util::sqlfmt::sql_collation,
 };
 
 use super::{context::Context, error::Error};
+use regex::Regex;
 
 pub fn parse_index_definition(ctx: &Context, n: &Node) -> Result<Index, Error> {
     assert_eq!(n.r#type, "index");
@@ -21,18 +23,33 @@ pub fn parse_index_definition(ctx: &Context, n: &Node) -> Result<Index, Error> {
 
     let mut table_name: Option<String> = None;
     let mut unique: Option<bool> = None;
+    let mut nulls_not_distinct: Option<bool> = None;
     let mut method: Option<String> = None;
-    let mut key_expressions: Option<Vec<String>> = None;
+    let mut keys: Option<Vec<IndexKey>> = None;
+    let mut include: Vec<String> = vec![];
+    let mut predicate: Option<String> = None;
+    let mut storage_parameters: Vec<String> = vec![];
+    let mut tablespace: Option<String> = None;
+    let mut comment: Option<String> = None;
 
     for cn in child_nodes {
         if let Err(e) = match cn.r#type {
             "unique" => coerce_boolean_value(cn).and_then(|v| Ok(unique = Some(v))),
+            "nulls not distinct" => {
+                coerce_boolean_value(cn).and_then(|v| Ok(nulls_not_distinct = Some(v)))
+            }
             "on" => coerce_name_and_string_varargs_value(cn).and_then(|(name, v)| {
                 table_name = Some(name);
-                key_expressions = Some(v);
+                keys = Some(v.iter().map(|k| parse_index_key(k)).collect());
                 Ok(())
             }),
             "using" => coerce_string_value(cn).and_then(|v| Ok(method = Some(v))),
+            "include" => coerce_string_varargs_value(cn).and_then(|v| Ok(include = v)),
+            "where" => coerce_string_value(cn).and_then(|v| Ok(predicate = Some(v))),
+            "with" => coerce_string_varargs_value(cn).and_then(|v| Ok(storage_parameters = v)),
+            "tablespace" => coerce_string_value(cn).and_then(|v| Ok(tablespace = Some(v))),
+            "comment" => coerce_string_value(cn).and_then(|v| Ok(comment = Some(v))),
+            "renamed from" => coerce_string_value(cn).and_then(|_| Ok(())),
             _ => Err(Error::new_unexpected_node(cn)),
         } {
             errors.push(e);
@@ -45,17 +62,29 @@ pub fn parse_index_definition(ctx: &Context, n: &Node) -> Result<Index, Error> {
     if method.is_none() {
         method = Some(defaults::get_default_index_method());
     }
-    if table_name.is_none() || key_expressions.is_none() {
+    if nulls_not_distinct.is_none() {
+        nulls_not_distinct = Some(defaults::get_index_nulls_not_distinct());
+    }
+    if table_name.is_none() || keys.is_none() {
         errors.push(Error::new_attribute_required(n, "on"));
     }
+    if comment.is_none() {
+        comment = Some(defaults::get_default_description());
+    }
 
     Ok(Index {
         schema_name: ctx.schema_name.clone(),
         table_name: table_name.unwrap(),
         name: name,
         unique: unique.unwrap(),
+        nulls_not_distinct: nulls_not_distinct.unwrap(),
         method: method.unwrap(),
-        key_expressions: key_expressions.unwrap(),
+        keys: keys.unwrap(),
+        include,
+        predicate,
+        storage_parameters,
+        tablespace,
+        comment: comment.unwrap(),
     })
 }
 
@@ -65,24 +94,97 @@ pub fn render_index_definition(_ctx: &Context, def: &Index) -> Node {
     if def.unique != defaults::get_index_unique() {
         subnodes.push(Node::new("unique").with_boolean_value(def.unique));
     }
+    if def.nulls_not_distinct != defaults::get_index_nulls_not_distinct() {
+        subnodes.push(Node::new("nulls not distinct").with_boolean_value(def.nulls_not_distinct));
+    }
     if def.method != defaults::get_default_index_method() {
         subnodes.push(Node::new("using").with_string_value(def.method.clone()));
     }
     subnodes.push(
         Node::new("on")
             .with_name(def.table_name.clone())
-            .with_string_varargs_value(def.key_expressions.clone()),
+            .with_string_varargs_value(def.keys.iter().map(render_index_key).collect()),
     );
+    if !def.include.is_empty() {
+        subnodes.push(Node::new("include").with_string_varargs_value(def.include.clone()));
+    }
+    if let Some(predicate) = &def.predicate {
+        subnodes.push(Node::new("where").with_string_value(predicate.clone()));
+    }
+    if !def.storage_parameters.is_empty() {
+        subnodes.push(Node::new("with").with_string_varargs_value(def.storage_parameters.clone()));
+    }
+    if let Some(tablespace) = &def.tablespace {
+        subnodes.push(Node::new("tablespace").with_string_value(tablespace.clone()));
+    }
+    if def.comment != defaults::get_default_description() {
+        subnodes.push(Node::new("comment").with_string_value(def.comment.clone()));
+    }
 
     Node::new("index")
         .with_name(def.name.clone())
         .with_nodes(subnodes)
 }
 
+// `lower(email) COLLATE "C" text_pattern_ops DESC NULLS LAST`, everything
+// but the expression being optional. Operator classes are told apart from the
+// expression by their conventional `_ops` suffix.
+fn parse_index_key(input: &str) -> IndexKey {
+    let re = Regex::new(
+        r#"(?i)^(?P<expression>.+?)(?:\s+collate\s+(?P<collation>"[^"]+"(?:\."[^"]+")?|[\w.]+))?(?:\s+(?P<opclass>[\w.]+_ops))?(?:\s+(?P<order>asc|desc))?(?:\s+nulls\s+(?P<nulls>first|last))?$"#,
+    )
+    .unwrap();
+    let caps = re.captures(input.trim()).unwrap();
+
+    let order = caps
+        .name("order")
+        .map(|m| m.as_str().to_uppercase())
+        .unwrap_or_else(defaults::get_default_index_order);
+    let nulls = caps
+        .name("nulls")
+        .map(|m| m.as_str().to_uppercase())
+        .unwrap_or_else(|| defaults::get_default_index_nulls(&order));
+
+    IndexKey {
+        expression: caps.name("expression").unwrap().as_str().to_string(),
+        collation: caps.name("collation").map(|m| m.as_str().replace('"', "")),
+        opclass: caps.name("opclass").map(|m| m.as_str().to_string()),
+        order,
+        nulls,
+    }
+}
+
+fn render_index_key(key: &IndexKey) -> String {
+    let mut tokens = vec![key.expression.clone()];
+    if let Some(collation) = &key.collation {
+        tokens.push(format!("COLLATE {}", sql_collation(collation)));
+    }
+    if let Some(opclass) = &key.opclass {
+        tokens.push(opclass.clone());
+    }
+    if key.order != defaults::get_default_index_order() {
+        tokens.push(key.order.clone());
+    }
+    if key.nulls != defaults::get_default_index_nulls(&key.order) {
+        tokens.push(format!("NULLS {}", key.nulls));
+    }
+    tokens.join(" ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn index_key(expression: &str) -> IndexKey {
+        IndexKey {
+            expression: expression.to_owned(),
+            collation: None,
+            opclass: None,
+            order: "ASC".to_owned(),
+            nulls: "LAST".to_owned(),
+        }
+    }
+
     #[test]
     fn test_parse_and_render_index_definition() {
         let node = Node::new("index")
@@ -99,7 +201,13 @@ mod tests {
             name: "user_id_idx".to_owned(),
             unique: defaults::get_index_unique(),
             method: defaults::get_default_index_method(),
-            key_expressions: vec!["user_id".to_owned(), "username".to_owned()],
+            nulls_not_distinct: false,
+            keys: vec![index_key("user_id"), index_key("username")],
+            include: vec![],
+            predicate: None,
+            storage_parameters: vec![],
+            tablespace: None,
+            comment: defaults::get_default_description(),
         };
         let ctx = Context {
             schema_name: "public".to_owned(),
@@ -111,4 +219,75 @@ mod tests {
         let got_node = render_index_definition(&ctx, &def);
         assert_eq!(got_node, node);
     }
+
+    #[test]
+    fn test_parse_and_render_complete_index_definition() {
+        let node = Node::new("index")
+            .with_name("user_email_idx".to_owned())
+            .with_nodes(vec![
+                Node::new("unique").with_boolean_value(true),
+                Node::new("nulls not distinct").with_boolean_value(true),
+                Node::new("on")
+                    .with_name("users".to_owned())
+                    .with_string_varargs_value(vec![
+                        "lower(email) text_pattern_ops DESC".to_owned(),
+                        "name COLLATE \"C\" NULLS FIRST".to_owned(),
+                    ]),
+                Node::new("include").with_string_varargs_value(vec!["id".to_owned()]),
+                Node::new("where").with_string_value("deleted_at IS NULL".to_owned()),
+                Node::new("with").with_string_varargs_value(vec!["fillfactor=70".to_owned()]),
+                Node::new("tablespace").with_string_value("fast".to_owned()),
+            ]);
+        let def = Index {
+            schema_name: "public".to_owned(),
+            table_name: "users".to_owned(),
+            name: "user_email_idx".to_owned(),
+            unique: true,
+            nulls_not_distinct: true,
+            method: defaults::get_default_index_method(),
+            keys: vec![
+                IndexKey {
+                    expression: "lower(email)".to_owned(),
+                    collation: None,
+                    opclass: Some("text_pattern_ops".to_owned()),
+                    order: "DESC".to_owned(),
+                    nulls: "FIRST".to_owned(),
+                },
+                IndexKey {
+                    expression: "name".to_owned(),
+                    collation: Some("C".to_owned()),
+                    opclass: None,
+                    order: "ASC".to_owned(),
+                    nulls: "FIRST".to_owned(),
+                },
+            ],
+            include: vec!["id".to_owned()],
+            predicate: Some("deleted_at IS NULL".to_owned()),
+            storage_parameters: vec!["fillfactor=70".to_owned()],
+            tablespace: Some("fast".to_owned()),
+            comment: defaults::get_default_description(),
+        };
+        let ctx = Context {
+            schema_name: "public".to_owned(),
+            table_name: None,
+        };
+        let got_def = parse_index_definition(&ctx, &node).unwrap();
+        assert_eq!(got_def, def);
+
+        let got_node = render_index_definition(&ctx, &def);
+        assert_eq!(got_node, node);
+    }
+
+    #[test]
+    fn test_parse_index_key() {
+        assert_eq!(parse_index_key("a + b"), index_key("a + b"));
+        assert_eq!(
+            parse_index_key("created_at desc nulls last"),
+            IndexKey {
+                order: "DESC".to_owned(),
+                nulls: "LAST".to_owned(),
+                ..index_key("created_at")
+            }
+        );
+    }
 }
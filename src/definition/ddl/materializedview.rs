@@ -0,0 +1,146 @@
+use crate::{
+    definition::{
+        base::Node,
+        ddl::coerce::{
+            coerce_boolean_value, coerce_name, coerce_nodes, coerce_string_value,
+            coerce_string_varargs_value,
+        },
+        defaults,
+    },
+    snapshot::MaterializedView,
+};
+
+use super::{context::Context, error::Error};
+
+pub fn parse_materialized_view_definition(
+    ctx: &Context,
+    n: &Node,
+) -> Result<MaterializedView, Error> {
+    assert_eq!(n.r#type, "materialized view");
+
+    let name = coerce_name(n)?;
+    let child_nodes = coerce_nodes(n)?;
+    let mut errors: Vec<Error> = Vec::new();
+
+    let mut query: Option<String> = None;
+    let mut with_data: Option<bool> = None;
+    let mut storage_parameters: Vec<String> = Vec::new();
+    let mut refresh: Option<bool> = None;
+    let mut owner: Option<String> = None;
+    let mut comment: Option<String> = None;
+
+    for cn in child_nodes {
+        if let Err(e) = match cn.r#type {
+            "as" => coerce_string_value(cn).and_then(|v| Ok(query = Some(v))),
+            "with data" => coerce_boolean_value(cn).and_then(|v| Ok(with_data = Some(v))),
+            "with" => coerce_string_varargs_value(cn).and_then(|v| Ok(storage_parameters = v)),
+            "refresh" => coerce_boolean_value(cn).and_then(|v| Ok(refresh = Some(v))),
+            "owner" => coerce_string_value(cn).and_then(|v| Ok(owner = Some(v))),
+            "comment" => coerce_string_value(cn).and_then(|v| Ok(comment = Some(v))),
+            "renamed from" => coerce_string_value(cn).and_then(|_| Ok(())),
+            _ => Err(Error::new_unexpected_node(cn)),
+        } {
+            errors.push(e);
+        }
+    }
+
+    if query.is_none() {
+        errors.push(Error::new_attribute_required(n, "as"));
+    }
+    if with_data.is_none() {
+        with_data = Some(defaults::get_materialized_view_with_data());
+    }
+    if refresh.is_none() {
+        refresh = Some(defaults::get_materialized_view_refresh());
+    }
+    if comment.is_none() {
+        comment = Some(defaults::get_default_description());
+    }
+
+    if !errors.is_empty() {
+        return Err(Error::new_has_errors(n, errors));
+    }
+
+    Ok(MaterializedView {
+        schema_name: ctx.schema_name.clone(),
+        name,
+        query: query.unwrap(),
+        with_data: with_data.unwrap(),
+        storage_parameters,
+        refresh: refresh.unwrap(),
+        owner,
+        comment: comment.unwrap(),
+    })
+}
+
+pub fn render_materialized_view_definition(ctx: &Context, def: &MaterializedView) -> Node {
+    assert!(def.schema_name == ctx.schema_name);
+
+    let mut subnodes: Vec<Node> = Vec::new();
+
+    subnodes.push(Node::new("as").with_string_value(def.query.clone()));
+
+    if def.with_data != defaults::get_materialized_view_with_data() {
+        subnodes.push(Node::new("with data").with_boolean_value(def.with_data));
+    }
+    if !def.storage_parameters.is_empty() {
+        subnodes.push(Node::new("with").with_string_varargs_value(def.storage_parameters.clone()));
+    }
+    if def.refresh != defaults::get_materialized_view_refresh() {
+        subnodes.push(Node::new("refresh").with_boolean_value(def.refresh));
+    }
+    if let Some(owner) = &def.owner {
+        subnodes.push(Node::new("owner").with_string_value(owner.clone()));
+    }
+    if def.comment != defaults::get_default_description() {
+        subnodes.push(Node::new("comment").with_string_value(def.comment.clone()));
+    }
+
+    Node::new("materialized view")
+        .with_name(def.name.clone())
+        .with_nodes(subnodes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_render_materialized_view_definition() {
+        let node = Node::new("materialized view")
+            .with_name("order_totals".to_owned())
+            .with_nodes(vec![
+                Node::new("as").with_string_value(
+                    "SELECT user_id, sum(amount) AS total FROM orders GROUP BY user_id".to_owned(),
+                ),
+                Node::new("with data").with_boolean_value(false),
+                Node::new("with").with_string_varargs_value(vec![
+                    "fillfactor=70".to_owned(),
+                    "autovacuum_enabled=false".to_owned(),
+                ]),
+                Node::new("refresh").with_boolean_value(true),
+                Node::new("owner").with_string_value("app_owner".to_owned()),
+            ]);
+        let def = MaterializedView {
+            schema_name: "public".to_owned(),
+            name: "order_totals".to_owned(),
+            query: "SELECT user_id, sum(amount) AS total FROM orders GROUP BY user_id".to_owned(),
+            with_data: false,
+            storage_parameters: vec![
+                "fillfactor=70".to_owned(),
+                "autovacuum_enabled=false".to_owned(),
+            ],
+            refresh: true,
+            owner: Some("app_owner".to_owned()),
+            comment: defaults::get_default_description(),
+        };
+        let ctx = Context {
+            schema_name: "public".to_owned(),
+            table_name: None,
+        };
+        let got_def = parse_materialized_view_definition(&ctx, &node).unwrap();
+        assert_eq!(got_def, def);
+        let got_node = render_materialized_view_definition(&ctx, &def);
+        assert_eq!(got_node, node);
+    }
+}
@@ -4,14 +4,27 @@ mod column;
 mod constraint;
 mod context;
 mod error;
+mod exclusion;
+mod extension;
 mod foreignkey;
 mod function;
+mod grant;
 mod index;
+mod materializedview;
+mod policy;
 mod primarykey;
+mod procedure;
+mod publication;
+mod rename;
 mod schema;
 mod sequence;
+mod serial;
+mod statistics;
 mod table;
 mod unique;
 mod view;
 
+pub use extension::*;
+pub use publication::*;
+pub use rename::*;
 pub use schema::*;
@@ -0,0 +1,129 @@
+use crate::{
+    definition::{
+        base::Node,
+        ddl::coerce::{
+            coerce_name, coerce_nodes, coerce_string_value, coerce_string_varargs_value,
+        },
+        defaults,
+    },
+    snapshot::Policy,
+};
+
+use super::{context::Context, error::Error};
+
+pub fn parse_policy_definition(schema: &str, table: &str, n: &Node) -> Result<Policy, Error> {
+    assert_eq!(n.r#type, "policy");
+
+    let name = coerce_name(n)?;
+    let child_nodes = coerce_nodes(n)?;
+    let mut errors: Vec<Error> = Vec::new();
+
+    let mut command: Option<String> = None;
+    let mut permissive: Option<bool> = None;
+    let mut roles: Option<Vec<String>> = None;
+    let mut using: Option<String> = None;
+    let mut with_check: Option<String> = None;
+
+    for cn in child_nodes {
+        if let Err(e) = match cn.r#type {
+            "for" => coerce_string_value(cn).and_then(|v| Ok(command = Some(v.to_uppercase()))),
+            "as" => coerce_string_value(cn).and_then(|v| match v.to_lowercase().as_str() {
+                "permissive" => Ok(permissive = Some(true)),
+                "restrictive" => Ok(permissive = Some(false)),
+                _ => Err(Error::new_invalid_type_attribute(
+                    cn,
+                    "permissive or restrictive",
+                )),
+            }),
+            "to" => coerce_string_varargs_value(cn).and_then(|v| Ok(roles = Some(v))),
+            "using" => coerce_string_value(cn).and_then(|v| Ok(using = Some(v))),
+            "with check" => coerce_string_value(cn).and_then(|v| Ok(with_check = Some(v))),
+            _ => Err(Error::new_unexpected_node(cn)),
+        } {
+            errors.push(e);
+        }
+    }
+
+    if command.is_none() {
+        command = Some(defaults::get_policy_command());
+    }
+    if permissive.is_none() {
+        permissive = Some(defaults::get_policy_permissive());
+    }
+    if roles.is_none() {
+        roles = Some(defaults::get_policy_roles());
+    }
+
+    if !errors.is_empty() {
+        return Err(Error::new_has_errors(n, errors));
+    }
+
+    Ok(Policy {
+        schema_name: schema.to_owned(),
+        table_name: table.to_owned(),
+        name,
+        command: command.unwrap(),
+        permissive: permissive.unwrap(),
+        roles: roles.unwrap(),
+        using,
+        with_check,
+    })
+}
+
+pub fn render_policy_definition(_ctx: &Context, def: &Policy) -> Node {
+    let mut subnodes: Vec<Node> = Vec::new();
+
+    if def.command != defaults::get_policy_command() {
+        subnodes.push(Node::new("for").with_string_value(def.command.to_lowercase()));
+    }
+    if def.permissive != defaults::get_policy_permissive() {
+        subnodes.push(Node::new("as").with_string_value("restrictive".to_owned()));
+    }
+    if def.roles != defaults::get_policy_roles() {
+        subnodes.push(Node::new("to").with_string_varargs_value(def.roles.clone()));
+    }
+    if let Some(using) = &def.using {
+        subnodes.push(Node::new("using").with_string_value(using.clone()));
+    }
+    if let Some(with_check) = &def.with_check {
+        subnodes.push(Node::new("with check").with_string_value(with_check.clone()));
+    }
+
+    Node::new("policy")
+        .with_name(def.name.clone())
+        .with_nodes(subnodes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_render_policy_definition() {
+        let node = Node::new("policy")
+            .with_name("tenant_isolation".to_owned())
+            .with_nodes(vec![
+                Node::new("for").with_string_value("select".to_owned()),
+                Node::new("as").with_string_value("restrictive".to_owned()),
+                Node::new("to").with_string_varargs_value(vec!["app_rw".to_owned()]),
+                Node::new("using").with_string_value("tenant_id = 1".to_owned()),
+            ]);
+
+        let def = Policy {
+            schema_name: "public".to_owned(),
+            table_name: "account".to_owned(),
+            name: "tenant_isolation".to_owned(),
+            command: "SELECT".to_owned(),
+            permissive: false,
+            roles: vec!["app_rw".to_owned()],
+            using: Some("tenant_id = 1".to_owned()),
+            with_check: None,
+        };
+
+        assert_eq!(
+            parse_policy_definition("public", "account", &node).unwrap(),
+            def
+        );
+        assert_eq!(render_policy_definition(&Context::default(), &def), node);
+    }
+}
@@ -1,7 +1,8 @@
 use crate::{
     definition::{
         ddl::coerce::{
-            coerce_boolean_value, coerce_name, coerce_nodes, coerce_string_varargs_value,
+            coerce_boolean_value, coerce_name, coerce_nodes, coerce_string_value,
+            coerce_string_varargs_value,
         },
         base::Node,
         defaults,
@@ -25,6 +26,7 @@ pub fn parse_primary_key_definition(
     let mut columns: Option<Vec<String>> = None;
     let mut deferrable: Option<bool> = None;
     let mut initially_deferred: Option<bool> = None;
+    let mut comment: Option<String> = None;
 
     for cn in child_node {
         if let Err(e) = match cn.r#type {
@@ -33,6 +35,8 @@ pub fn parse_primary_key_definition(
             "initially deferred" => {
                 coerce_boolean_value(cn).and_then(|v| Ok(initially_deferred = Some(v)))
             }
+            "comment" => coerce_string_value(cn).and_then(|v| Ok(comment = Some(v))),
+            "renamed from" => coerce_string_value(cn).and_then(|_| Ok(())),
             _ => Err(Error::new_unexpected_node(cn)),
         } {
             errors.push(e);
@@ -48,6 +52,9 @@ pub fn parse_primary_key_definition(
     if initially_deferred.is_none() {
         initially_deferred = Some(defaults::get_constraint_initially_deferred());
     }
+    if comment.is_none() {
+        comment = Some(defaults::get_default_description());
+    }
 
     if !errors.is_empty() {
         return Err(Error::new_has_errors(n, errors));
@@ -60,6 +67,7 @@ pub fn parse_primary_key_definition(
         columns: columns.unwrap(),
         deferrable: deferrable.unwrap(),
         initially_deferred: initially_deferred.unwrap(),
+        comment: comment.unwrap(),
     })
 }
 
@@ -74,6 +82,9 @@ pub fn render_primary_key_definition(_ctx: &Context, def: &PrimaryKey) -> Node {
     if def.initially_deferred != defaults::get_constraint_initially_deferred() {
         subnodes.push(Node::new("initially deferred").with_boolean_value(def.initially_deferred));
     }
+    if def.comment != defaults::get_default_description() {
+        subnodes.push(Node::new("comment").with_string_value(def.comment.clone()));
+    }
 
     Node::new("constraint")
         .with_name(def.name.clone())
@@ -100,6 +111,7 @@ mod test {
             columns: vec!["id".to_owned()],
             deferrable: true,
             initially_deferred: true,
+            comment: defaults::get_default_description(),
         };
         let ctx = Context {
             schema_name: "public".to_owned(),
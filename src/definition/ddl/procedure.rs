@@ -0,0 +1,112 @@
+use crate::{
+    definition::{
+        base::Node,
+        ddl::coerce::{coerce_name, coerce_nodes, coerce_string_value},
+        defaults,
+    },
+    snapshot::Procedure,
+};
+
+use super::{context::Context, error::Error};
+
+pub fn parse_procedure_definition(ctx: &Context, n: &Node) -> Result<Procedure, Error> {
+    assert_eq!(n.r#type, "procedure");
+
+    let name = coerce_name(n)?;
+    let child_nodes = coerce_nodes(n)?;
+    let mut errors: Vec<Error> = Vec::new();
+
+    let mut arguments: Option<String> = None;
+    let mut body: Option<String> = None;
+    let mut language: Option<String> = None;
+    let mut owner: Option<String> = None;
+
+    for cn in child_nodes {
+        if let Err(e) = match cn.r#type {
+            "arguments" => coerce_string_value(cn).and_then(|v| Ok(arguments = Some(v))),
+            "as" => coerce_string_value(cn).and_then(|v| Ok(body = Some(v))),
+            "language" => coerce_string_value(cn).and_then(|v| Ok(language = Some(v))),
+            "owner" => coerce_string_value(cn).and_then(|v| Ok(owner = Some(v))),
+            _ => Err(Error::new_unexpected_node(cn)),
+        } {
+            errors.push(e);
+        }
+    }
+
+    if body.is_none() {
+        errors.push(Error::new_attribute_required(n, "as"));
+    }
+    if arguments.is_none() {
+        arguments = Some(defaults::get_procedure_arguments());
+    }
+    if language.is_none() {
+        language = Some(defaults::get_procedure_language());
+    }
+
+    if !errors.is_empty() {
+        return Err(Error::new_has_errors(n, errors));
+    }
+
+    Ok(Procedure {
+        schema_name: ctx.schema_name.clone(),
+        name,
+        arguments: arguments.unwrap(),
+        body: body.unwrap(),
+        language: language.unwrap(),
+        owner,
+    })
+}
+
+pub fn render_procedure_definition(_ctx: &Context, def: &Procedure) -> Node {
+    let mut subnodes: Vec<Node> = Vec::new();
+
+    if def.arguments != defaults::get_procedure_arguments() {
+        subnodes.push(Node::new("arguments").with_string_value(def.arguments.clone()));
+    }
+    if def.language != defaults::get_procedure_language() {
+        subnodes.push(Node::new("language").with_string_value(def.language.clone()));
+    }
+    subnodes.push(Node::new("as").with_string_value(def.body.clone()));
+    if let Some(owner) = &def.owner {
+        subnodes.push(Node::new("owner").with_string_value(owner.clone()));
+    }
+
+    Node::new("procedure")
+        .with_name(def.name.clone())
+        .with_nodes(subnodes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_render_procedure_definition() {
+        let node = Node::new("procedure")
+            .with_name("archive_orders".to_owned())
+            .with_nodes(vec![
+                Node::new("arguments").with_string_value("before date".to_owned()),
+                Node::new("language").with_string_value("plpgsql".to_owned()),
+                Node::new("as").with_string_value(
+                    "BEGIN DELETE FROM orders WHERE created_at < before; END;".to_owned(),
+                ),
+                Node::new("owner").with_string_value("app_owner".to_owned()),
+            ]);
+        let def = Procedure {
+            schema_name: "public".to_owned(),
+            name: "archive_orders".to_owned(),
+            arguments: "before date".to_owned(),
+            body: "BEGIN DELETE FROM orders WHERE created_at < before; END;".to_owned(),
+            language: "plpgsql".to_owned(),
+            owner: Some("app_owner".to_owned()),
+        };
+        let ctx = Context {
+            schema_name: "public".to_owned(),
+            table_name: None,
+        };
+        let got_def = parse_procedure_definition(&ctx, &node).unwrap();
+        assert_eq!(got_def, def);
+        let got_node = render_procedure_definition(&ctx, &def);
+        assert_eq!(got_node, node);
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{
+    definition::{
+        base::Node,
+        ddl::coerce::{
+            coerce_name, coerce_nodes, coerce_string_value, coerce_string_varargs_value,
+        },
+    },
+    snapshot::{Publication, PublicationTable},
+};
+
+use super::error::Error;
+
+pub fn parse_publication_definition(n: &Node) -> Result<Publication, Error> {
+    assert_eq!(n.r#type, "publication");
+
+    let name = coerce_name(n)?;
+    let child_nodes = coerce_nodes(n)?;
+    let mut errors: Vec<Error> = Vec::new();
+
+    let mut tables: Vec<PublicationTable> = Vec::new();
+
+    for cn in child_nodes {
+        if let Err(e) = match cn.r#type {
+            "table" => parse_publication_table_definition(cn).and_then(|v| Ok(tables.push(v))),
+            _ => Err(Error::new_unexpected_node(cn)),
+        } {
+            errors.push(e);
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(Error::new_has_errors(n, errors));
+    }
+
+    Ok(Publication { name, tables })
+}
+
+// `table schema.name`, either bare or with a column list and a row filter
+fn parse_publication_table_definition(n: &Node) -> Result<PublicationTable, Error> {
+    let reference = coerce_name(n)?;
+    let (schema_name, table_name) = match reference.split_once('.') {
+        Some((schema, table)) if !table.contains('.') => (schema.to_owned(), table.to_owned()),
+        _ => return Err(Error::new_invalid_table_reference(reference)),
+    };
+
+    let mut errors: Vec<Error> = Vec::new();
+    let mut columns: Vec<String> = Vec::new();
+    let mut row_filter: Option<String> = None;
+
+    if n.child.is_some() {
+        for cn in coerce_nodes(n)? {
+            if let Err(e) = match cn.r#type {
+                "columns" => coerce_string_varargs_value(cn).and_then(|v| Ok(columns = v)),
+                "where" => coerce_string_value(cn).and_then(|v| Ok(row_filter = Some(v))),
+                _ => Err(Error::new_unexpected_node(cn)),
+            } {
+                errors.push(e);
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(Error::new_has_errors(n, errors));
+    }
+
+    Ok(PublicationTable {
+        schema_name,
+        table_name,
+        columns,
+        row_filter,
+    })
+}
+
+pub fn render_publication_definition(def: &Publication) -> Node {
+    let mut subnodes: Vec<Node> = Vec::new();
+
+    for table in &def.tables {
+        let mut table_nodes: Vec<Node> = Vec::new();
+        if !table.columns.is_empty() {
+            table_nodes.push(Node::new("columns").with_string_varargs_value(table.columns.clone()));
+        }
+        if let Some(row_filter) = &table.row_filter {
+            table_nodes.push(Node::new("where").with_string_value(row_filter.clone()));
+        }
+
+        let node =
+            Node::new("table").with_name(format!("{}.{}", table.schema_name, table.table_name));
+        if table_nodes.is_empty() {
+            subnodes.push(node);
+        } else {
+            subnodes.push(node.with_nodes(table_nodes));
+        }
+    }
+
+    Node::new("publication")
+        .with_name(def.name.clone())
+        .with_nodes(subnodes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_render_publication_definition() {
+        let node = Node::new("publication")
+            .with_name("warehouse".to_owned())
+            .with_nodes(vec![
+                Node::new("table")
+                    .with_name("public.orders".to_owned())
+                    .with_nodes(vec![
+                        Node::new("columns")
+                            .with_string_varargs_value(vec!["id".to_owned(), "total".to_owned()]),
+                        Node::new("where").with_string_value("(total > 0)".to_owned()),
+                    ]),
+                Node::new("table").with_name("sales.customers".to_owned()),
+            ]);
+        let def = Publication {
+            name: "warehouse".to_owned(),
+            tables: vec![
+                PublicationTable {
+                    schema_name: "public".to_owned(),
+                    table_name: "orders".to_owned(),
+                    columns: vec!["id".to_owned(), "total".to_owned()],
+                    row_filter: Some("(total > 0)".to_owned()),
+                },
+                PublicationTable {
+                    schema_name: "sales".to_owned(),
+                    table_name: "customers".to_owned(),
+                    columns: vec![],
+                    row_filter: None,
+                },
+            ],
+        };
+        let got_def = parse_publication_definition(&node).unwrap();
+        assert_eq!(got_def, def);
+        let got_node = render_publication_definition(&def);
+        assert_eq!(got_node, node);
+    }
+}
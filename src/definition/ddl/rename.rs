@@ -0,0 +1,133 @@
+use crate::{
+    definition::{base::Node, ddl::coerce::coerce_name},
+    snapshot::Rename,
+};
+
+use super::error::Error;
+
+/// Collects the rename hints of a schema and everything defined in
+/// it, outer objects first, so each hint names its parents by their new names.
+pub fn parse_rename_definitions(n: &Node) -> Result<Vec<Rename>, Error> {
+    assert_eq!(n.r#type, "schema");
+
+    let schema = coerce_name(n)?;
+    let mut renames: Vec<Rename> = vec![];
+
+    if let Some(from) = get_renamed_from(n) {
+        renames.push(Rename::schema(&from, &schema));
+    }
+
+    for rn in n.get_nodes().into_iter().flatten() {
+        if !matches!(
+            rn.r#type,
+            "table" | "view" | "materialized view" | "sequence" | "index"
+        ) {
+            continue;
+        }
+        let relation = coerce_name(rn)?;
+        if let Some(from) = get_renamed_from(rn) {
+            renames.push(Rename::relation(&schema, &from, &relation));
+        }
+        if rn.r#type != "table" {
+            continue;
+        }
+
+        for cn in rn.get_nodes().into_iter().flatten() {
+            let Some(from) = get_renamed_from(cn) else {
+                continue;
+            };
+            let name = coerce_name(cn)?;
+            match cn.r#type {
+                "column" => renames.push(Rename::column(&schema, &relation, &from, &name)),
+                "constraint" => renames.push(Rename::constraint(&schema, &relation, &from, &name)),
+                _ => {}
+            }
+        }
+    }
+
+    Ok(renames)
+}
+
+// either inline as `new_name (was old_name)` or a `renamed from` attribute
+fn get_renamed_from(n: &Node) -> Option<String> {
+    if let Some(from) = n.get_renamed_from() {
+        return Some(from);
+    }
+    n.get_nodes()?
+        .iter()
+        .find(|cn| cn.r#type == "renamed from")
+        .and_then(|cn| cn.get_string_value())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rename_definitions() {
+        let node = Node::new("schema")
+            .with_name("app".to_owned())
+            .with_nodes(vec![
+                Node::new("renamed from").with_string_value("legacy".to_owned()),
+                Node::new("table")
+                    .with_name("account".to_owned())
+                    .with_nodes(vec![
+                        Node::new("renamed from").with_string_value("user".to_owned()),
+                        Node::new("column")
+                            .with_name("email".to_owned())
+                            .with_nodes(vec![
+                                Node::new("type").with_string_value("text".to_owned()),
+                                Node::new("renamed from").with_string_value("mail".to_owned()),
+                            ]),
+                        Node::new("column")
+                            .with_name("id".to_owned())
+                            .with_string_value("uuid".to_owned()),
+                        Node::new("constraint")
+                            .with_name("account_pkey".to_owned())
+                            .with_nodes(vec![
+                                Node::new("primary key").with_string_value("id".to_owned()),
+                                Node::new("renamed from").with_string_value("user_pkey".to_owned()),
+                            ]),
+                    ]),
+                Node::new("index")
+                    .with_name("account_email_idx".to_owned())
+                    .with_nodes(vec![
+                        Node::new("on")
+                            .with_name("account".to_owned())
+                            .with_string_value("email".to_owned()),
+                        Node::new("renamed from").with_string_value("user_mail_idx".to_owned()),
+                    ]),
+            ]);
+
+        assert_eq!(
+            parse_rename_definitions(&node).unwrap(),
+            vec![
+                Rename::schema("legacy", "app"),
+                Rename::relation("app", "user", "account"),
+                Rename::column("app", "account", "mail", "email"),
+                Rename::constraint("app", "account", "user_pkey", "account_pkey"),
+                Rename::relation("app", "user_mail_idx", "account_email_idx"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_inline_rename_definitions() {
+        let node = Node::new("schema")
+            .with_name("app (was legacy)".to_owned())
+            .with_nodes(vec![Node::new("table")
+                .with_name("account (was user)".to_owned())
+                .with_nodes(vec![Node::new("column")
+                    .with_name("email (was mail)".to_owned())
+                    .with_string_value("text".to_owned())])]);
+
+        assert_eq!(
+            parse_rename_definitions(&node).unwrap(),
+            vec![
+                Rename::schema("legacy", "app"),
+                Rename::relation("app", "user", "account"),
+                Rename::column("app", "account", "mail", "email"),
+            ]
+        );
+    }
+}
@@ -1,23 +1,32 @@
 use crate::{
     definition::{
         base::Node,
+        defaults,
         ddl::{
-            coerce::{coerce_name, coerce_nodes},
+            coerce::{coerce_name, coerce_nodes, coerce_string_value},
             context::Context,
             function::parse_function_definition,
+            grant::{
+                parse_default_privileges_definition, parse_grant_definition,
+                parse_revoke_definition, render_default_privileges_definitions,
+                render_grant_definitions, render_revoke_definitions,
+            },
             index::parse_index_definition,
+            materializedview::parse_materialized_view_definition,
+            procedure::parse_procedure_definition,
             sequence::parse_sequence_definition,
+            serial::{collapse_serial_columns, parse_serial_sequence_definitions},
             table::parse_table_definition,
             view::parse_view_definition,
         },
     },
-    snapshot::{Function, Relation, Schema},
+    snapshot::{insert_grant, DefaultGrant, Function, Grant, Procedure, Relation, Schema},
 };
 
 use super::{
     error::Error, function::render_function_definition, index::render_index_definition,
-    sequence::render_sequence_definition, table::render_table_definition,
-    view::render_view_definition,
+    materializedview::render_materialized_view_definition, procedure::render_procedure_definition, sequence::render_sequence_definition,
+    table::render_table_definition, view::render_view_definition,
 };
 
 pub fn parse_schema_definition(n: &Node) -> Result<Schema, Error> {
@@ -31,15 +40,26 @@ pub fn parse_schema_definition(n: &Node) -> Result<Schema, Error> {
 
     let mut relations: Vec<Relation> = vec![];
     let mut functions: Vec<Function> = vec![];
+    let mut procedures: Vec<Procedure> = vec![];
+    let mut owner: Option<String> = None;
+    let mut comment: Option<String> = None;
+    let mut grants: Vec<Grant> = vec![];
+    let mut revokes: Vec<Grant> = vec![];
+    let mut default_grants: Vec<DefaultGrant> = vec![];
 
     for cn in child_nodes {
         if let Err(e) = match cn.r#type {
-            "table" => {
-                parse_table_definition(&name, cn).and_then(|def| Ok(relations.push(def.into())))
-            }
+            "table" => parse_table_definition(&name, cn).and_then(|def| {
+                let sequences = parse_serial_sequence_definitions(&def, cn)?;
+                relations.push(def.into());
+                relations.extend(sequences.into_iter().map(|s| s.into()));
+                Ok(())
+            }),
             "view" => {
                 parse_view_definition(&ctx, cn).and_then(|def| Ok(relations.push(def.into())))
             }
+            "materialized view" => parse_materialized_view_definition(&ctx, cn)
+                .and_then(|def| Ok(relations.push(def.into()))),
             "sequence" => {
                 parse_sequence_definition(&ctx, cn).and_then(|def| Ok(relations.push(def.into())))
             }
@@ -49,6 +69,31 @@ pub fn parse_schema_definition(n: &Node) -> Result<Schema, Error> {
             "function" => {
                 parse_function_definition(&ctx, cn).and_then(|def| Ok(functions.push(def)))
             }
+            "procedure" => {
+                parse_procedure_definition(&ctx, cn).and_then(|def| Ok(procedures.push(def)))
+            }
+            "owner" => coerce_string_value(cn).and_then(|v| Ok(owner = Some(v))),
+            "comment" => coerce_string_value(cn).and_then(|v| Ok(comment = Some(v))),
+            // rename hints are collected by parse_rename_definitions
+            "renamed from" => coerce_string_value(cn).and_then(|_| Ok(())),
+            "grant" => parse_grant_definition("schema", cn).and_then(|v| {
+                for grant in v {
+                    insert_grant(&mut grants, grant);
+                }
+                Ok(())
+            }),
+            "revoke" => parse_revoke_definition("schema", cn).and_then(|v| {
+                for revoke in v {
+                    insert_grant(&mut revokes, revoke);
+                }
+                Ok(())
+            }),
+            "default privileges on" => parse_default_privileges_definition(cn).and_then(|v| {
+                for grant in v {
+                    insert_grant(&mut default_grants, grant);
+                }
+                Ok(())
+            }),
             _ => Err(Error::new_unexpected_node(cn)),
         } {
             errors.push(e);
@@ -60,12 +105,22 @@ pub fn parse_schema_definition(n: &Node) -> Result<Schema, Error> {
     }
 
     let mut schema = Schema::new(&name);
+    schema.owner = owner;
+    if let Some(comment) = comment {
+        schema.comment = comment;
+    }
+    schema.grants = grants;
+    schema.revokes = revokes;
+    schema.default_grants = default_grants;
     for relation in relations {
         schema.add_relation(relation).unwrap();
     }
     for function in functions {
         schema.add_function(function).unwrap();
     }
+    for procedure in procedures {
+        schema.add_procedure(procedure).unwrap();
+    }
 
     Ok(schema)
 }
@@ -73,8 +128,20 @@ pub fn parse_schema_definition(n: &Node) -> Result<Schema, Error> {
 pub fn render_schema_definition(def: &Schema) -> Node {
     let mut subnodes: Vec<Node> = Vec::new();
 
+    let def = &collapse_serial_columns(def);
+
     let ctx = &Context::new(def.name.clone());
 
+    if let Some(owner) = &def.owner {
+        subnodes.push(Node::new("owner").with_string_value(owner.clone()));
+    }
+    if def.comment != defaults::get_default_description() {
+        subnodes.push(Node::new("comment").with_string_value(def.comment.clone()));
+    }
+    subnodes.extend(render_grant_definitions("schema", &def.grants));
+    subnodes.extend(render_revoke_definitions("schema", &def.revokes));
+    subnodes.extend(render_default_privileges_definitions(&def.default_grants));
+
     for relation in def.iter_relations() {
         match relation {
             Relation::Table(table) => {
@@ -83,6 +150,9 @@ pub fn render_schema_definition(def: &Schema) -> Node {
             Relation::View(view) => {
                 subnodes.push(render_view_definition(ctx, view));
             }
+            Relation::MaterializedView(view) => {
+                subnodes.push(render_materialized_view_definition(ctx, view));
+            }
             Relation::Index(index) => {
                 subnodes.push(render_index_definition(ctx, index));
             }
@@ -94,6 +164,9 @@ pub fn render_schema_definition(def: &Schema) -> Node {
     for function in def.iter_functions() {
         subnodes.push(render_function_definition(ctx, function));
     }
+    for procedure in def.iter_procedures() {
+        subnodes.push(render_procedure_definition(ctx, procedure));
+    }
 
     return Node::new("schema")
         .with_name(def.name.clone())
@@ -7,10 +7,17 @@ use crate::{
         base::Node,
         defaults,
     },
-    snapshot::Sequence,
+    snapshot::{insert_grant, Grant, Sequence},
 };
 
-use super::{context::Context, error::Error};
+use super::{
+    context::Context,
+    error::Error,
+    grant::{
+        parse_grant_definition, parse_revoke_definition, render_grant_definitions,
+        render_revoke_definitions,
+    },
+};
 
 pub fn parse_sequence_definition(ctx: &Context, n: &Node) -> Result<Sequence, Error> {
     assert_eq!(n.r#type, "sequence");
@@ -28,6 +35,10 @@ pub fn parse_sequence_definition(ctx: &Context, n: &Node) -> Result<Sequence, Er
     let mut cycle: Option<bool> = None;
     let mut owned_by_table: Option<String> = None;
     let mut owned_by_column: Option<String> = None;
+    let mut owner: Option<String> = None;
+    let mut comment: Option<String> = None;
+    let mut grants: Vec<Grant> = vec![];
+    let mut revokes: Vec<Grant> = vec![];
 
     for d in child_nodes {
         if let Err(e) = match d.r#type {
@@ -43,6 +54,21 @@ pub fn parse_sequence_definition(ctx: &Context, n: &Node) -> Result<Sequence, Er
                 owned_by_column = Some(v);
                 Ok(())
             }),
+            "owner" => coerce_string_value(d).and_then(|v| Ok(owner = Some(v))),
+            "comment" => coerce_string_value(d).and_then(|v| Ok(comment = Some(v))),
+            "renamed from" => coerce_string_value(d).and_then(|_| Ok(())),
+            "grant" => parse_grant_definition("sequence", d).and_then(|v| {
+                for grant in v {
+                    insert_grant(&mut grants, grant);
+                }
+                Ok(())
+            }),
+            "revoke" => parse_revoke_definition("sequence", d).and_then(|v| {
+                for revoke in v {
+                    insert_grant(&mut revokes, revoke);
+                }
+                Ok(())
+            }),
             _ => Err(Error::new_unexpected_node(d)),
         } {
             errors.push(e);
@@ -80,6 +106,9 @@ pub fn parse_sequence_definition(ctx: &Context, n: &Node) -> Result<Sequence, Er
     if cycle.is_none() {
         cycle = Some(defaults::get_sequence_cycle());
     }
+    if comment.is_none() {
+        comment = Some(defaults::get_default_description());
+    }
 
     if !errors.is_empty() {
         return Err(Error::new_has_errors(n, errors));
@@ -97,6 +126,10 @@ pub fn parse_sequence_definition(ctx: &Context, n: &Node) -> Result<Sequence, Er
         cycle: cycle.unwrap(),
         owned_by_table: owned_by_table.clone(),
         owned_by_column: owned_by_column.clone(),
+        owner,
+        comment: comment.unwrap(),
+        grants,
+        revokes,
     })
 }
 
@@ -131,6 +164,14 @@ pub fn render_sequence_definition(_ctx: &Context, def: &Sequence) -> Node {
                 .with_string_value(def.owned_by_column.as_ref().unwrap().clone()),
         );
     }
+    if let Some(owner) = &def.owner {
+        subnodes.push(Node::new("owner").with_string_value(owner.clone()));
+    }
+    if def.comment != defaults::get_default_description() {
+        subnodes.push(Node::new("comment").with_string_value(def.comment.clone()));
+    }
+    subnodes.extend(render_grant_definitions("sequence", &def.grants));
+    subnodes.extend(render_revoke_definitions("sequence", &def.revokes));
 
     Node::new("sequence")
         .with_name(def.name.clone())
@@ -169,6 +210,10 @@ mod test {
             cycle: true,
             owned_by_table: Some("user".to_owned()),
             owned_by_column: Some("id".to_owned()),
+            owner: None,
+            comment: defaults::get_default_description(),
+            grants: vec![],
+            revokes: vec![],
         };
         let ctx = Context {
             schema_name: "public".to_owned(),
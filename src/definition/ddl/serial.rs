@@ -0,0 +1,233 @@
+use crate::{
+    definition::{base::Node, ddl::coerce::coerce_name, defaults},
+    snapshot::{Relation, Schema, Sequence, Table},
+};
+
+use super::{column::parse_column_serial_type, error::Error};
+
+/// The integer type behind `serial`, `bigserial`, `smallserial` and their
+/// `serialN` spellings.
+pub fn parse_serial_type(data_type: &str) -> Option<&'static str> {
+    match data_type.to_lowercase().as_str() {
+        "smallserial" | "serial2" => Some("int2"),
+        "serial" | "serial4" => Some("int4"),
+        "bigserial" | "serial8" => Some("int8"),
+        _ => None,
+    }
+}
+
+fn render_serial_type(data_type: &str) -> Option<&'static str> {
+    match data_type {
+        "int2" => Some("smallserial"),
+        "int4" => Some("serial"),
+        "int8" => Some("bigserial"),
+        _ => None,
+    }
+}
+
+/// Name of the sequence PostgreSQL creates for a serial column. Like
+/// `makeObjectName`, the longer of the table and column names is cut a byte at
+/// a time until the name fits in 63 bytes.
+pub fn get_serial_sequence_name(table: &str, column: &str) -> String {
+    let available = 63 - "_".len() - "_seq".len();
+    let (mut table_len, mut column_len) = (table.len(), column.len());
+    while table_len + column_len > available {
+        if table_len > column_len {
+            table_len -= 1;
+        } else {
+            column_len -= 1;
+        }
+    }
+    format!(
+        "{}_{}_seq",
+        clip_to_char_boundary(table, table_len),
+        clip_to_char_boundary(column, column_len)
+    )
+}
+
+// The longest prefix of `name` no longer than `len` bytes.
+fn clip_to_char_boundary(name: &str, mut len: usize) -> &str {
+    while !name.is_char_boundary(len) {
+        len -= 1;
+    }
+    &name[..len]
+}
+
+/// Default of a serial column, spelled the way introspection reads it back,
+/// with the sequence qualified unless it lives in `public`.
+pub fn get_serial_default(schema: &str, sequence: &str) -> String {
+    let name = match schema {
+        "public" => regclass_identifier(sequence),
+        _ => format!(
+            "{}.{}",
+            regclass_identifier(schema),
+            regclass_identifier(sequence)
+        ),
+    };
+    format!("nextval('{}'::regclass)", name.replace('\'', "''"))
+}
+
+fn regclass_identifier(name: &str) -> String {
+    let plain = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase() || c == '_')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '$');
+    if plain {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    }
+}
+
+fn new_serial_sequence(table: &Table, column: &str, data_type: &str) -> Sequence {
+    let increment = defaults::get_sequence_increment();
+    let min_value = defaults::get_sequence_min_value(increment, data_type);
+    let max_value = defaults::get_sequence_max_value(increment, data_type);
+
+    Sequence {
+        schema_name: table.schema_name.clone(),
+        name: get_serial_sequence_name(&table.name, column),
+        data_type: data_type.to_string(),
+        increment,
+        min_value,
+        max_value,
+        start: defaults::get_sequence_start(increment, min_value, max_value),
+        cache: defaults::get_sequence_cache(),
+        cycle: defaults::get_sequence_cycle(),
+        owned_by_table: Some(table.name.clone()),
+        owned_by_column: Some(column.to_string()),
+        // the sequence follows the owner of its table
+        owner: table.owner.clone(),
+        comment: defaults::get_default_description(),
+        grants: vec![],
+        revokes: vec![],
+    }
+}
+
+/// Sequences owned by the serial columns of the parsed table `n`.
+pub fn parse_serial_sequence_definitions(table: &Table, n: &Node) -> Result<Vec<Sequence>, Error> {
+    assert_eq!(n.r#type, "table");
+
+    let mut sequences: Vec<Sequence> = vec![];
+    for cn in n.get_nodes().into_iter().flatten() {
+        if cn.r#type != "column" {
+            continue;
+        }
+        if let Some(data_type) = parse_column_serial_type(cn) {
+            let column = coerce_name(cn)?;
+            sequences.push(new_serial_sequence(table, &column, data_type));
+        }
+    }
+
+    Ok(sequences)
+}
+
+/// Folds the columns of `def` filled by a sequence of their own back into
+/// serial columns, leaving the sequences out.
+pub fn collapse_serial_columns(def: &Schema) -> Schema {
+    let mut schema = def.clone();
+
+    for table in def.iter_relations().filter_map(|r| r.as_table().ok()) {
+        for column in table.iter_columns() {
+            let Some(serial_type) = render_serial_type(&column.data_type) else {
+                continue;
+            };
+            let sequence_name = get_serial_sequence_name(&table.name, &column.name);
+            let sequence = def
+                .get_relation(&sequence_name)
+                .and_then(|r| r.as_sequence())
+                .ok();
+            if !column.not_null
+                || column.default != Some(get_serial_default(&def.name, &sequence_name))
+                || sequence != Some(&new_serial_sequence(table, &column.name, &column.data_type))
+            {
+                continue;
+            }
+
+            schema.remove_relation(&sequence_name).unwrap();
+            let Ok(Relation::Table(table)) = schema.get_relation_mut(&table.name) else {
+                continue;
+            };
+            let column = table.get_column_mut(&column.name).unwrap();
+            column.data_type = serial_type.to_string();
+            column.not_null = false;
+            column.default = None;
+        }
+    }
+
+    schema
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::definition::ddl::{parse_schema_definition, render_schema_definition};
+
+    #[test]
+    fn test_parse_and_render_serial_columns() {
+        let node = Node::new("schema")
+            .with_name("app".to_owned())
+            .with_nodes(vec![Node::new("table")
+                .with_name("user".to_owned())
+                .with_nodes(vec![
+                    Node::new("column")
+                        .with_name("id".to_owned())
+                        .with_string_value("bigserial".to_owned()),
+                    Node::new("column")
+                        .with_name("rank".to_owned())
+                        .with_string_value("serial".to_owned()),
+                ])]);
+
+        let schema = parse_schema_definition(&node).unwrap();
+        let table = schema.get_relation("user").unwrap().as_table().unwrap();
+        let id = table.get_column("id").unwrap();
+        assert_eq!(id.data_type, "int8");
+        assert!(id.not_null);
+        assert_eq!(
+            id.default,
+            Some("nextval('app.user_id_seq'::regclass)".to_owned())
+        );
+        let sequence = schema
+            .get_relation("user_rank_seq")
+            .unwrap()
+            .as_sequence()
+            .unwrap();
+        assert_eq!(sequence.data_type, "int4");
+        assert_eq!(sequence.max_value, 2147483647);
+        assert_eq!(sequence.owned_by_table, Some("user".to_owned()));
+        assert_eq!(sequence.owned_by_column, Some("rank".to_owned()));
+
+        assert_eq!(render_schema_definition(&schema), node);
+    }
+
+    #[test]
+    fn test_serial_sequence_name() {
+        assert_eq!(get_serial_sequence_name("user", "id"), "user_id_seq");
+
+        // the longer name gives way first, the column on a tie
+        let name = get_serial_sequence_name(&"t".repeat(70), "id");
+        assert_eq!(name, format!("{}_id_seq", "t".repeat(56)));
+        assert_eq!(name.len(), 63);
+        let name = get_serial_sequence_name(&"t".repeat(40), &"c".repeat(40));
+        assert_eq!(name, format!("{}_{}_seq", "t".repeat(29), "c".repeat(29)));
+
+        // without splitting a character
+        let name = get_serial_sequence_name(&"é".repeat(40), "id");
+        assert_eq!(name, format!("{}_id_seq", "é".repeat(28)));
+    }
+
+    #[test]
+    fn test_serial_default() {
+        assert_eq!(
+            get_serial_default("public", "user_id_seq"),
+            "nextval('user_id_seq'::regclass)"
+        );
+        assert_eq!(
+            get_serial_default("App", "user_id_seq"),
+            "nextval('\"App\".user_id_seq'::regclass)"
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{
+    definition::{
+        base::Node,
+        ddl::coerce::{
+            coerce_integer_value, coerce_name, coerce_nodes, coerce_string_varargs_value,
+        },
+        defaults,
+    },
+    snapshot::Statistics,
+};
+
+use super::{context::Context, error::Error};
+
+pub fn parse_statistics_definition(
+    schema: &str,
+    table: &str,
+    n: &Node,
+) -> Result<Statistics, Error> {
+    assert_eq!(n.r#type, "statistics");
+
+    let name = coerce_name(n)?;
+    let child_nodes = coerce_nodes(n)?;
+    let mut errors: Vec<Error> = Vec::new();
+
+    let mut kinds: Option<Vec<String>> = None;
+    let mut expressions: Option<Vec<String>> = None;
+    let mut target: Option<i64> = None;
+
+    for cn in child_nodes {
+        if let Err(e) = match cn.r#type {
+            "kinds" => coerce_string_varargs_value(cn)
+                .and_then(|v| Ok(kinds = Some(v.iter().map(|k| k.to_lowercase()).collect()))),
+            "on" => coerce_string_varargs_value(cn).and_then(|v| Ok(expressions = Some(v))),
+            "target" => coerce_integer_value(cn).and_then(|v| Ok(target = Some(v))),
+            _ => Err(Error::new_unexpected_node(cn)),
+        } {
+            errors.push(e);
+        }
+    }
+
+    if expressions.is_none() {
+        errors.push(Error::new_attribute_required(n, "on"));
+    }
+    if kinds.is_none() {
+        kinds = Some(defaults::get_statistics_kinds());
+    }
+
+    if !errors.is_empty() {
+        return Err(Error::new_has_errors(n, errors));
+    }
+
+    Ok(Statistics {
+        schema_name: schema.to_owned(),
+        table_name: table.to_owned(),
+        name,
+        kinds: kinds.unwrap(),
+        expressions: expressions.unwrap(),
+        target,
+    })
+}
+
+pub fn render_statistics_definition(_ctx: &Context, def: &Statistics) -> Node {
+    let mut subnodes: Vec<Node> = Vec::new();
+
+    if def.kinds != defaults::get_statistics_kinds() {
+        subnodes.push(Node::new("kinds").with_string_varargs_value(def.kinds.clone()));
+    }
+    subnodes.push(Node::new("on").with_string_varargs_value(def.expressions.clone()));
+    if let Some(target) = def.target {
+        subnodes.push(Node::new("target").with_number_integer_value(target));
+    }
+
+    Node::new("statistics")
+        .with_name(def.name.clone())
+        .with_nodes(subnodes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_render_statistics_definition() {
+        let node = Node::new("statistics")
+            .with_name("order_stats".to_owned())
+            .with_nodes(vec![
+                Node::new("kinds").with_string_varargs_value(vec![
+                    "ndistinct".to_owned(),
+                    "dependencies".to_owned(),
+                ]),
+                Node::new("on")
+                    .with_string_varargs_value(vec!["city".to_owned(), "(lower(zip))".to_owned()]),
+                Node::new("target").with_number_integer_value(1000),
+            ]);
+
+        let def = Statistics {
+            schema_name: "public".to_owned(),
+            table_name: "order".to_owned(),
+            name: "order_stats".to_owned(),
+            kinds: vec!["ndistinct".to_owned(), "dependencies".to_owned()],
+            expressions: vec!["city".to_owned(), "(lower(zip))".to_owned()],
+            target: Some(1000),
+        };
+
+        assert_eq!(
+            parse_statistics_definition("public", "order", &node).unwrap(),
+            def
+        );
+        assert_eq!(
+            render_statistics_definition(&Context::default(), &def),
+            node
+        );
+    }
+}
@@ -1,9 +1,17 @@
 use crate::{
     definition::{
         base::Node,
-        ddl::coerce::{coerce_name, coerce_nodes},
+        ddl::coerce::{
+            coerce_boolean_value, coerce_name, coerce_name_and_string_value,
+            coerce_name_and_string_varargs_value, coerce_nodes, coerce_string_value,
+            coerce_string_varargs_value,
+        },
+        defaults,
+    },
+    snapshot::{
+        insert_grant, Column, Constraint, Grant, Inheritance, PartitionBound, PartitionKey, Policy,
+        Statistics, Table,
     },
-    snapshot::{Column, Constraint, Table},
 };
 
 use super::{
@@ -11,6 +19,13 @@ use super::{
     constraint::{parse_constraint_definition, render_constraint_definition},
     context::Context,
     error::Error,
+    foreignkey::{parse_table_reference, render_table_reference},
+    grant::{
+        parse_grant_definition, parse_revoke_definition, render_grant_definitions,
+        render_revoke_definitions,
+    },
+    policy::{parse_policy_definition, render_policy_definition},
+    statistics::{parse_statistics_definition, render_statistics_definition},
 };
 
 pub fn parse_table_definition(schema_name: &str, n: &Node) -> Result<Table, Error> {
@@ -22,6 +37,21 @@ pub fn parse_table_definition(schema_name: &str, n: &Node) -> Result<Table, Erro
 
     let mut columns: Vec<Column> = vec![];
     let mut constraints: Vec<Constraint> = vec![];
+    let mut partition_by: Option<PartitionKey> = None;
+    let mut partition_of: Option<PartitionBound> = None;
+    let mut inherits: Vec<Inheritance> = vec![];
+    let mut unlogged: Option<bool> = None;
+    let mut storage_parameters: Vec<String> = vec![];
+    let mut tablespace: Option<String> = None;
+    let mut replica_identity: Option<(String, Option<String>)> = None;
+    let mut row_level_security: Option<bool> = None;
+    let mut force_row_level_security: Option<bool> = None;
+    let mut policies: Vec<Policy> = vec![];
+    let mut statistics: Vec<Statistics> = vec![];
+    let mut owner: Option<String> = None;
+    let mut comment: Option<String> = None;
+    let mut grants: Vec<Grant> = vec![];
+    let mut revokes: Vec<Grant> = vec![];
 
     for d in child_node {
         if let Err(e) = match d.r#type {
@@ -31,6 +61,61 @@ pub fn parse_table_definition(schema_name: &str, n: &Node) -> Result<Table, Erro
             "constraint" => {
                 parse_constraint_definition(schema_name, &name, d).and_then(|def| Ok(constraints.push(def)))
             }
+            "partition by" => coerce_name_and_string_varargs_value(d).and_then(|(strategy, v)| {
+                Ok(partition_by = Some(PartitionKey {
+                    strategy: strategy.to_lowercase(),
+                    key_expressions: v,
+                }))
+            }),
+            "partition of" => coerce_name_and_string_value(d).and_then(|(parent, v)| {
+                let (parent_schema, parent) = parse_table_reference(schema_name, &parent)?;
+                Ok(partition_of = Some(PartitionBound {
+                    parent_schema: parent_schema,
+                    parent: parent,
+                    bound: v,
+                }))
+            }),
+            "inherits" => coerce_string_varargs_value(d).and_then(|v| {
+                for parent in v {
+                    let (parent_schema, parent) = parse_table_reference(schema_name, &parent)?;
+                    inherits.push(Inheritance {
+                        parent_schema,
+                        parent,
+                    });
+                }
+                Ok(())
+            }),
+            "unlogged" => coerce_boolean_value(d).and_then(|v| Ok(unlogged = Some(v))),
+            "with" => coerce_string_varargs_value(d).and_then(|v| Ok(storage_parameters = v)),
+            "tablespace" => coerce_string_value(d).and_then(|v| Ok(tablespace = Some(v))),
+            "replica identity" => coerce_string_value(d)
+                .and_then(|v| parse_replica_identity(d, &v))
+                .and_then(|v| Ok(replica_identity = Some(v))),
+            "row level security" => {
+                coerce_boolean_value(d).and_then(|v| Ok(row_level_security = Some(v)))
+            }
+            "force row level security" => {
+                coerce_boolean_value(d).and_then(|v| Ok(force_row_level_security = Some(v)))
+            }
+            "policy" => parse_policy_definition(schema_name, &name, d)
+                .and_then(|def| Ok(policies.push(def))),
+            "statistics" => parse_statistics_definition(schema_name, &name, d)
+                .and_then(|def| Ok(statistics.push(def))),
+            "owner" => coerce_string_value(d).and_then(|v| Ok(owner = Some(v))),
+            "comment" => coerce_string_value(d).and_then(|v| Ok(comment = Some(v))),
+            "renamed from" => coerce_string_value(d).and_then(|_| Ok(())),
+            "grant" => parse_grant_definition("table", d).and_then(|v| {
+                for grant in v {
+                    insert_grant(&mut grants, grant);
+                }
+                Ok(())
+            }),
+            "revoke" => parse_revoke_definition("table", d).and_then(|v| {
+                for revoke in v {
+                    insert_grant(&mut revokes, revoke);
+                }
+                Ok(())
+            }),
             _ => Err(Error::new_unexpected_node(d)),
         } {
             errors.push(e);
@@ -42,16 +127,58 @@ pub fn parse_table_definition(schema_name: &str, n: &Node) -> Result<Table, Erro
     }
 
     let mut table = Table::new(schema_name, &name);
+    table.partition_by = partition_by;
+    table.partition_of = partition_of;
+    table.inherits = inherits;
+    table.unlogged = unlogged.unwrap_or_else(defaults::get_table_unlogged);
+    table.storage_parameters = storage_parameters;
+    table.tablespace = tablespace;
+    if let Some((identity, index)) = replica_identity {
+        table.replica_identity = identity;
+        table.replica_identity_index = index;
+    }
+    table.row_level_security =
+        row_level_security.unwrap_or_else(defaults::get_table_row_level_security);
+    table.force_row_level_security =
+        force_row_level_security.unwrap_or_else(defaults::get_table_force_row_level_security);
+    table.owner = owner;
+    if let Some(comment) = comment {
+        table.comment = comment;
+    }
+    table.grants = grants;
+    table.revokes = revokes;
     for column in columns {
         table.add_column(column).unwrap();
     }
     for pk in constraints {
         table.add_constraint(pk).unwrap();
     }
+    for policy in policies {
+        table.add_policy(policy).unwrap();
+    }
+    for statistics in statistics {
+        table.add_statistics(statistics).unwrap();
+    }
 
     Ok(table)
 }
 
+// `default`, `full`, `nothing` or `using index <name>`
+fn parse_replica_identity(n: &Node, v: &str) -> Result<(String, Option<String>), Error> {
+    let lowered = v.to_lowercase();
+    if let Some(index) = lowered.strip_prefix("using index ") {
+        let index = v[v.len() - index.len()..].trim();
+        return Ok(("index".to_owned(), Some(index.to_owned())));
+    }
+    match lowered.as_str() {
+        "default" | "full" | "nothing" => Ok((lowered, None)),
+        _ => Err(Error::new_invalid_type_attribute(
+            n,
+            "default | full | nothing | using index <name>",
+        )),
+    }
+}
+
 pub fn render_table_definition(ctx: &Context, def: &Table) -> Node {
     let mut subnodes: Vec<Node> = Vec::new();
 
@@ -61,6 +188,72 @@ pub fn render_table_definition(ctx: &Context, def: &Table) -> Node {
     for constraint in def.iter_constraints() {
         subnodes.push(render_constraint_definition(ctx, constraint));
     }
+    if let Some(partition_by) = &def.partition_by {
+        subnodes.push(
+            Node::new("partition by")
+                .with_name(partition_by.strategy.clone())
+                .with_string_varargs_value(partition_by.key_expressions.clone()),
+        );
+    }
+    if let Some(partition_of) = &def.partition_of {
+        subnodes.push(
+            Node::new("partition of")
+                .with_name(render_table_reference(
+                    ctx,
+                    &partition_of.parent_schema,
+                    &partition_of.parent,
+                ))
+                .with_string_value(partition_of.bound.clone()),
+        );
+    }
+    if !def.inherits.is_empty() {
+        subnodes.push(
+            Node::new("inherits").with_string_varargs_value(
+                def.inherits
+                    .iter()
+                    .map(|p| render_table_reference(ctx, &p.parent_schema, &p.parent))
+                    .collect(),
+            ),
+        );
+    }
+    if def.unlogged != defaults::get_table_unlogged() {
+        subnodes.push(Node::new("unlogged").with_boolean_value(def.unlogged));
+    }
+    if !def.storage_parameters.is_empty() {
+        subnodes.push(Node::new("with").with_string_varargs_value(def.storage_parameters.clone()));
+    }
+    if let Some(tablespace) = &def.tablespace {
+        subnodes.push(Node::new("tablespace").with_string_value(tablespace.clone()));
+    }
+    if def.replica_identity != defaults::get_table_replica_identity() {
+        let identity = match &def.replica_identity_index {
+            Some(index) => format!("using index {}", index),
+            None => def.replica_identity.clone(),
+        };
+        subnodes.push(Node::new("replica identity").with_string_value(identity));
+    }
+    if def.row_level_security != defaults::get_table_row_level_security() {
+        subnodes.push(Node::new("row level security").with_boolean_value(def.row_level_security));
+    }
+    if def.force_row_level_security != defaults::get_table_force_row_level_security() {
+        subnodes.push(
+            Node::new("force row level security").with_boolean_value(def.force_row_level_security),
+        );
+    }
+    for policy in def.iter_policies() {
+        subnodes.push(render_policy_definition(ctx, policy));
+    }
+    for statistics in def.iter_statistics() {
+        subnodes.push(render_statistics_definition(ctx, statistics));
+    }
+    if let Some(owner) = &def.owner {
+        subnodes.push(Node::new("owner").with_string_value(owner.clone()));
+    }
+    if def.comment != defaults::get_default_description() {
+        subnodes.push(Node::new("comment").with_string_value(def.comment.clone()));
+    }
+    subnodes.extend(render_grant_definitions("table", &def.grants));
+    subnodes.extend(render_revoke_definitions("table", &def.revokes));
 
     return Node::new("table")
         .with_name(def.name.clone())
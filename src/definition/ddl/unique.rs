@@ -2,7 +2,8 @@ use crate::{
     definition::{
         base::Node,
         ddl::coerce::{
-            coerce_boolean_value, coerce_name, coerce_nodes, coerce_string_varargs_value,
+            coerce_boolean_value, coerce_name, coerce_nodes, coerce_string_value,
+            coerce_string_varargs_value,
         },
         defaults,
     },
@@ -25,6 +26,7 @@ pub fn parse_unique_definition(
     let mut columns: Vec<String> = Vec::new();
     let mut deferrable: Option<bool> = None;
     let mut initially_deferred: Option<bool> = None;
+    let mut comment: Option<String> = None;
 
     for cn in child_nodes {
         if let Err(e) = match cn.r#type {
@@ -33,6 +35,8 @@ pub fn parse_unique_definition(
             "initially deferred" => {
                 coerce_boolean_value(cn).and_then(|v| Ok(initially_deferred = Some(v)))
             }
+            "comment" => coerce_string_value(cn).and_then(|v| Ok(comment = Some(v))),
+            "renamed from" => coerce_string_value(cn).and_then(|_| Ok(())),
             _ => Err(Error::new_unexpected_node(cn)),
         } {
             errors.push(e);
@@ -48,6 +52,9 @@ pub fn parse_unique_definition(
     if initially_deferred.is_none() {
         initially_deferred = Some(defaults::get_constraint_initially_deferred());
     }
+    if comment.is_none() {
+        comment = Some(defaults::get_default_description());
+    }
 
     if !errors.is_empty() {
         return Err(Error::new_has_errors(n, errors));
@@ -60,6 +67,7 @@ pub fn parse_unique_definition(
         columns: columns,
         deferrable: deferrable.unwrap(),
         initially_deferred: initially_deferred.unwrap(),
+        comment: comment.unwrap(),
     })
 }
 
@@ -72,6 +80,9 @@ pub fn render_unique_definition(ctx: &Context, def: &Unique) -> Node {
     if def.initially_deferred != defaults::get_constraint_initially_deferred() {
         subnodes.push(Node::new("initially deferred").with_boolean_value(def.initially_deferred))
     }
+    if def.comment != defaults::get_default_description() {
+        subnodes.push(Node::new("comment").with_string_value(def.comment.clone()));
+    }
 
     Node::new("constraint")
         .with_name(def.name.clone())
@@ -98,6 +109,7 @@ mod test {
             columns: vec!["id".to_owned()],
             deferrable: true,
             initially_deferred: true,
+            comment: defaults::get_default_description(),
         };
         let ctx = Context {
             schema_name: "public".to_owned(),
@@ -2,6 +2,7 @@ use crate::{
     definition::{
         ddl::coerce::{coerce_name, coerce_nodes, coerce_string_value},
         base::Node,
+        defaults,
     },
     snapshot::View,
 };
@@ -16,10 +17,15 @@ pub fn parse_view_definition(ctx: &Context, n: &Node) -> Result<View, Error> {
     let mut errors: Vec<Error> = Vec::new();
 
     let mut query: Option<String> = None;
+    let mut owner: Option<String> = None;
+    let mut comment: Option<String> = None;
 
     for cn in child_nodes {
         if let Err(e) = match cn.r#type {
             "as" => coerce_string_value(cn).and_then(|v| Ok(query = Some(v))),
+            "owner" => coerce_string_value(cn).and_then(|v| Ok(owner = Some(v))),
+            "comment" => coerce_string_value(cn).and_then(|v| Ok(comment = Some(v))),
+            "renamed from" => coerce_string_value(cn).and_then(|_| Ok(())),
             _ => Err(Error::new_unexpected_node(cn)),
         } {
             errors.push(e);
@@ -29,6 +35,9 @@ pub fn parse_view_definition(ctx: &Context, n: &Node) -> Result<View, Error> {
     if query.is_none() {
         errors.push(Error::new_attribute_required(n, "as"));
     }
+    if comment.is_none() {
+        comment = Some(defaults::get_default_description());
+    }
 
     if !errors.is_empty() {
         return Err(Error::new_has_errors(n, errors));
@@ -38,6 +47,8 @@ pub fn parse_view_definition(ctx: &Context, n: &Node) -> Result<View, Error> {
         schema_name: ctx.schema_name.clone(),
         name,
         query: query.unwrap(),
+        owner,
+        comment: comment.unwrap(),
     })
 }
 
@@ -53,6 +64,12 @@ pub fn render_view_definition(ctx: &Context, def: &View) -> Node {
     if !def.query.is_empty() {
         subnodes.push(Node::new("as").with_string_value(def.query.to_owned()));
     }
+    if let Some(owner) = &def.owner {
+        subnodes.push(Node::new("owner").with_string_value(owner.clone()));
+    }
+    if def.comment != defaults::get_default_description() {
+        subnodes.push(Node::new("comment").with_string_value(def.comment.clone()));
+    }
     return node.with_nodes(subnodes);
 }
 
@@ -65,12 +82,15 @@ mod tests {
         let node = Node::new("view")
             .with_name("userview".to_owned())
             .with_nodes(vec![
-                Node::new("as").with_string_value("select * from users".to_owned())
+                Node::new("as").with_string_value("select * from users".to_owned()),
+                Node::new("owner").with_string_value("app_owner".to_owned()),
             ]);
         let def = View {
             schema_name: "public".to_owned(),
             name: "userview".to_owned(),
             query: "select * from users".to_owned(),
+            owner: Some("app_owner".to_owned()),
+            comment: defaults::get_default_description(),
         };
         let ctx = Context {
             schema_name: "public".to_owned(),
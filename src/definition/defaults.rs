@@ -18,10 +18,18 @@ pub fn get_foreign_key_delete_rule() -> String {
     "NO ACTION".to_string()
 }
 
+pub fn get_exclusion_method() -> String {
+    "gist".to_string()
+}
+
 pub fn get_index_unique() -> bool {
     false
 }
 
+pub fn get_index_nulls_not_distinct() -> bool {
+    false
+}
+
 pub fn get_default_index_method() -> String {
     "btree".to_string()
 }
@@ -38,6 +46,14 @@ pub fn get_default_index_desc_nulls() -> String {
     "FIRST".to_string()
 }
 
+pub fn get_default_index_nulls(order: &str) -> String {
+    if order == "DESC" {
+        get_default_index_desc_nulls()
+    } else {
+        get_default_index_asc_nulls()
+    }
+}
+
 pub fn get_default_column_default() -> String {
     "".to_string()
 }
@@ -58,6 +74,58 @@ pub fn get_function_volatility() -> String {
     "VOLATILE".to_string()
 }
 
+pub fn get_extension_schema() -> String {
+    "public".to_string()
+}
+
+pub fn get_materialized_view_with_data() -> bool {
+    true
+}
+
+pub fn get_materialized_view_refresh() -> bool {
+    false
+}
+
+pub fn get_policy_command() -> String {
+    "ALL".to_string()
+}
+
+pub fn get_policy_permissive() -> bool {
+    true
+}
+
+pub fn get_policy_roles() -> Vec<String> {
+    vec!["public".to_string()]
+}
+
+pub fn get_table_unlogged() -> bool {
+    false
+}
+
+pub fn get_table_row_level_security() -> bool {
+    false
+}
+
+pub fn get_table_force_row_level_security() -> bool {
+    false
+}
+
+pub fn get_procedure_arguments() -> String {
+    "".to_string()
+}
+
+pub fn get_procedure_language() -> String {
+    "sql".to_string()
+}
+
+pub fn get_statistics_kinds() -> Vec<String> {
+    vec![
+        "ndistinct".to_string(),
+        "dependencies".to_string(),
+        "mcv".to_string(),
+    ]
+}
+
 pub fn get_sequence_data_type() -> String {
     "int8".to_string()
 }
@@ -74,6 +142,16 @@ pub fn get_sequence_cycle() -> bool {
     false
 }
 
+// The sequence backing an identity column takes the column's own type.
+pub fn get_identity_data_type(column_type: &str) -> String {
+    match column_type.to_lowercase().as_str() {
+        "smallint" | "int2" => "int2",
+        "integer" | "int" | "int4" => "int4",
+        _ => "int8",
+    }
+    .to_string()
+}
+
 pub fn get_sequence_min_value(increment: i64, data_type: &str) -> i64 {
     if increment >= 0 {
         return 1;
@@ -105,3 +183,7 @@ pub fn get_sequence_start(increment: i64, min_value: i64, max_value: i64) -> i64
         max_value
     }
 }
+
+pub fn get_table_replica_identity() -> String {
+    "default".to_string()
+}
@@ -3,31 +3,47 @@ use std::path::{Path, PathBuf};
 use crate::{
     definition::{
         base::{yaml::render_nodes_to_mapping, Node},
-        ddl::render_schema_definition,
+        ddl::{
+            render_extension_definition, render_publication_definition, render_schema_definition,
+        },
     },
     error::AnyError,
     snapshot::Database,
 };
 
-use self::{base::yaml::parse_mapping_to_nodes, ddl::parse_schema_definition};
+use self::{
+    base::yaml::parse_mapping_to_nodes,
+    ddl::{
+        parse_extension_definition, parse_publication_definition, parse_rename_definitions,
+        parse_schema_definition,
+    },
+};
 
 mod base;
 mod ddl;
 mod defaults;
 
 pub fn save_snapshot(file_path: &Path, snapshot: &Database) -> Result<(), AnyError> {
+    std::fs::write(file_path, render_snapshot(snapshot))?;
+
+    return Ok(());
+}
+
+pub fn render_snapshot(snapshot: &Database) -> String {
     let mut nodes: Vec<Node> = Vec::new();
 
+    for extension in snapshot.iter_extensions() {
+        nodes.push(render_extension_definition(extension));
+    }
     for schema in snapshot.iter_schemas() {
         nodes.push(render_schema_definition(schema));
     }
+    for publication in snapshot.iter_publications() {
+        nodes.push(render_publication_definition(publication));
+    }
 
     let mapping = render_nodes_to_mapping(&nodes);
-    let got = serde_yaml::to_string(&mapping).unwrap();
-
-    std::fs::write(file_path, got)?;
-
-    return Ok(());
+    serde_yaml::to_string(&mapping).unwrap()
 }
 
 pub fn load_snpashot(dir_path: &PathBuf) -> Result<Database, AnyError> {
@@ -57,7 +73,18 @@ pub fn load_yaml_string(s: &str) -> Result<Database, AnyError> {
     let mut database = Database::new();
 
     for node in nodes {
+        if node.r#type == "extension" {
+            database.add_extension(parse_extension_definition(&node)?)?;
+            continue;
+        }
+        if node.r#type == "publication" {
+            database.add_publication(parse_publication_definition(&node)?)?;
+            continue;
+        }
         let parsed_schema = parse_schema_definition(&node)?;
+        for rename in parse_rename_definitions(&node)? {
+            database.add_rename(rename);
+        }
         if database.has_schema(&parsed_schema.name) {
             let target_schema = database.get_schema_mut(&parsed_schema.name)?;
             target_schema.merge_schema(parsed_schema)?;
@@ -70,9 +97,15 @@ pub fn load_yaml_string(s: &str) -> Result<Database, AnyError> {
 }
 
 fn collect_yaml_file(dir_path: &PathBuf) -> Result<Vec<PathBuf>, AnyError> {
-    let mut paths: Vec<PathBuf> = Vec::new();
+    let mut entries: Vec<PathBuf> = Vec::new();
     for entry in std::fs::read_dir(dir_path)? {
-        let path = entry?.path();
+        entries.push(entry?.path());
+    }
+    // read_dir has no defined order; sort so definitions merge the same way everywhere
+    entries.sort();
+
+    let mut paths: Vec<PathBuf> = Vec::new();
+    for path in entries {
         if path.is_dir() {
             let mut sub_paths = collect_yaml_file(&path)?;
             paths.append(&mut sub_paths);
@@ -82,3 +115,43 @@ fn collect_yaml_file(dir_path: &PathBuf) -> Result<Vec<PathBuf>, AnyError> {
     }
     Ok(paths)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_snapshot_keeps_definition_order() {
+        let yaml = "\
+schema public:
+  table zebra:
+    column id: uuid
+    constraint zebra_pkey:
+      primary key: id
+    constraint zebra_id_check:
+      check: (id IS NOT NULL)
+  table antelope:
+    column id: uuid
+  view zoo:
+    as: SELECT id FROM public.zebra
+schema audit:
+  table log:
+    column id: uuid
+";
+        let rendered = render_snapshot(&load_yaml_string(yaml).unwrap());
+        let reloaded = render_snapshot(&load_yaml_string(&rendered).unwrap());
+        assert_eq!(rendered, reloaded);
+
+        let order = [
+            "schema public",
+            "table zebra",
+            "constraint zebra_pkey",
+            "constraint zebra_id_check",
+            "table antelope",
+            "view zoo",
+            "schema audit",
+        ];
+        let positions: Vec<usize> = order.iter().map(|key| rendered.find(key).unwrap()).collect();
+        assert!(positions.windows(2).all(|w| w[0] < w[1]), "{}", rendered);
+    }
+}
@@ -1,6 +1,6 @@
 use postgres::Transaction;
 
-use crate::snapshot::changes::Change;
+use crate::snapshot::changes::{Change, Hazard};
 
 use super::Version;
 
@@ -23,6 +23,7 @@ impl DBHistory {
         version: &Version,
     ) -> Result<(), DBHistoryError> {
         let change_json = &serde_json::to_string(&version.changes)?;
+        let hazards_json = &serde_json::to_string(&version.hazards)?;
 
         tx.execute(
             &format_insert_version_sql(&self.metadata_schema),
@@ -32,6 +33,8 @@ impl DBHistory {
                 &change_json,
                 &version.up,
                 &version.down,
+                &hazards_json,
+                &version.custom_down,
             ],
         )?;
         Ok(())
@@ -105,6 +108,8 @@ impl DBHistory {
         if !self.meta_schema_exists(tx)? {
             self.create_meta_schema(tx)?;
         }
+        // tables created before versions kept their hazards
+        tx.execute(&format_upgrade_table_sql(&self.metadata_schema), &[])?;
         Ok(())
     }
 
@@ -144,8 +149,11 @@ impl DBHistory {
         let changes_json: String = row.get(2);
         let up: Vec<String> = row.get(3);
         let down: Vec<String> = row.get(4);
+        let hazards_json: String = row.get(5);
+        let custom_down: Option<Vec<String>> = row.get(6);
 
         let changes: Vec<Change> = serde_json::from_str(&changes_json)?;
+        let hazards: Vec<Hazard> = serde_json::from_str(&hazards_json)?;
 
         Ok(Version {
             name,
@@ -153,6 +161,8 @@ impl DBHistory {
             changes,
             up,
             down,
+            hazards,
+            custom_down,
         })
     }
 }
@@ -168,22 +178,30 @@ fn format_create_table_sql(schema: &str) -> String {
     changes JSON NOT NULL,
     up TEXT[] NOT NULL,
     down TEXT[] NOT NULL,
+    hazards JSON NOT NULL DEFAULT '[]',
+    custom_down TEXT[],
     applied_at TIMESTAMP NOT NULL DEFAULT now(),
     applied_order SERIAL NOT NULL UNIQUE
 )",
         schema, schema,
     )
 }
+fn format_upgrade_table_sql(schema: &str) -> String {
+    format!(
+        "ALTER TABLE {}.version ADD COLUMN IF NOT EXISTS hazards JSON NOT NULL DEFAULT '[]', ADD COLUMN IF NOT EXISTS custom_down TEXT[]",
+        schema
+    )
+}
 fn format_insert_version_sql(schema: &str) -> String {
     format!(
-        "INSERT INTO {}.version (name, previous, changes, up, down) VALUES ($1, $2, $3::TEXT::JSON, $4, $5)",
+        "INSERT INTO {}.version (name, previous, changes, up, down, hazards, custom_down) VALUES ($1, $2, $3::TEXT::JSON, $4, $5, $6::TEXT::JSON, $7)",
         schema
     )
 }
 
 fn format_current_version_sql(schema: &str) -> String {
     format!(
-        "SELECT name, previous, changes::TEXT, up, down, applied_at FROM {}.version ORDER BY applied_order DESC LIMIT 1",
+        "SELECT name, previous, changes::TEXT, up, down, hazards::TEXT, custom_down, applied_at FROM {}.version ORDER BY applied_order DESC LIMIT 1",
         schema
     )
 }
@@ -208,7 +226,7 @@ fn format_get_applied_order_sql(schema: &str) -> String {
 
 fn format_get_version_sql(schema: &str) -> String {
     format!(
-        "SELECT name, previous, changes::TEXT, up, down FROM {}.version WHERE name = $1",
+        "SELECT name, previous, changes::TEXT, up, down, hazards::TEXT, custom_down FROM {}.version WHERE name = $1",
         schema
     )
 }
@@ -247,3 +265,47 @@ impl From<serde_json::Error> for DBHistoryError {
         DBHistoryError::SerdeError(e)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::DBHistory;
+    use crate::util::test::get_test_connection;
+
+    #[test]
+    fn test_ensure_initialized_upgrades_table() {
+        let mut conn = get_test_connection();
+        let mut tx = conn.transaction().unwrap();
+        // the table as created before versions kept their hazards
+        tx.execute("CREATE SCHEMA test_dbh_upgrade", &[]).unwrap();
+        tx.execute(
+            "CREATE TABLE test_dbh_upgrade.version (
+                name VARCHAR(128) NOT NULL PRIMARY KEY,
+                previous VARCHAR(128) REFERENCES test_dbh_upgrade.version(name),
+                changes JSON NOT NULL,
+                up TEXT[] NOT NULL,
+                down TEXT[] NOT NULL,
+                applied_at TIMESTAMP NOT NULL DEFAULT now(),
+                applied_order SERIAL NOT NULL UNIQUE
+            )",
+            &[],
+        )
+        .unwrap();
+        tx.execute(
+            "INSERT INTO test_dbh_upgrade.version (name, previous, changes, up, down)
+                VALUES ('init', NULL, '[]', '{}', '{}')",
+            &[],
+        )
+        .unwrap();
+
+        let mut dbh = DBHistory::new("test_dbh_upgrade");
+        dbh.ensure_initialized(&mut tx).unwrap();
+        // and running it again leaves the table alone
+        dbh.ensure_initialized(&mut tx).unwrap();
+
+        let version = dbh.get_current_version(&mut tx).unwrap();
+        assert_eq!(version.name, "init");
+        assert_eq!(version.hazards, vec![]);
+        assert_eq!(version.custom_down, None);
+        assert!(version.is_reversible());
+    }
+}
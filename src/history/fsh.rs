@@ -29,9 +29,9 @@ impl FSHistory {
             current_version: INIT.to_owned(),
         };
 
-        let dir_entries = std::fs::read_dir(&dir)?;
+        let mut dir_entries = std::fs::read_dir(&dir)?.collect::<Result<Vec<_>, _>>()?;
+        dir_entries.sort_by_key(|entry| entry.file_name());
         for entry in dir_entries {
-            let entry = entry?;
             let filetype = entry.file_type()?;
             let filename = entry.file_name().to_str().unwrap().to_owned();
 
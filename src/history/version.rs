@@ -1,4 +1,4 @@
-use crate::snapshot::changes::{Change, CreateSchemaChange};
+use crate::snapshot::changes::{Change, CreateSchemaChange, Hazard};
 use serde::{Deserialize, Serialize};
 
 pub const STAGE: &str = "stage";
@@ -11,6 +11,12 @@ pub struct Version {
     pub changes: Vec<Change>,
     pub up: Vec<String>,
     pub down: Vec<String>,
+    /// Hazards of the changes, as marked by `stage`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hazards: Vec<Hazard>,
+    /// Written by hand to run instead of the generated `down`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_down: Option<Vec<String>>,
 }
 
 impl Version {
@@ -24,6 +30,8 @@ impl Version {
             .into()],
             up: vec!["CREATE SCHEMA public;".to_owned()],
             down: vec!["DROP SCHEMA public;".to_owned()],
+            hazards: vec![],
+            custom_down: None,
         }
     }
 
@@ -34,6 +42,8 @@ impl Version {
             changes: vec![],
             up: vec![],
             down: vec![],
+            hazards: vec![],
+            custom_down: None,
         }
     }
 
@@ -48,13 +58,72 @@ impl Version {
         self.down = vec![down.to_owned()];
         self.down.extend(previous)
     }
+    pub fn add_hazards(&mut self, hazards: &[Hazard]) {
+        self.hazards.extend(hazards);
+        self.hazards.sort();
+        self.hazards.dedup();
+    }
     pub fn reset(&mut self) {
         self.changes = vec![];
         self.up = vec![];
         self.down = vec![];
+        self.hazards = vec![];
+        self.custom_down = None;
+    }
+
+    /// Statements reverting the version, the hand-written ones if given.
+    pub fn get_down(&self) -> &[String] {
+        self.custom_down.as_deref().unwrap_or(&self.down)
+    }
+
+    /// Whether `down` can revert the version without losing anything, which a
+    /// hand-written down is trusted to do.
+    pub fn is_reversible(&self) -> bool {
+        self.custom_down.is_some() || !self.hazards.contains(&Hazard::Irreversible)
     }
 
     pub fn is_empty(&self) -> bool {
         self.changes.len() == 0 && self.up.len() == 0 && self.down.len() == 0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Version;
+    use crate::snapshot::changes::Hazard;
+
+    #[test]
+    fn test_is_reversible() {
+        let mut version = Version::new_stage("init");
+        assert!(version.is_reversible());
+
+        version.add_hazards(&[Hazard::DataLosing, Hazard::LockHeavy]);
+        assert!(version.is_reversible());
+
+        version.add_hazards(&[Hazard::Irreversible]);
+        assert!(!version.is_reversible());
+
+        version.custom_down = Some(vec![]);
+        assert!(version.is_reversible());
+    }
+
+    #[test]
+    fn test_get_down() {
+        let mut version = Version::new_stage("init");
+        version.add_down("DROP TABLE \"public\".\"user\";");
+        version.add_down("ALTER TABLE \"public\".\"user\" DROP COLUMN \"email\";");
+        assert_eq!(
+            version.get_down(),
+            &[
+                "ALTER TABLE \"public\".\"user\" DROP COLUMN \"email\";".to_string(),
+                "DROP TABLE \"public\".\"user\";".to_string(),
+            ]
+        );
+
+        version.custom_down = Some(vec!["TRUNCATE \"public\".\"user\";".to_string()]);
+        assert_eq!(
+            version.get_down(),
+            &["TRUNCATE \"public\".\"user\";".to_string()]
+        );
+    }
+}
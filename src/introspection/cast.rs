@@ -0,0 +1,48 @@
+use postgres::{Error, Transaction};
+
+/// Tells whether a column of `source_type` can change to `target_type`
+/// without a `USING` expression, or `None` when a type is unknown.
+pub fn introspect_cast_is_implicit(
+    client: &mut Transaction,
+    source_type: &str,
+    target_type: &str,
+) -> Result<Option<bool>, Error> {
+    let query = include_str!("cast.sql");
+    let stmt = client.prepare(query)?;
+    let row = client.query_one(&stmt, &[&source_type, &target_type])?;
+
+    Ok(row.get(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::introspect_cast_is_implicit;
+    use crate::util::test::get_test_connection;
+
+    #[test]
+    fn test_introspect_cast_is_implicit() {
+        let mut conn = get_test_connection();
+        let mut tx = conn.transaction().unwrap();
+
+        assert_eq!(
+            introspect_cast_is_implicit(&mut tx, "int4", "int8").unwrap(),
+            Some(true)
+        );
+        assert_eq!(
+            introspect_cast_is_implicit(&mut tx, "int8", "int4").unwrap(),
+            Some(true)
+        );
+        assert_eq!(
+            introspect_cast_is_implicit(&mut tx, "int4", "varchar(20)").unwrap(),
+            Some(true)
+        );
+        assert_eq!(
+            introspect_cast_is_implicit(&mut tx, "text", "int4").unwrap(),
+            Some(false)
+        );
+        assert_eq!(
+            introspect_cast_is_implicit(&mut tx, "text", "no_such_type").unwrap(),
+            None
+        );
+    }
+}
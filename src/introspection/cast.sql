@@ -0,0 +1,22 @@
+WITH t AS (
+    SELECT
+        to_regtype($1) AS source,
+        to_regtype($2) AS target
+)
+SELECT
+    CASE
+        WHEN t.source IS NULL OR t.target IS NULL THEN NULL
+        -- ALTER COLUMN ... TYPE falls back to an assignment cast
+        ELSE t.source = t.target
+            OR EXISTS (
+                SELECT 1
+                FROM pg_cast c
+                WHERE
+                    c.castsource = t.source
+                    AND c.casttarget = t.target
+                    AND c.castcontext IN ('i', 'a')
+            )
+            -- anything converts to a string type through its output function
+            OR (SELECT typcategory FROM pg_type WHERE oid = t.target) = 'S'
+    END AS implicit
+FROM t
@@ -17,4 +17,5 @@ FROM pg_constraint con
     LEFT JOIN pg_type t ON t.oid = con.contypid
 WHERE pg_has_role(COALESCE(c.relowner, t.typowner), 'USAGE') AND con.contype = 'c'
     AND con.conrelid != 0
-    AND rs.nspname = ANY($1);
+    AND rs.nspname = ANY($1)
+ORDER BY rs.nspname, c.relname, con.conname;
@@ -9,6 +9,19 @@ pub struct Column {
     pub default: String,
     pub not_null: bool,
     pub data_type: String,
+    pub generated: Option<String>,
+    /// `a` for `ALWAYS`, `d` for `BY DEFAULT`, empty if not an identity column
+    pub identity: String,
+    pub identity_increment: Option<i64>,
+    pub identity_min_value: Option<i64>,
+    pub identity_max_value: Option<i64>,
+    pub identity_start: Option<i64>,
+    pub identity_cache: Option<i64>,
+    pub identity_cycle: Option<bool>,
+    pub collation: Option<String>,
+    pub storage: Option<String>,
+    pub compression: Option<String>,
+    pub statistics: Option<i64>,
 }
 
 pub fn introspect_columns(client: &mut Transaction, schemas: &[&str]) -> Result<Vec<Column>, Error> {
@@ -26,6 +39,18 @@ pub fn introspect_columns(client: &mut Transaction, schemas: &[&str]) -> Result<
             default: row.get(4),
             not_null: row.get(5),
             data_type: row.get(6),
+            generated: row.get(7),
+            identity: row.get(8),
+            identity_increment: row.get(9),
+            identity_min_value: row.get(10),
+            identity_max_value: row.get(11),
+            identity_start: row.get(12),
+            identity_cache: row.get(13),
+            identity_cycle: row.get(14),
+            collation: row.get(15),
+            storage: row.get(16),
+            compression: row.get(17),
+            statistics: row.get(18),
         };        
 
         vals.push(val);
@@ -61,6 +86,18 @@ mod tests {
                 default: "".to_string(),
                 not_null: false,
                 data_type: "bool".to_string(),
+                generated: None,
+                identity: "".to_string(),
+                identity_increment: None,
+                identity_min_value: None,
+                identity_max_value: None,
+                identity_start: None,
+                identity_cache: None,
+                identity_cycle: None,
+                collation: None,
+                storage: None,
+                compression: None,
+                statistics: None,
             },
             Column {
                 schema: "test_column".to_string(),
@@ -70,6 +107,18 @@ mod tests {
                 default: "".to_string(),
                 not_null: false,
                 data_type: "varchar(255)".to_string(),
+                generated: None,
+                identity: "".to_string(),
+                identity_increment: None,
+                identity_min_value: None,
+                identity_max_value: None,
+                identity_start: None,
+                identity_cache: None,
+                identity_cycle: None,
+                collation: None,
+                storage: None,
+                compression: None,
+                statistics: None,
             },
             Column {
                 schema: "test_column".to_string(),
@@ -79,6 +128,18 @@ mod tests {
                 default: "".to_string(),
                 not_null: false,
                 data_type: "numeric(13,4)".to_string(),
+                generated: None,
+                identity: "".to_string(),
+                identity_increment: None,
+                identity_min_value: None,
+                identity_max_value: None,
+                identity_start: None,
+                identity_cache: None,
+                identity_cycle: None,
+                collation: None,
+                storage: None,
+                compression: None,
+                statistics: None,
             },
             Column {
                 schema: "test_column".to_string(),
@@ -88,7 +149,70 @@ mod tests {
                 default: "".to_string(),
                 not_null: false,
                 data_type: "text[]".to_string(),
+                generated: None,
+                identity: "".to_string(),
+                identity_increment: None,
+                identity_min_value: None,
+                identity_max_value: None,
+                identity_start: None,
+                identity_cache: None,
+                identity_cycle: None,
+                collation: None,
+                storage: None,
+                compression: None,
+                statistics: None,
             },
         ]);
     }
+
+    #[test]
+    fn test_introspect_generated_and_identity_columns() {
+        let mut conn = get_test_connection();
+        let mut tx = conn.transaction().unwrap();
+        tx.execute("CREATE SCHEMA test_column_generated", &[]).unwrap();
+        tx.execute("
+            CREATE TABLE test_column_generated.table1 (
+                id int GENERATED BY DEFAULT AS IDENTITY (START WITH 100),
+                price numeric,
+                total numeric GENERATED ALWAYS AS (price * 2) STORED
+            );
+        ", &[]).unwrap();
+        let res = introspect_columns(&mut tx, &vec!["test_column_generated"]).unwrap();
+
+        assert_eq!(res[0].identity, "d");
+        assert_eq!(res[0].identity_start, Some(100));
+        assert_eq!(res[0].identity_max_value, Some(2147483647));
+        assert_eq!(res[0].default, "");
+        assert_eq!(res[2].generated, Some("(price * (2)::numeric)".to_string()));
+        assert_eq!(res[2].default, "");
+        assert_eq!(res[2].identity, "");
+    }
+
+    #[test]
+    fn test_introspect_column_storage_settings() {
+        let mut conn = get_test_connection();
+        let mut tx = conn.transaction().unwrap();
+        tx.execute("CREATE SCHEMA test_column_storage", &[]).unwrap();
+        tx.execute("
+            CREATE TABLE test_column_storage.table1 (
+                col_plain text,
+                col_tuned text COMPRESSION pglz COLLATE \"C\"
+            );
+        ", &[]).unwrap();
+        tx.execute("
+            ALTER TABLE test_column_storage.table1
+                ALTER COLUMN col_tuned SET STORAGE EXTERNAL,
+                ALTER COLUMN col_tuned SET STATISTICS 500;
+        ", &[]).unwrap();
+        let res = introspect_columns(&mut tx, &vec!["test_column_storage"]).unwrap();
+
+        assert_eq!(res[0].collation, None);
+        assert_eq!(res[0].storage, None);
+        assert_eq!(res[0].compression, None);
+        assert_eq!(res[0].statistics, None);
+        assert_eq!(res[1].collation, Some("C".to_string()));
+        assert_eq!(res[1].storage, Some("external".to_string()));
+        assert_eq!(res[1].compression, Some("pglz".to_string()));
+        assert_eq!(res[1].statistics, Some(500));
+    }
 }
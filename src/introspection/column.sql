@@ -44,13 +44,59 @@ SELECT
     END
     ||
     CASE WHEN t.typelem <> 0 THEN '[]' ELSE '' END
-        AS "data_type"
+        AS "data_type",
+    CASE WHEN a.attgenerated = 's' THEN pg_get_expr(ad.adbin, ad.adrelid) ELSE null END
+        AS "generated",
+    a.attidentity::text
+        AS "identity",
+    s.seqincrement
+        AS "identity_increment",
+    s.seqmin
+        AS "identity_min_value",
+    s.seqmax
+        AS "identity_max_value",
+    s.seqstart
+        AS "identity_start",
+    s.seqcache
+        AS "identity_cache",
+    s.seqcycle
+        AS "identity_cycle",
+    CASE WHEN a.attcollation <> t.typcollation THEN (
+        SELECT CASE WHEN cn.nspname = 'pg_catalog' THEN co.collname ELSE cn.nspname || '.' || co.collname END
+        FROM pg_collation co
+            JOIN pg_namespace cn ON cn.oid = co.collnamespace
+        WHERE co.oid = a.attcollation
+    ) ELSE null END
+        AS "collation",
+    CASE WHEN a.attstorage <> t.typstorage THEN
+        CASE a.attstorage
+            WHEN 'p' THEN 'plain'
+            WHEN 'e' THEN 'external'
+            WHEN 'x' THEN 'extended'
+            WHEN 'm' THEN 'main'
+        END
+    ELSE null END
+        AS "storage",
+    CASE a.attcompression
+        WHEN 'p' THEN 'pglz'
+        WHEN 'l' THEN 'lz4'
+        ELSE null
+    END
+        AS "compression",
+    CASE WHEN a.attstattarget >= 0 THEN a.attstattarget::int8 ELSE null END
+        AS "statistics"
 FROM pg_attribute a
     LEFT JOIN pg_attrdef ad ON a.attrelid = ad.adrelid AND a.attnum = ad.adnum
+    LEFT JOIN (pg_depend d JOIN pg_sequence s ON s.seqrelid = d.objid)
+        ON d.classid = 'pg_class'::regclass
+            AND d.refobjid = a.attrelid
+            AND d.refobjsubid = a.attnum
+            AND d.deptype = 'i'
     JOIN (pg_class c JOIN pg_namespace nc ON c.relnamespace = nc.oid) ON a.attrelid = c.oid
     JOIN (pg_type t JOIN pg_namespace nt ON t.typnamespace = nt.oid) ON a.atttypid = t.oid
 WHERE NOT pg_is_other_temp_schema(nc.oid)
     AND a.attnum > 0
     AND NOT a.attisdropped
     AND c.relkind = 'r'
-    AND nc.nspname = ANY($1);
+    AND nc.nspname = ANY($1)
+ORDER BY nc.nspname, c.relname, a.attnum;
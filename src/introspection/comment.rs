@@ -0,0 +1,84 @@
+use postgres::{Error, Transaction};
+
+#[derive(PartialEq, Debug)]
+pub struct Comment {
+    /// `schema`, `relation`, `column`, `constraint` or `function`
+    pub kind: String,
+    pub schema: String,
+    pub object: Option<String>,
+    pub sub_object: Option<String>,
+    pub comment: String,
+}
+
+pub fn introspect_comments(
+    client: &mut Transaction,
+    schemas: &[&str],
+) -> Result<Vec<Comment>, Error> {
+    let query = include_str!("comment.sql");
+    let stmt = client.prepare(query)?;
+    let rows = client.query(&stmt, &[&schemas])?;
+
+    let mut vals = Vec::new();
+    for row in rows {
+        let val = Comment {
+            kind: row.get(0),
+            schema: row.get(1),
+            object: row.get(2),
+            sub_object: row.get(3),
+            comment: row.get(4),
+        };
+        vals.push(val);
+    }
+    Ok(vals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::introspect_comments;
+    use crate::{introspection::comment::Comment, util::test::get_test_connection};
+
+    #[test]
+    fn test_introspect_comments() {
+        let mut conn = get_test_connection();
+        let mut tx = conn.transaction().unwrap();
+        tx.execute("CREATE SCHEMA test_comment", &[]).unwrap();
+        tx.execute(
+            "CREATE TABLE test_comment.table1 (
+                col1 INT CONSTRAINT col1_positive CHECK (col1 > 0)
+            );",
+            &[],
+        )
+        .unwrap();
+        tx.execute("COMMENT ON SCHEMA test_comment IS 'schema'", &[])
+            .unwrap();
+        tx.execute("COMMENT ON TABLE test_comment.table1 IS 'table'", &[])
+            .unwrap();
+        tx.execute("COMMENT ON COLUMN test_comment.table1.col1 IS 'column'", &[])
+            .unwrap();
+        tx.execute(
+            "COMMENT ON CONSTRAINT col1_positive ON test_comment.table1 IS 'constraint'",
+            &[],
+        )
+        .unwrap();
+        let res = introspect_comments(&mut tx, &vec!["test_comment"]).unwrap();
+
+        let comment = |kind: &str, object: Option<&str>, sub_object: Option<&str>, c: &str| {
+            Comment {
+                kind: kind.to_string(),
+                schema: "test_comment".to_string(),
+                object: object.map(|s| s.to_string()),
+                sub_object: sub_object.map(|s| s.to_string()),
+                comment: c.to_string(),
+            }
+        };
+        assert_eq!(
+            res,
+            vec![
+                comment("schema", None, None, "schema"),
+                comment("relation", Some("table1"), None, "table"),
+                comment("column", Some("table1"), Some("col1"), "column"),
+                comment("constraint", Some("table1"), Some("col1_positive"), "constraint"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,61 @@
+SELECT
+    'schema' AS kind,
+    n.nspname AS schema,
+    NULL AS object,
+    NULL AS sub_object,
+    obj_description(n.oid, 'pg_namespace') AS comment
+FROM pg_namespace n
+WHERE n.nspname = ANY($1)
+    AND obj_description(n.oid, 'pg_namespace') IS NOT NULL
+UNION ALL
+SELECT
+    'relation',
+    n.nspname,
+    c.relname,
+    NULL,
+    obj_description(c.oid, 'pg_class')
+FROM pg_class c
+    JOIN pg_namespace n ON c.relnamespace = n.oid
+WHERE n.nspname = ANY($1)
+    AND c.relkind = ANY (ARRAY['r', 'p', 'v', 'm', 'S', 'i', 'I'])
+    AND obj_description(c.oid, 'pg_class') IS NOT NULL
+UNION ALL
+SELECT
+    'column',
+    n.nspname,
+    c.relname,
+    a.attname,
+    col_description(c.oid, a.attnum)
+FROM pg_attribute a
+    JOIN pg_class c ON a.attrelid = c.oid
+    JOIN pg_namespace n ON c.relnamespace = n.oid
+WHERE n.nspname = ANY($1)
+    AND c.relkind = ANY (ARRAY['r', 'p'])
+    AND a.attnum > 0
+    AND NOT a.attisdropped
+    AND col_description(c.oid, a.attnum) IS NOT NULL
+UNION ALL
+SELECT
+    'constraint',
+    n.nspname,
+    c.relname,
+    con.conname,
+    obj_description(con.oid, 'pg_constraint')
+FROM pg_constraint con
+    JOIN pg_class c ON con.conrelid = c.oid
+    JOIN pg_namespace n ON c.relnamespace = n.oid
+WHERE n.nspname = ANY($1)
+    AND obj_description(con.oid, 'pg_constraint') IS NOT NULL
+UNION ALL
+SELECT
+    'function',
+    n.nspname,
+    p.proname,
+    NULL,
+    obj_description(p.oid, 'pg_proc')
+FROM pg_proc p
+    JOIN pg_namespace n ON p.pronamespace = n.oid
+WHERE n.nspname = ANY($1)
+    AND p.prokind = 'f'
+    AND obj_description(p.oid, 'pg_proc') IS NOT NULL
+ORDER BY schema, object NULLS FIRST, sub_object NULLS FIRST, kind
@@ -0,0 +1,18 @@
+-- Default privileges of the current role, which is the one
+-- `ALTER DEFAULT PRIVILEGES` applies to when no role is given.
+SELECT
+    n.nspname AS schema,
+    CASE d.defaclobjtype
+        WHEN 'r' THEN 'tables'
+        WHEN 'S' THEN 'sequences'
+        WHEN 'f' THEN 'functions'
+        WHEN 'T' THEN 'types'
+    END AS object_type,
+    CASE WHEN e.grantee = 0 THEN 'public' ELSE pg_get_userbyid(e.grantee) END AS grantee,
+    lower(e.privilege_type) AS privilege
+FROM pg_default_acl d
+    JOIN pg_namespace n ON d.defaclnamespace = n.oid,
+    LATERAL aclexplode(d.defaclacl) e
+WHERE n.nspname = ANY($1)
+    AND d.defaclrole = (SELECT oid FROM pg_roles WHERE rolname = current_user)
+ORDER BY schema, object_type, grantee, privilege
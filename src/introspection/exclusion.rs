@@ -0,0 +1,79 @@
+use postgres::{Error, Transaction};
+
+#[derive(Debug, PartialEq)]
+pub struct Exclusion {
+    pub schema: String,
+    pub table: String,
+    pub name: String,
+    pub method: String,
+    pub expressions: Vec<String>,
+    pub operators: Vec<String>,
+    pub predicate: Option<String>,
+    pub is_deferrable: bool,
+    pub initially_deferred: bool,
+}
+
+pub fn introspect_exclusions(
+    client: &mut Transaction,
+    schemas: &[&str],
+) -> Result<Vec<Exclusion>, Error> {
+    let query = include_str!("exclusion.sql");
+    let stmt = client.prepare(query)?;
+    let rows = client.query(&stmt, &[&schemas])?;
+
+    let mut vals = Vec::new();
+    for row in rows {
+        let val = Exclusion {
+            schema: row.get(0),
+            table: row.get(1),
+            name: row.get(2),
+            method: row.get(3),
+            expressions: row.get(4),
+            operators: row.get(5),
+            predicate: row.get(6),
+            is_deferrable: row.get(7),
+            initially_deferred: row.get(8),
+        };
+        vals.push(val);
+    }
+    Ok(vals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::introspect_exclusions;
+    use crate::{introspection::exclusion::Exclusion, util::test::get_test_connection};
+
+    #[test]
+    fn test_introspect_exclusions() {
+        let mut conn = get_test_connection();
+        let mut tx = conn.transaction().unwrap();
+        tx.execute("CREATE SCHEMA test_exclusion;", &[]).unwrap();
+        tx.execute(
+            "CREATE TABLE test_exclusion.booking (
+                during TSRANGE,
+                cancelled BOOLEAN,
+                CONSTRAINT booking_no_overlap
+                    EXCLUDE USING gist (during WITH &&) WHERE (NOT cancelled)
+            );",
+            &[],
+        )
+        .unwrap();
+        let res = introspect_exclusions(&mut tx, &vec!["test_exclusion"]).unwrap();
+
+        assert_eq!(
+            res,
+            vec![Exclusion {
+                schema: "test_exclusion".to_string(),
+                table: "booking".to_string(),
+                name: "booking_no_overlap".to_string(),
+                method: "gist".to_string(),
+                expressions: vec!["during".to_string()],
+                operators: vec!["&&".to_string()],
+                predicate: Some("(NOT cancelled)".to_string()),
+                is_deferrable: false,
+                initially_deferred: false,
+            }]
+        );
+    }
+}
@@ -0,0 +1,35 @@
+SELECT
+    rs.nspname
+        constraint_schema,
+    c.relname
+        table_name,
+    con.conname
+        constraint_name,
+    am.amname
+        method,
+    ARRAY(
+        SELECT pg_get_indexdef(con.conindid, k, true)
+        FROM generate_subscripts(con.conexclop, 1) AS k
+        ORDER BY k
+    ) AS expressions,
+    ARRAY(
+        SELECT o.oprname::text
+        FROM unnest(con.conexclop) WITH ORDINALITY AS e(oid, k)
+            JOIN pg_operator o ON o.oid = e.oid
+        ORDER BY e.k
+    ) AS operators,
+    pg_get_expr(i.indpred, i.indrelid)
+        predicate,
+    con.condeferrable
+        is_deferrable,
+    con.condeferred
+        initially_deferred
+FROM pg_constraint con
+    JOIN pg_namespace rs ON rs.oid = con.connamespace
+    JOIN pg_class c ON c.oid = con.conrelid
+    JOIN pg_index i ON i.indexrelid = con.conindid
+    JOIN pg_class ic ON ic.oid = con.conindid
+    JOIN pg_am am ON am.oid = ic.relam
+WHERE con.contype = 'x'
+    AND rs.nspname = ANY($1)
+ORDER BY c.relname, con.conname;
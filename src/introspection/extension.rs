@@ -0,0 +1,51 @@
+use postgres::{Error, Transaction};
+
+#[derive(PartialEq, Debug)]
+pub struct Extension {
+    pub name: String,
+    pub schema: String,
+    pub version: String,
+}
+
+pub fn introspect_extensions(client: &mut Transaction) -> Result<Vec<Extension>, Error> {
+    let query = include_str!("extension.sql");
+    let stmt = client.prepare(query)?;
+    let rows = client.query(&stmt, &[])?;
+
+    let mut vals = Vec::new();
+    for row in rows {
+        let val = Extension {
+            name: row.get(0),
+            schema: row.get(1),
+            version: row.get(2),
+        };
+        vals.push(val);
+    }
+    Ok(vals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::introspect_extensions;
+    use crate::{introspection::extension::Extension, util::test::get_test_connection};
+
+    #[test]
+    fn test_introspect_extensions() {
+        let mut conn = get_test_connection();
+        let mut tx = conn.transaction().unwrap();
+        tx.execute("CREATE SCHEMA test_extension", &[]).unwrap();
+        tx.execute(
+            "CREATE EXTENSION citext WITH SCHEMA test_extension VERSION '1.5'",
+            &[],
+        )
+        .unwrap();
+        let res = introspect_extensions(&mut tx).unwrap();
+
+        assert!(res.contains(&Extension {
+            name: "citext".to_string(),
+            schema: "test_extension".to_string(),
+            version: "1.5".to_string(),
+        }));
+        assert!(!res.iter().any(|e| e.name == "plpgsql"));
+    }
+}
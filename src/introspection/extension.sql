@@ -0,0 +1,10 @@
+SELECT
+    e.extname AS name,
+    n.nspname AS schema,
+    e.extversion AS version
+FROM
+    pg_extension e
+    JOIN pg_namespace n ON e.extnamespace = n.oid
+-- plpgsql is installed in every database and never declared
+WHERE e.extname <> 'plpgsql'
+ORDER BY e.extname
@@ -8,6 +8,7 @@ pub struct Function {
     pub language: String,
     pub returns: String,
     pub volatility: String,
+    pub owner: String,
 }
 
 pub fn introspect_functions(
@@ -27,6 +28,7 @@ pub fn introspect_functions(
             language: row.get(3),
             returns: row.get(4),
             volatility: row.get(5),
+            owner: row.get(6),
         };
         vals.push(val);
     }
@@ -50,6 +52,16 @@ mod tests {
             AS $$SELECT 1;$$;",
             &[],
         ).unwrap();
+        tx.execute(
+            "CREATE EXTENSION pg_trgm WITH SCHEMA test_function",
+            &[],
+        ).unwrap();
+        tx.execute(
+            "CREATE PROCEDURE test_function.proc1()
+            LANGUAGE sql
+            AS $$SELECT 1;$$;",
+            &[],
+        ).unwrap();
         let res = introspect_functions(&mut tx, &vec!["test_function"]).unwrap();
 
         assert_eq!(res, vec![
@@ -60,6 +72,7 @@ mod tests {
                 language: "sql".to_string(),
                 returns: "integer".to_string(),
                 volatility: "VOLATILE".to_string(),
+                owner: "postgres".to_string(),
             },
         ]);
     }
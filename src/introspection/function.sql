@@ -11,9 +11,20 @@ SELECT
         WHEN p.provolatile = 'i' THEN 'IMMUTABLE'
         WHEN p.provolatile = 's' THEN 'STABLE'
         ELSE 'VOLATILE'
-    END AS volatility
+    END AS volatility,
+    pg_get_userbyid(p.proowner)::text AS owner
 FROM
     pg_proc p
     JOIN pg_namespace n ON p.pronamespace = n.oid
     JOIN pg_language l ON p.prolang = l.oid
 WHERE n.nspname = ANY($1)
+    AND p.prokind = 'f'
+    -- objects belonging to an extension are managed through it
+    AND NOT EXISTS (
+        SELECT 1
+        FROM pg_depend d
+        WHERE d.classid = 'pg_proc'::regclass
+            AND d.objid = p.oid
+            AND d.deptype = 'e'
+    )
+ORDER BY n.nspname, p.proname
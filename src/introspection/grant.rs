@@ -0,0 +1,149 @@
+use postgres::{Error, Transaction};
+
+#[derive(PartialEq, Debug)]
+pub struct Grant {
+    /// `schema`, `table`, `column`, `sequence` or `function`
+    pub kind: String,
+    pub schema: String,
+    pub object: Option<String>,
+    pub sub_object: Option<String>,
+    pub grantee: String,
+    pub privilege: String,
+    /// Whether the privilege is one the object started out with and lost.
+    pub revoked: bool,
+}
+
+#[derive(PartialEq, Debug)]
+pub struct DefaultGrant {
+    pub schema: String,
+    pub object_type: String,
+    pub grantee: String,
+    pub privilege: String,
+}
+
+pub fn introspect_grants(client: &mut Transaction, schemas: &[&str]) -> Result<Vec<Grant>, Error> {
+    let query = include_str!("grant.sql");
+    let stmt = client.prepare(query)?;
+    let rows = client.query(&stmt, &[&schemas])?;
+
+    let mut vals = Vec::new();
+    for row in rows {
+        let val = Grant {
+            kind: row.get(0),
+            schema: row.get(1),
+            object: row.get(2),
+            sub_object: row.get(3),
+            grantee: row.get(4),
+            privilege: row.get(5),
+            revoked: row.get(6),
+        };
+        vals.push(val);
+    }
+    Ok(vals)
+}
+
+pub fn introspect_default_grants(
+    client: &mut Transaction,
+    schemas: &[&str],
+) -> Result<Vec<DefaultGrant>, Error> {
+    let query = include_str!("default_grant.sql");
+    let stmt = client.prepare(query)?;
+    let rows = client.query(&stmt, &[&schemas])?;
+
+    let mut vals = Vec::new();
+    for row in rows {
+        let val = DefaultGrant {
+            schema: row.get(0),
+            object_type: row.get(1),
+            grantee: row.get(2),
+            privilege: row.get(3),
+        };
+        vals.push(val);
+    }
+    Ok(vals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{introspect_default_grants, introspect_grants, DefaultGrant, Grant};
+    use crate::util::test::get_test_connection;
+
+    #[test]
+    fn test_introspect_grants() {
+        let mut conn = get_test_connection();
+        let mut tx = conn.transaction().unwrap();
+        tx.execute("CREATE SCHEMA test_grant", &[]).unwrap();
+        tx.execute("CREATE ROLE test_grant_ro", &[]).unwrap();
+        tx.execute("CREATE TABLE test_grant.table1 (col1 INT, col2 INT);", &[])
+            .unwrap();
+        tx.execute("GRANT USAGE ON SCHEMA test_grant TO test_grant_ro", &[])
+            .unwrap();
+        tx.execute("GRANT SELECT ON test_grant.table1 TO PUBLIC", &[])
+            .unwrap();
+        tx.execute(
+            "GRANT UPDATE (col2) ON test_grant.table1 TO test_grant_ro",
+            &[],
+        )
+        .unwrap();
+        tx.execute(
+            "CREATE FUNCTION test_grant.func1() RETURNS integer LANGUAGE sql AS 'SELECT 1'",
+            &[],
+        )
+        .unwrap();
+        tx.execute(
+            "REVOKE EXECUTE ON FUNCTION test_grant.func1() FROM PUBLIC",
+            &[],
+        )
+        .unwrap();
+        tx.execute(
+            "ALTER DEFAULT PRIVILEGES IN SCHEMA test_grant GRANT SELECT ON TABLES TO test_grant_ro",
+            &[],
+        )
+        .unwrap();
+
+        let res = introspect_grants(&mut tx, &vec!["test_grant"]).unwrap();
+        let grant = |kind: &str,
+                     object: Option<&str>,
+                     sub_object: Option<&str>,
+                     grantee: &str,
+                     privilege: &str,
+                     revoked: bool| {
+            Grant {
+                kind: kind.to_string(),
+                schema: "test_grant".to_string(),
+                object: object.map(|s| s.to_string()),
+                sub_object: sub_object.map(|s| s.to_string()),
+                grantee: grantee.to_string(),
+                privilege: privilege.to_string(),
+                revoked,
+            }
+        };
+        assert_eq!(
+            res,
+            vec![
+                grant(
+                    "column",
+                    Some("table1"),
+                    Some("col2"),
+                    "test_grant_ro",
+                    "update",
+                    false
+                ),
+                grant("function", Some("func1"), None, "public", "execute", true),
+                grant("schema", None, None, "test_grant_ro", "usage", false),
+                grant("table", Some("table1"), None, "public", "select", false),
+            ]
+        );
+
+        let res = introspect_default_grants(&mut tx, &vec!["test_grant"]).unwrap();
+        assert_eq!(
+            res,
+            vec![DefaultGrant {
+                schema: "test_grant".to_string(),
+                object_type: "tables".to_string(),
+                grantee: "test_grant_ro".to_string(),
+                privilege: "select".to_string(),
+            }]
+        );
+    }
+}
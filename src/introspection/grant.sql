@@ -0,0 +1,105 @@
+-- Only the differences to the privileges an object starts out with are
+-- reported, i.e. to the owner's implicit privileges or those set up by initdb:
+-- privileges granted on top of them, and those of them revoked.
+WITH acls AS (
+    SELECT
+        'schema' AS kind,
+        n.nspname AS schema,
+        NULL::name AS object,
+        NULL::name AS sub_object,
+        n.oid,
+        'pg_namespace'::regclass AS classoid,
+        0 AS objsubid,
+        n.nspacl AS acl,
+        acldefault('n', n.nspowner) AS default_acl
+    FROM pg_namespace n
+    WHERE n.nspname = ANY($1)
+    UNION ALL
+    SELECT
+        CASE WHEN c.relkind = 'S' THEN 'sequence' ELSE 'table' END,
+        n.nspname,
+        c.relname,
+        NULL,
+        c.oid,
+        'pg_class'::regclass,
+        0,
+        c.relacl,
+        acldefault(CASE WHEN c.relkind = 'S' THEN 's' ELSE 'r' END::"char", c.relowner)
+    FROM pg_class c
+        JOIN pg_namespace n ON c.relnamespace = n.oid
+    WHERE n.nspname = ANY($1)
+        AND c.relkind = ANY (ARRAY['r', 'p', 'S'])
+    UNION ALL
+    SELECT
+        'column',
+        n.nspname,
+        c.relname,
+        a.attname,
+        c.oid,
+        'pg_class'::regclass,
+        a.attnum,
+        a.attacl,
+        acldefault('c', c.relowner)
+    FROM pg_attribute a
+        JOIN pg_class c ON a.attrelid = c.oid
+        JOIN pg_namespace n ON c.relnamespace = n.oid
+    WHERE n.nspname = ANY($1)
+        AND c.relkind = ANY (ARRAY['r', 'p'])
+        AND a.attnum > 0
+        AND NOT a.attisdropped
+    UNION ALL
+    SELECT
+        'function',
+        n.nspname,
+        p.proname,
+        NULL,
+        p.oid,
+        'pg_proc'::regclass,
+        0,
+        p.proacl,
+        acldefault('f', p.proowner)
+    FROM pg_proc p
+        JOIN pg_namespace n ON p.pronamespace = n.oid
+    WHERE n.nspname = ANY($1)
+        AND p.prokind = 'f'
+)
+SELECT
+    a.kind,
+    a.schema,
+    a.object,
+    a.sub_object,
+    CASE WHEN e.grantee = 0 THEN 'public' ELSE pg_get_userbyid(e.grantee) END AS grantee,
+    lower(e.privilege_type) AS privilege,
+    e.revoked
+FROM acls a,
+    LATERAL (
+        SELECT
+            COALESCE(a.acl, a.default_acl) AS actual,
+            COALESCE(
+                (
+                    SELECT i.initprivs
+                    FROM pg_init_privs i
+                    WHERE i.objoid = a.oid
+                        AND i.classoid = a.classoid
+                        AND i.objsubid = a.objsubid
+                ),
+                a.default_acl
+            ) AS initial
+    ) p,
+    LATERAL (
+        SELECT g.grantee, g.privilege_type, false AS revoked
+        FROM (
+            SELECT grantee, privilege_type FROM aclexplode(p.actual)
+            EXCEPT
+            SELECT grantee, privilege_type FROM aclexplode(p.initial)
+        ) g
+        UNION ALL
+        -- privileges the object started out with but no longer holds
+        SELECT r.grantee, r.privilege_type, true
+        FROM (
+            SELECT grantee, privilege_type FROM aclexplode(p.initial)
+            EXCEPT
+            SELECT grantee, privilege_type FROM aclexplode(p.actual)
+        ) r
+    ) e
+ORDER BY a.kind, a.schema, a.object, a.sub_object, e.revoked, grantee, privilege
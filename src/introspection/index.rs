@@ -6,11 +6,17 @@ pub struct Index {
     pub index_name: String,
     pub table_name: String,
     pub unique: bool,
+    pub nulls_not_distinct: bool,
     pub method: String,
-    pub key_length: i16,
-    pub key_columns: Option<Vec<String>>,
-    pub key_options: Vec<i16>,
-    pub expressions: Option<String>,
+    pub key_expressions: Vec<String>,
+    pub key_descending: Vec<bool>,
+    pub key_nulls_first: Vec<bool>,
+    pub key_collations: Vec<Option<String>>,
+    pub key_opclasses: Vec<Option<String>>,
+    pub include: Vec<String>,
+    pub predicate: Option<String>,
+    pub storage_parameters: Vec<String>,
+    pub tablespace: Option<String>,
 }
 
 pub fn introspect_indexes(client: &mut Transaction, schemas: &[&str]) -> Result<Vec<Index>, Error> {
@@ -25,11 +31,17 @@ pub fn introspect_indexes(client: &mut Transaction, schemas: &[&str]) -> Result<
             index_name: row.get(1),
             table_name: row.get(2),
             unique: row.get(3),
-            method: row.get(4),
-            key_length: row.get(5),
-            key_columns: row.get(6),
-            key_options: row.get(7),
-            expressions: row.get(8),
+            nulls_not_distinct: row.get(4),
+            method: row.get(5),
+            key_expressions: row.get(6),
+            key_descending: row.get(7),
+            key_nulls_first: row.get(8),
+            key_collations: row.get(9),
+            key_opclasses: row.get(10),
+            include: row.get(11),
+            predicate: row.get(12),
+            storage_parameters: row.get(13),
+            tablespace: row.get(14),
         };
         vals.push(val);
     }
@@ -49,7 +61,8 @@ mod tests {
         tx.execute(
             "CREATE TABLE test_index.table1 (
                 col1 INT,
-                col2 INT
+                col2 INT,
+                col3 TEXT
             );",
             &[],
         )
@@ -64,6 +77,18 @@ mod tests {
             &[],
         )
         .unwrap();
+        tx.execute(
+            "CREATE UNIQUE INDEX idx_table1_3 ON test_index.table1(
+                lower(col3) text_pattern_ops,
+                col3 COLLATE \"C\" NULLS FIRST
+            )
+            INCLUDE (col1)
+            NULLS NOT DISTINCT
+            WITH (fillfactor = 70)
+            WHERE col2 > 0",
+            &[],
+        )
+        .unwrap();
         let res = introspect_indexes(&mut tx, &vec!["test_index"]).unwrap();
 
         assert_eq!(
@@ -74,22 +99,51 @@ mod tests {
                     index_name: "idx_table1_1".to_string(),
                     table_name: "table1".to_string(),
                     unique: true,
+                    nulls_not_distinct: false,
                     method: "btree".to_string(),
-                    key_length: 1,
-                    key_columns: Some(vec!["col1".to_string(),],),
-                    key_options: vec![0,],
-                    expressions: None,
+                    key_expressions: vec!["col1".to_string()],
+                    key_descending: vec![false],
+                    key_nulls_first: vec![false],
+                    key_collations: vec![None],
+                    key_opclasses: vec![None],
+                    include: vec![],
+                    predicate: None,
+                    storage_parameters: vec![],
+                    tablespace: None,
                 },
                 Index {
                     schema_name: "test_index".to_string(),
                     index_name: "idx_table1_2".to_string(),
                     table_name: "table1".to_string(),
                     unique: false,
+                    nulls_not_distinct: false,
+                    method: "btree".to_string(),
+                    key_expressions: vec!["col1".to_string(), "col2".to_string()],
+                    key_descending: vec![false, true],
+                    key_nulls_first: vec![false, true],
+                    key_collations: vec![None, None],
+                    key_opclasses: vec![None, None],
+                    include: vec![],
+                    predicate: None,
+                    storage_parameters: vec![],
+                    tablespace: None,
+                },
+                Index {
+                    schema_name: "test_index".to_string(),
+                    index_name: "idx_table1_3".to_string(),
+                    table_name: "table1".to_string(),
+                    unique: true,
+                    nulls_not_distinct: true,
                     method: "btree".to_string(),
-                    key_length: 2,
-                    key_columns: Some(vec!["col1".to_string(), "col2".to_string()],),
-                    key_options: vec![0, 3,],
-                    expressions: None,
+                    key_expressions: vec!["lower(col3)".to_string(), "col3".to_string()],
+                    key_descending: vec![false, false],
+                    key_nulls_first: vec![false, true],
+                    key_collations: vec![None, Some("C".to_string())],
+                    key_opclasses: vec![Some("text_pattern_ops".to_string()), None],
+                    include: vec!["col1".to_string()],
+                    predicate: Some("(col2 > 0)".to_string()),
+                    storage_parameters: vec!["fillfactor=70".to_string()],
+                    tablespace: None,
                 },
             ]
         )
@@ -7,28 +7,74 @@ SELECT
         AS table_name,
     pi.indisunique
         AS unique,
+    pi.indnullsnotdistinct
+        AS nulls_not_distinct,
     am.amname
         AS method,
-    pi.indnatts
-        AS key_length,
-    (
-        SELECT array_agg(pa.attname) 
-        FROM unnest(pi.indkey) AS key(i)
-            JOIN pg_catalog.pg_attribute pa
+    ARRAY(
+        SELECT pg_catalog.pg_get_indexdef(pi.indexrelid, k, true)
+        FROM generate_series(1, pi.indnkeyatts) AS k
+        ORDER BY k
+    ) AS key_expressions,
+    ARRAY(
+        SELECT (pi.indoption[k - 1] & 1) <> 0
+        FROM generate_series(1, pi.indnkeyatts) AS k
+        ORDER BY k
+    ) AS key_descending,
+    ARRAY(
+        SELECT (pi.indoption[k - 1] & 2) <> 0
+        FROM generate_series(1, pi.indnkeyatts) AS k
+        ORDER BY k
+    ) AS key_nulls_first,
+    -- only collations other than the one of the indexed column or, for
+    -- expressions, the database's default one
+    ARRAY(
+        SELECT
+            CASE
+                WHEN pco.oid IS NULL OR pco.oid = COALESCE(NULLIF(pa.attcollation, 0), 100) THEN NULL
+                WHEN pcon.nspname = 'pg_catalog' THEN pco.collname::text
+                ELSE pcon.nspname || '.' || pco.collname
+            END
+        FROM generate_series(1, pi.indnkeyatts) AS k
+            LEFT JOIN pg_catalog.pg_collation pco ON pco.oid = pi.indcollation[k - 1]
+            LEFT JOIN pg_catalog.pg_namespace pcon ON pcon.oid = pco.collnamespace
+            LEFT JOIN pg_catalog.pg_attribute pa
                 ON pa.attrelid = pi.indrelid
-                    AND pa.attnum = i 
-    ) AS key_columns,
-    ( 
-        SELECT array_agg(o)
-        FROM unnest(pi.indoption) AS ko(o)
-    ) AS key_options,
-    pg_catalog.pg_get_expr(pi.indexprs, pi.indrelid)
-        AS expressions
+                    AND pa.attnum = pi.indkey[k - 1]
+                    AND pi.indkey[k - 1] <> 0
+        ORDER BY k
+    ) AS key_collations,
+    -- only operator classes other than the default one of the key's type
+    ARRAY(
+        SELECT
+            CASE
+                WHEN popc.opcdefault THEN NULL
+                WHEN popcn.nspname = 'pg_catalog' THEN popc.opcname::text
+                ELSE popcn.nspname || '.' || popc.opcname
+            END
+        FROM generate_series(1, pi.indnkeyatts) AS k
+            JOIN pg_catalog.pg_opclass popc ON popc.oid = pi.indclass[k - 1]
+            JOIN pg_catalog.pg_namespace popcn ON popcn.oid = popc.opcnamespace
+        ORDER BY k
+    ) AS key_opclasses,
+    ARRAY(
+        SELECT pg_catalog.pg_get_indexdef(pi.indexrelid, k, true)
+        FROM generate_series(pi.indnkeyatts + 1, pi.indnatts) AS k
+        ORDER BY k
+    ) AS include,
+    pg_catalog.pg_get_expr(pi.indpred, pi.indrelid)
+        AS predicate,
+    COALESCE(pc_index.reloptions, '{}')
+        AS storage_parameters,
+    pt.spcname::text
+        AS tablespace
 FROM pg_catalog.pg_index pi
     JOIN pg_catalog.pg_class pc_index ON pc_index.oid = pi.indexrelid
     JOIN pg_catalog.pg_namespace pn ON pc_index.relnamespace = pn.oid
     JOIN pg_catalog.pg_class pc_table ON pc_table.oid = pi.indrelid
     JOIN pg_am am ON am.oid=pc_index.relam
+    LEFT JOIN pg_catalog.pg_tablespace pt ON pt.oid = pc_index.reltablespace
     LEFT JOIN pg_catalog.pg_constraint pc ON pc.conindid = pc_index.oid
 WHERE pc.oid IS NULL
-    AND pn.nspname = ANY($1);
+    AND pn.nspname = ANY($1)
+ORDER BY pn.nspname, pc_index.relname;
@@ -0,0 +1,70 @@
+use postgres::{Error, Transaction};
+
+#[derive(PartialEq, Debug)]
+pub struct MaterializedView {
+    pub schema: String,
+    pub name: String,
+    pub query: String,
+    pub with_data: bool,
+    pub storage_parameters: Vec<String>,
+    pub owner: String,
+}
+
+pub fn introspect_materialized_views(
+    client: &mut Transaction,
+    schemas: &[&str],
+) -> Result<Vec<MaterializedView>, Error> {
+    let query = include_str!("materializedview.sql");
+    let stmt = client.prepare(query)?;
+    let rows = client.query(&stmt, &[&schemas])?;
+
+    let mut vals = Vec::new();
+    for row in rows {
+        let val = MaterializedView {
+            schema: row.get(0),
+            name: row.get(1),
+            query: row.get(2),
+            with_data: row.get(3),
+            storage_parameters: row.get(4),
+            owner: row.get(5),
+        };
+        vals.push(val);
+    }
+    Ok(vals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::introspect_materialized_views;
+    use crate::{
+        introspection::materializedview::MaterializedView, util::test::get_test_connection,
+    };
+
+    #[test]
+    fn test_introspect_materialized_views() {
+        let mut conn = get_test_connection();
+        let mut tx = conn.transaction().unwrap();
+        tx.execute("CREATE SCHEMA test_materialized_view", &[])
+            .unwrap();
+        tx.execute(
+            "CREATE MATERIALIZED VIEW test_materialized_view.view1
+                WITH (fillfactor=70)
+                AS SELECT 1 AS column1
+                WITH NO DATA",
+            &[],
+        )
+        .unwrap();
+        let res = introspect_materialized_views(&mut tx, &vec!["test_materialized_view"]).unwrap();
+        assert_eq!(
+            res,
+            vec![MaterializedView {
+                schema: "test_materialized_view".to_owned(),
+                name: "view1".to_owned(),
+                query: "SELECT 1 AS column1".to_owned(),
+                with_data: false,
+                storage_parameters: vec!["fillfactor=70".to_owned()],
+                owner: "postgres".to_string(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,13 @@
+SELECT
+    n.nspname AS schema,
+    c.relname AS name,
+    left(trim(pg_get_viewdef(c.oid)), -1) AS query,
+    c.relispopulated AS with_data,
+    COALESCE(c.reloptions, '{}') AS storage_parameters,
+    pg_get_userbyid(c.relowner)::text AS owner
+FROM
+    pg_class c
+    JOIN pg_namespace n ON c.relnamespace = n.oid
+WHERE n.nspname = ANY($1)
+    AND c.relkind = 'm'
+ORDER BY n.nspname, c.relname
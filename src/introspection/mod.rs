@@ -3,47 +3,84 @@ use postgres::Transaction;
 use crate::{
     error::AnyError,
     snapshot::{
-        Check, Column, Constraint, Database, ForeignKey, Function, Index, PrimaryKey, Relation,
-        Schema, Sequence, Table, Unique,
+        defaults::get_default_description, insert_grant, Check, Column, Constraint, Database,
+        DefaultGrant, Exclusion, ExclusionElement, Extension, ForeignKey, Function, Grant,
+        Identity, Index, IndexKey, Inheritance, MaterializedView, PartitionBound, PartitionKey,
+        Policy, PrimaryKey, Procedure, Publication, PublicationTable, Relation, Schema, Sequence,
+        Statistics, Table, Unique,
     },
 };
 
+mod cast;
 mod check;
 mod column;
+mod comment;
+mod exclusion;
+mod extension;
 mod foreignkey;
 mod function;
+mod grant;
 mod index;
+mod materializedview;
+mod policy;
 mod primarykey;
+mod procedure;
+mod publication;
 mod schema;
 mod sequence;
+mod statistics;
 mod table;
 mod unique;
 mod view;
 
+pub use cast::introspect_cast_is_implicit;
+
 pub fn introspect(tx: &mut Transaction, schemas: &[&str]) -> Result<Database, AnyError> {
     let mut database = Database::new();
 
+    load_extensions(tx, &mut database)?;
     load_schemas(tx, &mut database, schemas)?;
     load_sequences(tx, &mut database, schemas)?;
     load_tables(tx, &mut database, schemas)?;
     load_columns(tx, &mut database, schemas)?;
+    load_materialized_views(tx, &mut database, schemas)?;
     load_primary_keys(tx, &mut database, schemas)?;
     load_foreign_keys(tx, &mut database, schemas)?;
     load_functions(tx, &mut database, schemas)?;
+    load_procedures(tx, &mut database, schemas)?;
     load_unique(tx, &mut database, schemas)?;
     load_checks(tx, &mut database, schemas)?;
+    load_exclusions(tx, &mut database, schemas)?;
+    load_policies(tx, &mut database, schemas)?;
+    load_statistics(tx, &mut database, schemas)?;
+    load_publications(tx, &mut database, schemas)?;
     load_indexes(tx, &mut database, schemas)?;
+    load_comments(tx, &mut database, schemas)?;
+    load_grants(tx, &mut database, schemas)?;
 
     Ok(database)
 }
 
+fn load_extensions(tx: &mut Transaction, database: &mut Database) -> Result<(), AnyError> {
+    for iextension in extension::introspect_extensions(tx)? {
+        let extension = Extension {
+            name: iextension.name,
+            schema_name: iextension.schema,
+            version: Some(iextension.version),
+        };
+        database.add_extension(extension)?;
+    }
+    Ok(())
+}
+
 fn load_schemas(
     tx: &mut Transaction,
     database: &mut Database,
     schemas: &[&str],
 ) -> Result<(), AnyError> {
     for ischema in schema::introspect_schemas(tx, schemas)? {
-        let schema = Schema::new(&ischema.name);
+        let mut schema = Schema::new(&ischema.name);
+        schema.owner = Some(ischema.owner);
         database.add_schema(schema)?;
     }
     Ok(())
@@ -67,6 +104,10 @@ fn load_sequences(
             cache: iseq.cache,
             owned_by_column: iseq.owned_by_column_name,
             owned_by_table: iseq.owned_by_table_name,
+            owner: Some(iseq.owner),
+            comment: get_default_description(),
+            grants: vec![],
+            revokes: vec![],
         };
         database
             .get_schema_mut(&sequence.schema_name)?
@@ -81,10 +122,65 @@ fn load_tables(
     schemas: &[&str],
 ) -> Result<(), AnyError> {
     for itable in table::introspect_tables(tx, schemas)? {
-        let table = Table::new(&itable.schema, &itable.name);
+        let mut table = Table::new(&itable.schema, &itable.name);
+        if let Some(strategy) = itable.partition_strategy {
+            table.partition_by = Some(PartitionKey {
+                strategy: strategy,
+                key_expressions: itable.partition_key_expressions.unwrap_or_default(),
+            });
+        }
+        if let (Some(parent_schema), Some(parent)) =
+            (itable.partition_parent_schema, itable.partition_parent)
+        {
+            table.partition_of = Some(PartitionBound {
+                parent_schema: parent_schema,
+                parent: parent,
+                bound: itable.partition_bound.unwrap_or_default(),
+            });
+        }
+        table.inherits = itable
+            .inherits_schemas
+            .into_iter()
+            .zip(itable.inherits)
+            .map(|(parent_schema, parent)| Inheritance {
+                parent_schema,
+                parent,
+            })
+            .collect();
+        table.unlogged = itable.unlogged;
+        table.storage_parameters = itable.storage_parameters;
+        table.tablespace = itable.tablespace;
+        table.replica_identity = itable.replica_identity;
+        table.replica_identity_index = itable.replica_identity_index;
+        table.owner = Some(itable.owner);
+        table.row_level_security = itable.row_level_security;
+        table.force_row_level_security = itable.force_row_level_security;
         database
             .get_schema_mut(&itable.schema)?
-            .add_relation(Relation::Table(table))?;
+            .add_relation(table.into())?;
+    }
+    Ok(())
+}
+
+fn load_materialized_views(
+    tx: &mut Transaction,
+    database: &mut Database,
+    schemas: &[&str],
+) -> Result<(), AnyError> {
+    for iview in materializedview::introspect_materialized_views(tx, schemas)? {
+        let view = MaterializedView {
+            schema_name: iview.schema,
+            name: iview.name,
+            query: iview.query,
+            with_data: iview.with_data,
+            storage_parameters: iview.storage_parameters,
+            refresh: false,
+            owner: Some(iview.owner),
+            comment: get_default_description(),
+        };
+        database
+            .get_schema_mut(&view.schema_name)?
+            .add_relation(Relation::MaterializedView(view))?;
     }
     Ok(())
 }
@@ -100,12 +196,36 @@ fn load_columns(
             schema_name: icolumn.schema,
             table_name: icolumn.table,
             data_type: icolumn.data_type,
+            collation: icolumn.collation,
             default: if icolumn.default.is_empty() {
                 None
             } else {
                 Some(icolumn.default)
             },
             not_null: icolumn.not_null,
+            generated: icolumn.generated,
+            identity: if icolumn.identity.is_empty() {
+                None
+            } else {
+                Some(Identity {
+                    always: icolumn.identity == "a",
+                    increment: icolumn.identity_increment.unwrap(),
+                    min_value: icolumn.identity_min_value.unwrap(),
+                    max_value: icolumn.identity_max_value.unwrap(),
+                    start: icolumn.identity_start.unwrap(),
+                    cache: icolumn.identity_cache.unwrap(),
+                    cycle: icolumn.identity_cycle.unwrap(),
+                })
+            },
+            storage: icolumn.storage,
+            compression: icolumn.compression,
+            statistics: icolumn.statistics,
+            comment: get_default_description(),
+            grants: vec![],
+            revokes: vec![],
+            using: None,
+            using_from: None,
+            revert_using: None,
         };
         database
             .get_schema_mut(&column.schema_name)?
@@ -129,6 +249,7 @@ fn load_primary_keys(
             columns: ipk.table_columns,
             deferrable: ipk.deferrable,
             initially_deferred: ipk.initially_deferred,
+            comment: get_default_description(),
         };
 
         database
@@ -153,6 +274,7 @@ fn load_unique(
             columns: iunique.table_columns,
             deferrable: iunique.deferrable,
             initially_deferred: iunique.initially_deferred,
+            comment: get_default_description(),
         };
 
         database
@@ -183,6 +305,7 @@ fn load_foreign_keys(
             match_option: ifk.match_option,
             update_rule: ifk.update_rule,
             delete_rule: ifk.delete_rule,
+            comment: get_default_description(),
         };
 
         database
@@ -207,6 +330,7 @@ fn load_checks(
             expression: icheck.check_clause,
             deferrable: icheck.is_deferrable,
             initially_deferred: icheck.initially_deferred,
+            comment: get_default_description(),
         };
         database
             .get_schema_mut(&check.schema_name)?
@@ -217,25 +341,70 @@ fn load_checks(
     Ok(())
 }
 
+fn load_exclusions(
+    tx: &mut Transaction,
+    database: &mut Database,
+    schemas: &[&str],
+) -> Result<(), AnyError> {
+    for iexclusion in exclusion::introspect_exclusions(tx, schemas)? {
+        let exclusion = Exclusion {
+            name: iexclusion.name,
+            schema_name: iexclusion.schema,
+            table_name: iexclusion.table,
+            method: iexclusion.method,
+            elements: iexclusion
+                .expressions
+                .into_iter()
+                .zip(iexclusion.operators)
+                .map(|(expression, operator)| ExclusionElement {
+                    expression,
+                    operator,
+                })
+                .collect(),
+            predicate: iexclusion.predicate,
+            deferrable: iexclusion.is_deferrable,
+            initially_deferred: iexclusion.initially_deferred,
+            comment: get_default_description(),
+        };
+        database
+            .get_schema_mut(&exclusion.schema_name)?
+            .get_relation_mut(&exclusion.table_name)?
+            .as_table_mut()?
+            .add_constraint(Constraint::Exclusion(exclusion))?;
+    }
+    Ok(())
+}
+
 fn load_indexes(
     tx: &mut Transaction,
     database: &mut Database,
     schemas: &[&str],
 ) -> Result<(), AnyError> {
     for iindex in index::introspect_indexes(tx, schemas)? {
-        let key_expressions = if let Some(expressions) = iindex.expressions {
-            vec![expressions] //
-        } else {
-            iindex.key_columns.unwrap()
-        };
+        let mut keys = vec![];
+        for (i, expression) in iindex.key_expressions.into_iter().enumerate() {
+            keys.push(IndexKey {
+                expression,
+                collation: iindex.key_collations[i].clone(),
+                opclass: iindex.key_opclasses[i].clone(),
+                order: if iindex.key_descending[i] { "DESC" } else { "ASC" }.to_string(),
+                nulls: if iindex.key_nulls_first[i] { "FIRST" } else { "LAST" }.to_string(),
+            });
+        }
 
         let index = Index {
             name: iindex.index_name,
             schema_name: iindex.schema_name,
             table_name: iindex.table_name,
-            key_expressions: key_expressions,
+            keys,
             unique: iindex.unique,
+            nulls_not_distinct: iindex.nulls_not_distinct,
             method: iindex.method,
+            include: iindex.include,
+            predicate: iindex.predicate,
+            storage_parameters: iindex.storage_parameters,
+            tablespace: iindex.tablespace,
+            comment: get_default_description(),
         };
         database
             .get_schema_mut(&index.schema_name)?
@@ -257,6 +426,10 @@ fn load_functions(
             returns: ifunction.returns,
             volatility: ifunction.volatility,
             body: ifunction.body,
+            owner: Some(ifunction.owner),
+            comment: get_default_description(),
+            grants: vec![],
+            revokes: vec![],
         };
         database
             .get_schema_mut(&function.schema_name)?
@@ -264,3 +437,194 @@ fn load_functions(
     }
     Ok(())
 }
+
+fn load_procedures(
+    tx: &mut Transaction,
+    database: &mut Database,
+    schemas: &[&str],
+) -> Result<(), AnyError> {
+    for iprocedure in procedure::introspect_procedures(tx, schemas)? {
+        let procedure = Procedure {
+            name: iprocedure.name,
+            schema_name: iprocedure.schema,
+            arguments: iprocedure.arguments,
+            body: iprocedure.body,
+            language: iprocedure.language,
+            owner: Some(iprocedure.owner),
+        };
+        database
+            .get_schema_mut(&procedure.schema_name)?
+            .add_procedure(procedure)?;
+    }
+    Ok(())
+}
+
+fn load_policies(
+    tx: &mut Transaction,
+    database: &mut Database,
+    schemas: &[&str],
+) -> Result<(), AnyError> {
+    for ipolicy in policy::introspect_policies(tx, schemas)? {
+        let policy = Policy {
+            schema_name: ipolicy.schema.clone(),
+            table_name: ipolicy.table.clone(),
+            name: ipolicy.name,
+            command: ipolicy.command,
+            permissive: ipolicy.permissive,
+            roles: ipolicy.roles,
+            using: ipolicy.using,
+            with_check: ipolicy.with_check,
+        };
+        database
+            .get_schema_mut(&ipolicy.schema)?
+            .get_relation_mut(&ipolicy.table)?
+            .as_table_mut()?
+            .add_policy(policy)?;
+    }
+    Ok(())
+}
+
+fn load_statistics(
+    tx: &mut Transaction,
+    database: &mut Database,
+    schemas: &[&str],
+) -> Result<(), AnyError> {
+    for istatistics in statistics::introspect_statistics(tx, schemas)? {
+        let statistics = Statistics {
+            schema_name: istatistics.schema.clone(),
+            table_name: istatistics.table.clone(),
+            name: istatistics.name,
+            kinds: istatistics.kinds,
+            expressions: istatistics.expressions,
+            target: istatistics.target,
+        };
+        database
+            .get_schema_mut(&istatistics.schema)?
+            .get_relation_mut(&istatistics.table)?
+            .as_table_mut()?
+            .add_statistics(statistics)?;
+    }
+    Ok(())
+}
+
+fn load_publications(
+    tx: &mut Transaction,
+    database: &mut Database,
+    schemas: &[&str],
+) -> Result<(), AnyError> {
+    for itable in publication::introspect_publication_tables(tx, schemas)? {
+        if !database.has_publication(&itable.publication) {
+            database.add_publication(Publication {
+                name: itable.publication.clone(),
+                tables: vec![],
+            })?;
+        }
+        if let (Some(schema), Some(table)) = (itable.schema, itable.table) {
+            database
+                .get_publication_mut(&itable.publication)?
+                .tables
+                .push(PublicationTable {
+                    schema_name: schema,
+                    table_name: table,
+                    columns: itable.columns,
+                    row_filter: itable.row_filter,
+                });
+        }
+    }
+    Ok(())
+}
+
+fn load_comments(
+    tx: &mut Transaction,
+    database: &mut Database,
+    schemas: &[&str],
+) -> Result<(), AnyError> {
+    for icomment in comment::introspect_comments(tx, schemas)? {
+        let schema = database.get_schema_mut(&icomment.schema)?;
+        let object = icomment.object.unwrap_or_default();
+        let sub_object = icomment.sub_object.unwrap_or_default();
+
+        let target = match icomment.kind.as_str() {
+            "schema" => Some(&mut schema.comment),
+            "relation" => schema
+                .get_relation_mut(&object)
+                .ok()
+                .map(|r| r.get_comment_mut()),
+            "column" => schema
+                .get_relation_mut(&object)
+                .and_then(|r| r.as_table_mut())
+                .and_then(|t| t.get_column_mut(&sub_object))
+                .ok()
+                .map(|c| &mut c.comment),
+            "constraint" => schema
+                .get_relation_mut(&object)
+                .and_then(|r| r.as_table_mut())
+                .and_then(|t| t.get_constraint_mut(&sub_object))
+                .ok()
+                .map(|c| c.get_comment_mut()),
+            "function" => schema
+                .get_function_mut(&object)
+                .ok()
+                .map(|f| &mut f.comment),
+            _ => None,
+        };
+
+        // objects podo does not model, like indexes backing constraints, are skipped
+        if let Some(target) = target {
+            *target = icomment.comment;
+        }
+    }
+    Ok(())
+}
+
+fn load_grants(
+    tx: &mut Transaction,
+    database: &mut Database,
+    schemas: &[&str],
+) -> Result<(), AnyError> {
+    for igrant in grant::introspect_grants(tx, schemas)? {
+        let schema = database.get_schema_mut(&igrant.schema)?;
+        let object = igrant.object.unwrap_or_default();
+        let sub_object = igrant.sub_object.unwrap_or_default();
+
+        let target = match igrant.kind.as_str() {
+            "schema" => Some((&mut schema.grants, &mut schema.revokes)),
+            "table" => schema
+                .get_relation_mut(&object)
+                .and_then(|r| r.as_table_mut())
+                .ok()
+                .map(|t| (&mut t.grants, &mut t.revokes)),
+            "column" => schema
+                .get_relation_mut(&object)
+                .and_then(|r| r.as_table_mut())
+                .and_then(|t| t.get_column_mut(&sub_object))
+                .ok()
+                .map(|c| (&mut c.grants, &mut c.revokes)),
+            "sequence" => schema
+                .get_relation_mut(&object)
+                .and_then(|r| r.as_sequence_mut())
+                .ok()
+                .map(|s| (&mut s.grants, &mut s.revokes)),
+            "function" => schema
+                .get_function_mut(&object)
+                .ok()
+                .map(|f| (&mut f.grants, &mut f.revokes)),
+            _ => None,
+        };
+
+        // objects podo does not model, like extension functions, are skipped
+        if let Some((grants, revokes)) = target {
+            let target = if igrant.revoked { revokes } else { grants };
+            insert_grant(target, Grant::new(&igrant.grantee, &igrant.privilege));
+        }
+    }
+
+    for igrant in grant::introspect_default_grants(tx, schemas)? {
+        let schema = database.get_schema_mut(&igrant.schema)?;
+        insert_grant(
+            &mut schema.default_grants,
+            DefaultGrant::new(&igrant.object_type, &igrant.grantee, &igrant.privilege),
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use postgres::{Error, Transaction};
+
+#[derive(Debug, PartialEq)]
+pub struct Policy {
+    pub schema: String,
+    pub table: String,
+    pub name: String,
+    pub command: String,
+    pub permissive: bool,
+    pub roles: Vec<String>,
+    pub using: Option<String>,
+    pub with_check: Option<String>,
+}
+
+pub fn introspect_policies(
+    client: &mut Transaction,
+    schemas: &[&str],
+) -> Result<Vec<Policy>, Error> {
+    let query = include_str!("policy.sql");
+    let stmt = client.prepare(query)?;
+    let rows = client.query(&stmt, &[&schemas])?;
+
+    let mut vals = Vec::new();
+    for row in rows {
+        let val = Policy {
+            schema: row.get(0),
+            table: row.get(1),
+            name: row.get(2),
+            command: row.get(3),
+            permissive: row.get(4),
+            roles: row.get(5),
+            using: row.get(6),
+            with_check: row.get(7),
+        };
+        vals.push(val);
+    }
+    Ok(vals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::introspect_policies;
+    use crate::{introspection::policy::Policy, util::test::get_test_connection};
+
+    #[test]
+    fn test_introspect_policies() {
+        let mut conn = get_test_connection();
+        let mut tx = conn.transaction().unwrap();
+        tx.execute("CREATE SCHEMA test_policy", &[]).unwrap();
+        tx.execute("CREATE ROLE test_policy_rw", &[]).unwrap();
+        tx.execute("CREATE TABLE test_policy.account (tenant_id INT);", &[])
+            .unwrap();
+        tx.execute(
+            "CREATE POLICY tenant_read ON test_policy.account FOR SELECT
+                USING (tenant_id = 1);",
+            &[],
+        )
+        .unwrap();
+        tx.execute(
+            "CREATE POLICY tenant_write ON test_policy.account AS RESTRICTIVE
+                TO test_policy_rw WITH CHECK (tenant_id > 0);",
+            &[],
+        )
+        .unwrap();
+        let res = introspect_policies(&mut tx, &vec!["test_policy"]).unwrap();
+
+        assert_eq!(
+            res,
+            vec![
+                Policy {
+                    schema: "test_policy".to_string(),
+                    table: "account".to_string(),
+                    name: "tenant_read".to_string(),
+                    command: "SELECT".to_string(),
+                    permissive: true,
+                    roles: vec!["public".to_string()],
+                    using: Some("(tenant_id = 1)".to_string()),
+                    with_check: None,
+                },
+                Policy {
+                    schema: "test_policy".to_string(),
+                    table: "account".to_string(),
+                    name: "tenant_write".to_string(),
+                    command: "ALL".to_string(),
+                    permissive: false,
+                    roles: vec!["test_policy_rw".to_string()],
+                    using: None,
+                    with_check: Some("(tenant_id > 0)".to_string()),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,32 @@
+SELECT
+    n.nspname
+        AS "schema",
+    c.relname
+        AS "table",
+    p.polname
+        AS "name",
+    CASE p.polcmd
+        WHEN 'r' THEN 'SELECT'
+        WHEN 'a' THEN 'INSERT'
+        WHEN 'w' THEN 'UPDATE'
+        WHEN 'd' THEN 'DELETE'
+        ELSE 'ALL'
+    END
+        AS "command",
+    p.polpermissive
+        AS "permissive",
+    ARRAY(
+        SELECT CASE WHEN r.oid = 0 THEN 'public' ELSE pg_get_userbyid(r.oid)::text END
+        FROM unnest(p.polroles) AS r(oid)
+        ORDER BY 1
+    )
+        AS "roles",
+    pg_get_expr(p.polqual, p.polrelid)
+        AS "using",
+    pg_get_expr(p.polwithcheck, p.polrelid)
+        AS "with_check"
+FROM pg_policy p
+    JOIN pg_class c ON c.oid = p.polrelid
+    JOIN pg_namespace n ON n.oid = c.relnamespace
+WHERE n.nspname = ANY($1)
+ORDER BY c.relname, p.polname;
@@ -0,0 +1,75 @@
+use postgres::{Error, Transaction};
+
+#[derive(PartialEq, Debug)]
+pub struct Procedure {
+    pub schema: String,
+    pub name: String,
+    pub arguments: String,
+    pub body: String,
+    pub language: String,
+    pub owner: String,
+}
+
+pub fn introspect_procedures(
+    client: &mut Transaction,
+    schemas: &[&str],
+) -> Result<Vec<Procedure>, Error> {
+    let query = include_str!("procedure.sql");
+    let stmt = client.prepare(query)?;
+    let rows = client.query(&stmt, &[&schemas])?;
+
+    let mut vals = Vec::new();
+    for row in rows {
+        let val = Procedure {
+            schema: row.get(0),
+            name: row.get(1),
+            arguments: row.get(2),
+            body: row.get(3),
+            language: row.get(4),
+            owner: row.get(5),
+        };
+        vals.push(val);
+    }
+    Ok(vals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::introspect_procedures;
+    use crate::{introspection::procedure::Procedure, util::test::get_test_connection};
+
+    #[test]
+    fn test_introspect_procedures() {
+        let mut conn = get_test_connection();
+        let mut tx = conn.transaction().unwrap();
+        tx.execute("CREATE SCHEMA test_procedure", &[]).unwrap();
+        tx.execute(
+            "CREATE PROCEDURE test_procedure.proc1(a integer, b text)
+            LANGUAGE sql
+            AS $$SELECT 1;$$;",
+            &[],
+        )
+        .unwrap();
+        tx.execute(
+            "CREATE FUNCTION test_procedure.func1()
+            RETURNS integer
+            LANGUAGE sql
+            AS $$SELECT 1;$$;",
+            &[],
+        )
+        .unwrap();
+        let res = introspect_procedures(&mut tx, &vec!["test_procedure"]).unwrap();
+
+        assert_eq!(
+            res,
+            vec![Procedure {
+                schema: "test_procedure".to_string(),
+                name: "proc1".to_string(),
+                arguments: "IN a integer, IN b text".to_string(),
+                body: "SELECT 1;".to_string(),
+                language: "sql".to_string(),
+                owner: "postgres".to_string(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,22 @@
+SELECT
+    n.nspname AS schema,
+    p.proname AS name,
+    pg_get_function_arguments(p.oid) AS arguments,
+    p.prosrc AS body,
+    l.lanname AS language,
+    pg_get_userbyid(p.proowner)::text AS owner
+FROM
+    pg_proc p
+    JOIN pg_namespace n ON p.pronamespace = n.oid
+    JOIN pg_language l ON p.prolang = l.oid
+WHERE n.nspname = ANY($1)
+    AND p.prokind = 'p'
+    -- objects belonging to an extension are managed through it
+    AND NOT EXISTS (
+        SELECT 1
+        FROM pg_depend d
+        WHERE d.classid = 'pg_proc'::regclass
+            AND d.objid = p.oid
+            AND d.deptype = 'e'
+    )
+ORDER BY n.nspname, p.proname
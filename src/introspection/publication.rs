@@ -0,0 +1,89 @@
+use postgres::{Error, Transaction};
+
+/// One row per published table, or a single row without a table for an
+/// empty publication.
+#[derive(Debug, PartialEq)]
+pub struct PublicationTable {
+    pub publication: String,
+    pub schema: Option<String>,
+    pub table: Option<String>,
+    pub columns: Vec<String>,
+    pub row_filter: Option<String>,
+}
+
+pub fn introspect_publication_tables(
+    client: &mut Transaction,
+    schemas: &[&str],
+) -> Result<Vec<PublicationTable>, Error> {
+    let query = include_str!("publication.sql");
+    let stmt = client.prepare(query)?;
+    let rows = client.query(&stmt, &[&schemas])?;
+
+    let mut vals = Vec::new();
+    for row in rows {
+        let val = PublicationTable {
+            publication: row.get(0),
+            schema: row.get(1),
+            table: row.get(2),
+            columns: row.get(3),
+            row_filter: row.get(4),
+        };
+        vals.push(val);
+    }
+    Ok(vals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::introspect_publication_tables;
+    use crate::{introspection::publication::PublicationTable, util::test::get_test_connection};
+
+    #[test]
+    fn test_introspect_publication_tables() {
+        let mut conn = get_test_connection();
+        let mut tx = conn.transaction().unwrap();
+        tx.execute("CREATE SCHEMA test_publication", &[]).unwrap();
+        tx.execute(
+            "CREATE TABLE test_publication.orders (id INT PRIMARY KEY, total INT, note TEXT)",
+            &[],
+        )
+        .unwrap();
+        tx.execute(
+            "CREATE TABLE test_publication.customers (id INT PRIMARY KEY)",
+            &[],
+        )
+        .unwrap();
+        tx.execute(
+            "CREATE PUBLICATION test_publication_warehouse FOR
+                TABLE test_publication.orders (id, total) WHERE (total > 0),
+                TABLE test_publication.customers",
+            &[],
+        )
+        .unwrap();
+        tx.execute("CREATE PUBLICATION test_publication_empty", &[])
+            .unwrap();
+        let res = introspect_publication_tables(&mut tx, &vec!["test_publication"]).unwrap();
+
+        assert!(res.contains(&PublicationTable {
+            publication: "test_publication_empty".to_string(),
+            schema: None,
+            table: None,
+            columns: vec![],
+            row_filter: None,
+        }));
+        assert!(res.contains(&PublicationTable {
+            publication: "test_publication_warehouse".to_string(),
+            schema: Some("test_publication".to_string()),
+            table: Some("customers".to_string()),
+            columns: vec![],
+            row_filter: None,
+        }));
+        assert!(res.contains(&PublicationTable {
+            publication: "test_publication_warehouse".to_string(),
+            schema: Some("test_publication".to_string()),
+            table: Some("orders".to_string()),
+            columns: vec!["id".to_string(), "total".to_string()],
+            row_filter: Some("(total > 0)".to_string()),
+        }));
+    }
+}
@@ -0,0 +1,27 @@
+SELECT
+    p.pubname
+        AS "publication",
+    n.nspname::text
+        AS "schema",
+    c.relname::text
+        AS "table",
+    COALESCE(
+        ARRAY(
+            SELECT a.attname::text
+            FROM unnest(pr.prattrs) WITH ORDINALITY AS k(attnum, ord)
+                JOIN pg_attribute a ON a.attrelid = pr.prrelid AND a.attnum = k.attnum
+            ORDER BY k.ord
+        ),
+        '{}'
+    )
+        AS "columns",
+    pg_get_expr(pr.prqual, pr.prrelid)
+        AS "row_filter"
+FROM pg_publication p
+    LEFT JOIN (pg_publication_rel pr
+    JOIN pg_class c ON c.oid = pr.prrelid
+    JOIN pg_namespace n ON n.oid = c.relnamespace
+        AND n.nspname = ANY($1)) ON pr.prpubid = p.oid
+-- publications of all tables are not modelled
+WHERE NOT p.puballtables
+ORDER BY p.pubname, n.nspname, c.relname;
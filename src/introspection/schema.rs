@@ -3,6 +3,7 @@ use postgres::{Error, Transaction};
 #[derive(Debug, PartialEq)]
 pub struct Schema {
     pub name: String,
+    pub owner: String,
 }
 
 pub fn introspect_schemas(
@@ -15,7 +16,10 @@ pub fn introspect_schemas(
 
     let mut vals = Vec::new();
     for row in rows {
-        let val = Schema { name: row.get(0) };
+        let val = Schema {
+            name: row.get(0),
+            owner: row.get(1),
+        };
         vals.push(val);
     }
     Ok(vals)
@@ -36,7 +40,8 @@ mod tests {
         assert_eq!(
             res,
             vec![Schema {
-                name: "test_schema".to_string()
+                name: "test_schema".to_string(),
+                owner: "postgres".to_string(),
             }]
         );
     }
@@ -1,5 +1,8 @@
 SELECT
     nspname
-        AS "schema_name"
+        AS "schema_name",
+    pg_catalog.pg_get_userbyid(nspowner)::text
+        AS "owner"
 FROM pg_catalog.pg_namespace pn
-WHERE nspname = ANY($1);
+WHERE nspname = ANY($1)
+ORDER BY nspname;
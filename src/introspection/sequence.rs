@@ -13,6 +13,7 @@ pub struct Sequence {
     pub cycle: bool,
     pub owned_by_table_name: Option<String>,
     pub owned_by_column_name: Option<String>,
+    pub owner: String,
 }
 
 pub fn introspect_sequences(
@@ -37,6 +38,7 @@ pub fn introspect_sequences(
             cycle: row.get(8),
             owned_by_table_name: row.get(9),
             owned_by_column_name: row.get(10),
+            owner: row.get(11),
         };
         vals.push(val);
     }
@@ -81,6 +83,7 @@ mod tests {
                 cycle: true,
                 owned_by_table_name: None,
                 owned_by_column_name: None,
+                owner: "postgres".to_string(),
             }]
         );
     }
@@ -26,7 +26,9 @@ SELECT
         WHERE pa.attrelid = pc.oid
     ) AS owned_by_table_name,
     pa.attname
-        AS owned_by_column_name
+        AS owned_by_column_name,
+    pg_catalog.pg_get_userbyid(pc.relowner)::text
+        AS owner
 FROM pg_catalog.pg_sequence ps
 JOIN pg_catalog.pg_class pc 
     ON ps.seqrelid = pc.oid
@@ -38,4 +40,13 @@ LEFT JOIN (
             ON pa.attrelid = pd.refobjid
                 AND pa.attnum = pd.refobjsubid 
 ) ON pd.objid = ps.seqrelid
-WHERE pn.nspname = ANY($1);
+WHERE pn.nspname = ANY($1)
+    -- sequences backing identity columns are part of the column
+    AND NOT EXISTS (
+        SELECT 1
+        FROM pg_catalog.pg_depend pdi
+        WHERE pdi.classid = 'pg_class'::regclass
+            AND pdi.objid = ps.seqrelid
+            AND pdi.deptype = 'i'
+    )
+ORDER BY pn.nspname, pc.relname;
@@ -0,0 +1,96 @@
+use postgres::{Error, Transaction};
+
+#[derive(Debug, PartialEq)]
+pub struct Statistics {
+    pub schema: String,
+    pub table: String,
+    pub name: String,
+    pub kinds: Vec<String>,
+    pub expressions: Vec<String>,
+    pub target: Option<i64>,
+}
+
+pub fn introspect_statistics(
+    client: &mut Transaction,
+    schemas: &[&str],
+) -> Result<Vec<Statistics>, Error> {
+    let query = include_str!("statistics.sql");
+    let stmt = client.prepare(query)?;
+    let rows = client.query(&stmt, &[&schemas])?;
+
+    let mut vals = Vec::new();
+    for row in rows {
+        let val = Statistics {
+            schema: row.get(0),
+            table: row.get(1),
+            name: row.get(2),
+            kinds: row.get(3),
+            expressions: row.get(4),
+            target: row.get(5),
+        };
+        vals.push(val);
+    }
+    Ok(vals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::introspect_statistics;
+    use crate::{introspection::statistics::Statistics, util::test::get_test_connection};
+
+    #[test]
+    fn test_introspect_statistics() {
+        let mut conn = get_test_connection();
+        let mut tx = conn.transaction().unwrap();
+        tx.execute("CREATE SCHEMA test_statistics", &[]).unwrap();
+        tx.execute(
+            "CREATE TABLE test_statistics.address (city TEXT, zip TEXT, street TEXT);",
+            &[],
+        )
+        .unwrap();
+        tx.execute(
+            "CREATE STATISTICS test_statistics.address_city_zip (ndistinct, dependencies)
+                ON zip, city FROM test_statistics.address;",
+            &[],
+        )
+        .unwrap();
+        tx.execute(
+            "CREATE STATISTICS test_statistics.address_street
+                ON city, lower(street) FROM test_statistics.address;",
+            &[],
+        )
+        .unwrap();
+        tx.execute(
+            "ALTER STATISTICS test_statistics.address_street SET STATISTICS 500;",
+            &[],
+        )
+        .unwrap();
+        let res = introspect_statistics(&mut tx, &vec!["test_statistics"]).unwrap();
+
+        assert_eq!(
+            res,
+            vec![
+                Statistics {
+                    schema: "test_statistics".to_string(),
+                    table: "address".to_string(),
+                    name: "address_city_zip".to_string(),
+                    kinds: vec!["ndistinct".to_string(), "dependencies".to_string()],
+                    expressions: vec!["city".to_string(), "zip".to_string()],
+                    target: None,
+                },
+                Statistics {
+                    schema: "test_statistics".to_string(),
+                    table: "address".to_string(),
+                    name: "address_street".to_string(),
+                    kinds: vec![
+                        "ndistinct".to_string(),
+                        "dependencies".to_string(),
+                        "mcv".to_string(),
+                    ],
+                    expressions: vec!["city".to_string(), "(lower(street))".to_string()],
+                    target: Some(500),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,37 @@
+SELECT
+    n.nspname
+        AS "schema",
+    c.relname
+        AS "table",
+    s.stxname
+        AS "name",
+    -- the expressions kind comes along with expressions on its own
+    ARRAY(
+        SELECT CASE k::text
+            WHEN 'd' THEN 'ndistinct'
+            WHEN 'f' THEN 'dependencies'
+            WHEN 'm' THEN 'mcv'
+        END
+        FROM unnest(s.stxkind) AS k
+        WHERE k::text <> 'e'
+        ORDER BY array_position(ARRAY['d', 'f', 'm'], k::text)
+    )
+        AS "kinds",
+    ARRAY(
+        SELECT a.attname::text
+        FROM unnest(s.stxkeys::int2[]) WITH ORDINALITY AS k(attnum, i)
+            JOIN pg_attribute a ON a.attrelid = s.stxrelid AND a.attnum = k.attnum
+        ORDER BY k.i
+    ) || ARRAY(
+        SELECT '(' || e || ')'
+        FROM unnest(pg_get_statisticsobjdef_expressions(s.oid)) WITH ORDINALITY AS x(e, i)
+        ORDER BY x.i
+    )
+        AS "expressions",
+    CASE WHEN s.stxstattarget >= 0 THEN s.stxstattarget::int8 END
+        AS "target"
+FROM pg_statistic_ext s
+    JOIN pg_namespace n ON n.oid = s.stxnamespace
+    JOIN pg_class c ON c.oid = s.stxrelid
+WHERE n.nspname = ANY($1)
+ORDER BY c.relname, s.stxname;
@@ -4,6 +4,21 @@ use postgres::{Error, Transaction};
 pub struct Table {
     pub schema: String,
     pub name: String,
+    pub partition_strategy: Option<String>,
+    pub partition_key_expressions: Option<Vec<String>>,
+    pub partition_parent_schema: Option<String>,
+    pub partition_parent: Option<String>,
+    pub partition_bound: Option<String>,
+    pub unlogged: bool,
+    pub storage_parameters: Vec<String>,
+    pub tablespace: Option<String>,
+    pub inherits_schemas: Vec<String>,
+    pub inherits: Vec<String>,
+    pub replica_identity: String,
+    pub replica_identity_index: Option<String>,
+    pub row_level_security: bool,
+    pub force_row_level_security: bool,
+    pub owner: String,
 }
 
 pub fn introspect_tables(client: &mut Transaction, schemas: &[&str]) -> Result<Vec<Table>, Error> {
@@ -13,15 +28,61 @@ pub fn introspect_tables(client: &mut Transaction, schemas: &[&str]) -> Result<V
 
     let mut vals = Vec::new();
     for row in rows {
+        let partition_key: Option<String> = row.get(3);
         let val = Table {
             schema: row.get(0),
             name: row.get(1),
+            partition_strategy: row.get(2),
+            partition_key_expressions: partition_key.map(|k| split_partition_key(&k)),
+            partition_parent_schema: row.get(4),
+            partition_parent: row.get(5),
+            partition_bound: row.get(6),
+            unlogged: row.get(7),
+            storage_parameters: row.get(8),
+            tablespace: row.get(9),
+            inherits_schemas: row.get(10),
+            inherits: row.get(11),
+            replica_identity: row.get(12),
+            replica_identity_index: row.get(13),
+            row_level_security: row.get(14),
+            force_row_level_security: row.get(15),
+            owner: row.get(16),
         };
         vals.push(val);
     }
     Ok(vals)
 }
 
+// Splits the output of `pg_get_partkeydef`, e.g. `RANGE (a, lower(b))`, into
+// its key expressions.
+fn split_partition_key(def: &str) -> Vec<String> {
+    let inner = match (def.find('('), def.rfind(')')) {
+        (Some(start), Some(end)) if start < end => &def[start + 1..end],
+        _ => return vec![],
+    };
+
+    let mut keys = vec![];
+    let mut current = String::new();
+    let mut depth = 0;
+    let mut quoted = false;
+    for c in inner.chars() {
+        match c {
+            '\'' | '"' => quoted = !quoted,
+            '(' if !quoted => depth += 1,
+            ')' if !quoted => depth -= 1,
+            ',' if !quoted && depth == 0 => {
+                keys.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    keys.push(current.trim().to_string());
+    keys
+}
+
 #[cfg(test)]
 mod tests {
     use super::introspect_tables;
@@ -41,7 +102,206 @@ mod tests {
             vec![Table {
                 schema: "test_table".to_string(),
                 name: "foo".to_string(),
+                partition_strategy: None,
+                partition_key_expressions: None,
+                partition_parent_schema: None,
+                partition_parent: None,
+                partition_bound: None,
+                unlogged: false,
+                storage_parameters: vec![],
+                tablespace: None,
+                inherits_schemas: vec![],
+                inherits: vec![],
+                replica_identity: "default".to_string(),
+                replica_identity_index: None,
+                row_level_security: false,
+                force_row_level_security: false,
+                owner: "postgres".to_string(),
             }]
         );
     }
+
+    #[test]
+    fn test_introspect_partitioned_tables() {
+        let mut conn = get_test_connection();
+        let mut tx = conn.transaction().unwrap();
+        tx.execute("CREATE SCHEMA test_partition", &[]).unwrap();
+        tx.execute(
+            "CREATE TABLE test_partition.events (
+                id INT,
+                created_at DATE
+            ) PARTITION BY RANGE (created_at, (id % 10));",
+            &[],
+        )
+        .unwrap();
+        tx.execute(
+            "CREATE TABLE test_partition.events_2024 PARTITION OF test_partition.events
+                FOR VALUES FROM ('2024-01-01', 0) TO ('2025-01-01', 0);",
+            &[],
+        )
+        .unwrap();
+        tx.execute("CREATE SCHEMA test_partition_archive", &[])
+            .unwrap();
+        tx.execute(
+            "CREATE TABLE test_partition_archive.events_2023 PARTITION OF test_partition.events
+                FOR VALUES FROM ('2023-01-01', 0) TO ('2024-01-01', 0);",
+            &[],
+        )
+        .unwrap();
+        let res = introspect_tables(
+            &mut tx,
+            &vec!["test_partition", "test_partition_archive"],
+        )
+        .unwrap();
+
+        assert_eq!(
+            res,
+            vec![
+                Table {
+                    schema: "test_partition".to_string(),
+                    name: "events".to_string(),
+                    partition_strategy: Some("range".to_string()),
+                    partition_key_expressions: Some(vec![
+                        "created_at".to_string(),
+                        "((id % 10))".to_string(),
+                    ]),
+                    partition_parent_schema: None,
+                    partition_parent: None,
+                    partition_bound: None,
+                    unlogged: false,
+                    storage_parameters: vec![],
+                    tablespace: None,
+                    inherits_schemas: vec![],
+                    inherits: vec![],
+                    replica_identity: "default".to_string(),
+                    replica_identity_index: None,
+                    row_level_security: false,
+                    force_row_level_security: false,
+                    owner: "postgres".to_string(),
+                },
+                Table {
+                    schema: "test_partition_archive".to_string(),
+                    name: "events_2023".to_string(),
+                    partition_strategy: None,
+                    partition_key_expressions: None,
+                    partition_parent_schema: Some("test_partition".to_string()),
+                    partition_parent: Some("events".to_string()),
+                    partition_bound: Some(
+                        "FOR VALUES FROM ('2023-01-01', 0) TO ('2024-01-01', 0)".to_string()
+                    ),
+                    unlogged: false,
+                    storage_parameters: vec![],
+                    tablespace: None,
+                    inherits_schemas: vec![],
+                    inherits: vec![],
+                    replica_identity: "default".to_string(),
+                    replica_identity_index: None,
+                    row_level_security: false,
+                    force_row_level_security: false,
+                    owner: "postgres".to_string(),
+                },
+                Table {
+                    schema: "test_partition".to_string(),
+                    name: "events_2024".to_string(),
+                    partition_strategy: None,
+                    partition_key_expressions: None,
+                    partition_parent_schema: Some("test_partition".to_string()),
+                    partition_parent: Some("events".to_string()),
+                    partition_bound: Some(
+                        "FOR VALUES FROM ('2024-01-01', 0) TO ('2025-01-01', 0)".to_string()
+                    ),
+                    unlogged: false,
+                    storage_parameters: vec![],
+                    tablespace: None,
+                    inherits_schemas: vec![],
+                    inherits: vec![],
+                    replica_identity: "default".to_string(),
+                    replica_identity_index: None,
+                    row_level_security: false,
+                    force_row_level_security: false,
+                    owner: "postgres".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_introspect_table_options() {
+        let mut conn = get_test_connection();
+        let mut tx = conn.transaction().unwrap();
+        tx.execute("CREATE SCHEMA test_table_options", &[]).unwrap();
+        tx.execute("CREATE TABLE test_table_options.base (id INT);", &[])
+            .unwrap();
+        tx.execute(
+            "ALTER TABLE test_table_options.base REPLICA IDENTITY FULL;",
+            &[],
+        )
+        .unwrap();
+        tx.execute("CREATE SCHEMA test_table_options_shared", &[])
+            .unwrap();
+        tx.execute(
+            "CREATE TABLE test_table_options_shared.audit (at TIMESTAMPTZ);",
+            &[],
+        )
+        .unwrap();
+        tx.execute(
+            "CREATE UNLOGGED TABLE test_table_options.staging (note TEXT)
+                INHERITS (test_table_options.base, test_table_options_shared.audit)
+                WITH (fillfactor=70, autovacuum_enabled=false)
+                TABLESPACE pg_default;",
+            &[],
+        )
+        .unwrap();
+        let res = introspect_tables(&mut tx, &vec!["test_table_options"]).unwrap();
+
+        assert_eq!(
+            res,
+            vec![
+                Table {
+                    schema: "test_table_options".to_string(),
+                    name: "base".to_string(),
+                    partition_strategy: None,
+                    partition_key_expressions: None,
+                    partition_parent_schema: None,
+                    partition_parent: None,
+                    partition_bound: None,
+                    unlogged: false,
+                    storage_parameters: vec![],
+                    tablespace: None,
+                    inherits_schemas: vec![],
+                    inherits: vec![],
+                    replica_identity: "full".to_string(),
+                    replica_identity_index: None,
+                    row_level_security: false,
+                    force_row_level_security: false,
+                    owner: "postgres".to_string(),
+                },
+                Table {
+                    schema: "test_table_options".to_string(),
+                    name: "staging".to_string(),
+                    partition_strategy: None,
+                    partition_key_expressions: None,
+                    partition_parent_schema: None,
+                    partition_parent: None,
+                    partition_bound: None,
+                    unlogged: true,
+                    storage_parameters: vec![
+                        "fillfactor=70".to_string(),
+                        "autovacuum_enabled=false".to_string(),
+                    ],
+                    tablespace: None,
+                    inherits_schemas: vec![
+                        "test_table_options".to_string(),
+                        "test_table_options_shared".to_string(),
+                    ],
+                    inherits: vec!["base".to_string(), "audit".to_string()],
+                    replica_identity: "default".to_string(),
+                    replica_identity_index: None,
+                    row_level_security: false,
+                    force_row_level_security: false,
+                    owner: "postgres".to_string(),
+                },
+            ]
+        );
+    }
 }
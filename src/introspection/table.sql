@@ -2,11 +2,77 @@ SELECT
     nc.nspname
         AS "schema",
     c.relname
-        AS "name"
+        AS "name",
+    CASE pt.partstrat
+        WHEN 'r' THEN 'range'
+        WHEN 'l' THEN 'list'
+        WHEN 'h' THEN 'hash'
+    END
+        AS "partition_strategy",
+    CASE
+        WHEN pt.partrelid IS NOT NULL THEN pg_get_partkeydef(c.oid)
+    END
+        AS "partition_key",
+    parent_ns.nspname
+        AS "partition_parent_schema",
+    parent.relname
+        AS "partition_parent",
+    CASE
+        WHEN c.relispartition THEN pg_get_expr(c.relpartbound, c.oid)
+    END
+        AS "partition_bound",
+    c.relpersistence = 'u'
+        AS "unlogged",
+    COALESCE(c.reloptions, '{}')
+        AS "storage_parameters",
+    ts.spcname::text
+        AS "tablespace",
+    ARRAY(
+        SELECT ipn.nspname::text
+        FROM pg_inherits ii
+            JOIN pg_class ip ON ip.oid = ii.inhparent
+            JOIN pg_namespace ipn ON ipn.oid = ip.relnamespace
+        WHERE ii.inhrelid = c.oid AND NOT c.relispartition
+        ORDER BY ii.inhseqno
+    )
+        AS "inherits_schemas",
+    ARRAY(
+        SELECT ip.relname::text
+        FROM pg_inherits ii
+            JOIN pg_class ip ON ip.oid = ii.inhparent
+        WHERE ii.inhrelid = c.oid AND NOT c.relispartition
+        ORDER BY ii.inhseqno
+    )
+        AS "inherits",
+    CASE c.relreplident
+        WHEN 'd' THEN 'default'
+        WHEN 'f' THEN 'full'
+        WHEN 'n' THEN 'nothing'
+        WHEN 'i' THEN 'index'
+    END
+        AS "replica_identity",
+    (
+        SELECT ri.relname::text
+        FROM pg_index rx
+            JOIN pg_class ri ON ri.oid = rx.indexrelid
+        WHERE rx.indrelid = c.oid AND rx.indisreplident
+    )
+        AS "replica_identity_index",
+    c.relrowsecurity
+        AS "row_level_security",
+    c.relforcerowsecurity
+        AS "force_row_level_security",
+    pg_get_userbyid(c.relowner)::text
+        AS "owner"
 FROM pg_namespace nc
     JOIN pg_class c ON nc.oid = c.relnamespace
     LEFT JOIN (pg_type t
     JOIN pg_namespace nt ON t.typnamespace = nt.oid) ON c.reloftype = t.oid
+    LEFT JOIN pg_partitioned_table pt ON pt.partrelid = c.oid
+    LEFT JOIN pg_inherits i ON i.inhrelid = c.oid AND c.relispartition
+    LEFT JOIN pg_class parent ON parent.oid = i.inhparent
+    LEFT JOIN pg_namespace parent_ns ON parent_ns.oid = parent.relnamespace
+    LEFT JOIN pg_tablespace ts ON ts.oid = c.reltablespace
 WHERE c.relkind = ANY (ARRAY['r'::"char", 'p'::"char"])
     AND NOT pg_is_other_temp_schema(nc.oid)
     AND (
@@ -14,4 +80,5 @@ WHERE c.relkind = ANY (ARRAY['r'::"char", 'p'::"char"])
         OR has_table_privilege(c.oid, 'SELECT, INSERT, UPDATE, DELETE, TRUNCATE, REFERENCES, TRIGGER'::text)
         OR has_any_column_privilege(c.oid, 'SELECT, INSERT, UPDATE, REFERENCES'::text)
     )
-    AND nc.nspname = ANY($1);
+    AND nc.nspname = ANY($1)
+ORDER BY c.relname;
@@ -27,4 +27,5 @@ WHERE nc.oid = c.connamespace
         OR has_table_privilege(r.oid, 'INSERT, UPDATE, DELETE, TRUNCATE, REFERENCES, TRIGGER'::text)
         OR has_any_column_privilege(r.oid, 'INSERT, UPDATE, REFERENCES'::text)
     )
-    AND nc.nspname = ANY($1);
+    AND nc.nspname = ANY($1)
+ORDER BY nc.nspname, r.relname, c.conname;
@@ -43,4 +43,5 @@ WHERE nc.nspname = ANY($1)
     AND NOT pg_is_other_temp_schema(nc.oid)
     AND (pg_has_role(c.relowner, 'USAGE'::text)
     OR has_table_privilege(c.oid, 'SELECT, INSERT, UPDATE, DELETE, TRUNCATE, REFERENCES, TRIGGER'::text)
-    OR has_any_column_privilege(c.oid, 'SELECT, INSERT, UPDATE, REFERENCES'::text));
+    OR has_any_column_privilege(c.oid, 'SELECT, INSERT, UPDATE, REFERENCES'::text))
+ORDER BY nc.nspname, c.relname;
@@ -31,6 +31,7 @@ fn main() {
           Command::new("stage")
           .about("calculates diff and puts it to the stage area")
           .arg(clap::arg!(--"profile" <String>).default_value("database"))
+          .arg(clap::arg!(--"detect-renames" "asks whether look-alike dropped and created objects were renamed"))
         )
         .subcommand(
           Command::new("reset")
@@ -54,6 +55,7 @@ fn main() {
           .about("reads the history directory and applies downward migration")
           .arg(clap::arg!(--"profile" <String>).default_value("database"))
           .arg(clap::arg!(--"version" <String>))
+          .arg(clap::arg!(--"force" "reverts irreversible versions too, losing what they dropped"))
         )
         .subcommand(
           Command::new("clone")
@@ -74,11 +76,11 @@ fn main() {
     let res = match matches.subcommand() {
         Some(("init", _)) => cmd::init(),
         Some(("status", args)) => cmd::status(get_profile(args)),
-        Some(("stage", args)) => cmd::stage(get_profile(args)),
+        Some(("stage", args)) => cmd::stage(get_profile(args), args.get_flag("detect-renames")),
         Some(("reset", args)) => cmd::reset(get_profile(args)),
         Some(("make", args)) => cmd::make(get_profile(args), get_version(args)),
         Some(("up", args)) => cmd::up(get_profile(args), get_version(args)),
-        Some(("down", args)) => cmd::down(get_profile(args), get_version(args)),
+        Some(("down", args)) => cmd::down(get_profile(args), get_version(args), args.get_flag("force")),
         Some(("clone", args)) => cmd::clone(get_profile(args)),
         Some(("sync", args)) => cmd::sync(get_profile(args)),
         // Some(("print", _)) => cmd::print(),
@@ -1,6 +1,7 @@
 use crate::util::expand::expand_envvar;
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, fmt::Display, fs, path::PathBuf};
+use std::{fmt::Display, fs, path::PathBuf};
 
 /// Project
 ///
@@ -26,6 +27,15 @@ use std::{collections::HashMap, fmt::Display, fs, path::PathBuf};
 ///   history: database/history
 /// ```
 ///
+/// Ownership of new objects can be given to a role by default, or left out of
+/// the migrations altogether:
+///
+/// ```yaml
+/// database:
+///   default_owner: app_owner
+///   ignore_ownership: false
+/// ```
+///
 /// Environment variables can be used in the profile fields:
 ///
 /// ```yaml
@@ -75,6 +85,17 @@ impl Project {
         let profile = self.get_profile(profile_name)?;
         Ok(profile.metadata_schema.clone())
     }
+    pub fn resolve_default_owner(
+        &self,
+        profile_name: &str,
+    ) -> Result<Option<String>, ProjectError> {
+        let profile = self.get_profile(profile_name)?;
+        Ok(profile.default_owner.clone())
+    }
+    pub fn resolve_ignore_ownership(&self, profile_name: &str) -> Result<bool, ProjectError> {
+        let profile = self.get_profile(profile_name)?;
+        Ok(profile.ignore_ownership)
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -84,11 +105,13 @@ pub struct Profile {
     history: PathBuf,
     definitions: PathBuf,
     metadata_schema: String,
+    default_owner: Option<String>,
+    ignore_ownership: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct YAMLProject {
-    pub profiles: HashMap<String, YAMLProjectProfile>,
+    pub profiles: IndexMap<String, YAMLProjectProfile>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -97,6 +120,10 @@ pub struct YAMLProjectProfile {
     pub history: String,
     pub definitions: String,
     pub metadata_schema: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_owner: Option<String>,
+    #[serde(default)]
+    pub ignore_ownership: bool,
 }
 
 const CONFIG_FILE: &str = ".podo.yaml";
@@ -147,6 +174,8 @@ fn load_project_from_str(base_dir: PathBuf, s: &str) -> Result<Project, ProjectE
             history: base_dir.join(expand_envvar(&profile.history)),
             definitions: base_dir.join(expand_envvar(&profile.definitions)),
             metadata_schema: profile.metadata_schema.clone(),
+            default_owner: profile.default_owner.as_ref().map(|o| expand_envvar(o)),
+            ignore_ownership: profile.ignore_ownership,
         });
     }
 
@@ -155,7 +184,7 @@ fn load_project_from_str(base_dir: PathBuf, s: &str) -> Result<Project, ProjectE
 
 pub fn create_base_yaml_project() -> YAMLProject {
     let mut project = YAMLProject {
-        profiles: HashMap::new(),
+        profiles: IndexMap::new(),
     };
     project.profiles.insert(
         DEFAULT_PROFILE.to_owned(),
@@ -164,6 +193,8 @@ pub fn create_base_yaml_project() -> YAMLProject {
             history: "database/history".to_string(),
             definitions: "database/definitions".to_string(),
             metadata_schema: "podo_meta".to_string(),
+            default_owner: None,
+            ignore_ownership: false,
         },
     );
     project
@@ -1,6 +1,6 @@
 use super::super::Change;
 use super::DropCheckChange;
-use crate::snapshot::{Check, Database, SnapshotError};
+use crate::snapshot::{defaults::get_default_description, Check, Database, SnapshotError};
 use crate::util::sqlfmt::{sql_qa, sql_qn};
 use serde::{Deserialize, Serialize};
 
@@ -38,6 +38,7 @@ impl AddCheckChange {
                 expression: self.expression.clone(),
                 deferrable: self.deferrable,
                 initially_deferred: self.initially_deferred,
+                comment: get_default_description(),
             }
             .into(),
         )?;
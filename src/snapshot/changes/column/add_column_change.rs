@@ -1,7 +1,7 @@
 use super::super::Change;
-use super::DropColumnChange;
-use crate::snapshot::{Column, Database, SnapshotError};
-use crate::util::sqlfmt::{sql_qa, sql_qn};
+use super::{ColumnIdentity, DropColumnChange};
+use crate::snapshot::{defaults::get_default_description, Column, Database, SnapshotError};
+use crate::util::sqlfmt::{sql_collation, sql_qa, sql_qn};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -10,8 +10,12 @@ pub struct AddColumnChange {
     pub table: String,
     pub column: String,
     pub data_type: String,
+    pub collation: Option<String>,
+    pub compression: Option<String>,
     pub not_null: bool,
     pub default: Option<String>,
+    pub generated: Option<String>,
+    pub identity: Option<ColumnIdentity>,
 }
 
 impl AddColumnChange {
@@ -21,8 +25,12 @@ impl AddColumnChange {
             table: t.table_name.clone(),
             column: t.name.clone(),
             data_type: t.data_type.clone(),
+            collation: t.collation.clone(),
+            compression: t.compression.clone(),
             not_null: t.not_null,
             default: t.default.clone(),
+            generated: t.generated.clone(),
+            identity: t.identity.as_ref().map(ColumnIdentity::new),
         }
     }
 
@@ -35,8 +43,20 @@ impl AddColumnChange {
             table_name: self.table.clone(),
             name: self.column.clone(),
             data_type: self.data_type.clone(),
+            collation: self.collation.clone(),
             not_null: self.not_null,
             default: self.default.clone(),
+            generated: self.generated.clone(),
+            identity: self.identity.as_ref().map(|i| i.to_identity()),
+            storage: None,
+            compression: self.compression.clone(),
+            statistics: None,
+            comment: get_default_description(),
+            grants: vec![],
+            revokes: vec![],
+            using: None,
+            using_from: None,
+            revert_using: None,
         };
         table.add_column(column)?;
         Ok(())
@@ -49,12 +69,24 @@ impl AddColumnChange {
             sql_qn(&self.column),
             self.data_type,
         )];
-        if !self.not_null {
+        if let Some(compression) = &self.compression {
+            tokens.push(format!("COMPRESSION {}", compression));
+        }
+        if let Some(collation) = &self.collation {
+            tokens.push(format!("COLLATE {}", sql_collation(collation)));
+        }
+        if self.not_null {
             tokens.push("NOT NULL".to_string());
         }
         if let Some(default) = &self.default {
             tokens.push(format!("DEFAULT {}", default));
         }
+        if let Some(generated) = &self.generated {
+            tokens.push(format!("GENERATED ALWAYS AS ({}) STORED", generated));
+        }
+        if let Some(identity) = &self.identity {
+            tokens.push(identity.render_sql());
+        }
 
         tokens.join(" ") + ";"
     }
@@ -0,0 +1,51 @@
+use super::super::Change;
+use super::{AlterColumnDropIdentityChange, ColumnIdentity};
+use crate::snapshot::{Column, Database, SnapshotError};
+use crate::util::sqlfmt::{sql_qa, sql_qn};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AlterColumnAddIdentityChange {
+    pub schema: String,
+    pub table: String,
+    pub column: String,
+    pub identity: ColumnIdentity,
+}
+
+impl AlterColumnAddIdentityChange {
+    pub fn new(t: &Column) -> Self {
+        Self {
+            schema: t.schema_name.clone(),
+            table: t.table_name.clone(),
+            column: t.name.clone(),
+            identity: ColumnIdentity::new(t.identity.as_ref().unwrap()),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let table = schema.get_relation_mut(&self.table)?.as_table_mut()?;
+        let column = table.get_column_mut(&self.column)?;
+
+        column.identity = Some(self.identity.to_identity());
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER TABLE {} ALTER COLUMN {} ADD {};",
+            sql_qa(&self.schema, &self.table),
+            sql_qn(&self.column),
+            self.identity.render_sql(),
+        )
+    }
+
+    pub fn revert(&self, _source: &Database) -> Result<Change, SnapshotError> {
+        Ok(AlterColumnDropIdentityChange {
+            schema: self.schema.clone(),
+            table: self.table.clone(),
+            column: self.column.clone(),
+        }
+        .into())
+    }
+}
@@ -0,0 +1,48 @@
+use super::super::Change;
+use crate::snapshot::{Column, Database, SnapshotError};
+use crate::util::sqlfmt::{sql_qa, sql_qn};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AlterColumnDropExpressionChange {
+    pub schema: String,
+    pub table: String,
+    pub column: String,
+}
+
+impl AlterColumnDropExpressionChange {
+    pub fn new(s: &Column) -> Self {
+        Self {
+            schema: s.schema_name.clone(),
+            table: s.table_name.clone(),
+            column: s.name.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let table = schema.get_relation_mut(&self.table)?.as_table_mut()?;
+        let column = table.get_column_mut(&self.column)?;
+
+        column.generated = None;
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER TABLE {} ALTER COLUMN {} DROP EXPRESSION;",
+            sql_qa(&self.schema, &self.table),
+            sql_qn(&self.column),
+        )
+    }
+
+    // PostgreSQL has no way to turn a regular column back into a generated
+    // one, short of recreating it.
+    pub fn revert(&self, _source: &Database) -> Result<Change, SnapshotError> {
+        Err(SnapshotError::column_expression_not_restorable(
+            &self.schema,
+            &self.table,
+            &self.column,
+        ))
+    }
+}
@@ -0,0 +1,47 @@
+use super::super::Change;
+use super::AlterColumnAddIdentityChange;
+use crate::snapshot::{Column, Database, SnapshotError};
+use crate::util::sqlfmt::{sql_qa, sql_qn};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AlterColumnDropIdentityChange {
+    pub schema: String,
+    pub table: String,
+    pub column: String,
+}
+
+impl AlterColumnDropIdentityChange {
+    pub fn new(s: &Column) -> Self {
+        Self {
+            schema: s.schema_name.clone(),
+            table: s.table_name.clone(),
+            column: s.name.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let table = schema.get_relation_mut(&self.table)?.as_table_mut()?;
+        let column = table.get_column_mut(&self.column)?;
+
+        column.identity = None;
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER TABLE {} ALTER COLUMN {} DROP IDENTITY;",
+            sql_qa(&self.schema, &self.table),
+            sql_qn(&self.column),
+        )
+    }
+
+    pub fn revert(&self, source: &Database) -> Result<Change, SnapshotError> {
+        let schema = source.get_schema(&self.schema)?;
+        let table = schema.get_relation(&self.table)?.as_table()?;
+        let column = table.get_column(&self.column)?;
+
+        Ok(AlterColumnAddIdentityChange::new(column).into())
+    }
+}
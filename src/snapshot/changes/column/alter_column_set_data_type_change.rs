@@ -1,6 +1,6 @@
 use super::super::Change;
 use crate::snapshot::{Column, Database, SnapshotError};
-use crate::util::sqlfmt::{sql_qa, sql_qn};
+use crate::util::sqlfmt::{sql_collation, sql_qa, sql_qn};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -9,6 +9,9 @@ pub struct AlterColumnSetDataTypeChange {
     pub table: String,
     pub column: String,
     pub data_type: String,
+    pub collation: Option<String>,
+    pub using: Option<String>,
+    pub revert_using: Option<String>,
 }
 
 impl AlterColumnSetDataTypeChange {
@@ -18,6 +21,9 @@ impl AlterColumnSetDataTypeChange {
             table: t.table_name.clone(),
             column: t.name.clone(),
             data_type: t.data_type.clone(),
+            collation: t.collation.clone(),
+            using: t.using.clone(),
+            revert_using: t.revert_using.clone(),
         }
     }
 
@@ -27,15 +33,25 @@ impl AlterColumnSetDataTypeChange {
         let column = table.get_column_mut(&self.column)?;
 
         column.data_type = self.data_type.clone();
+        column.collation = self.collation.clone();
         Ok(())
     }
 
     pub fn render_sql(&self) -> String {
+        // leaving out COLLATE resets the column to the type's default
         format!(
-            "ALTER TABLE {} ALTER COLUMN {} SET DATA TYPE {};",
+            "ALTER TABLE {} ALTER COLUMN {} SET DATA TYPE {}{}{};",
             sql_qa(&self.schema, &self.table),
             sql_qn(&self.column),
             self.data_type,
+            match &self.collation {
+                Some(collation) => format!(" COLLATE {}", sql_collation(collation)),
+                None => "".to_string(),
+            },
+            match &self.using {
+                Some(using) => format!(" USING ({})", using),
+                None => "".to_string(),
+            },
         )
     }
 
@@ -44,11 +60,11 @@ impl AlterColumnSetDataTypeChange {
         let table = schema.get_relation(&self.table)?.as_table()?;
         let column = table.get_column(&self.column)?;
 
+        // the conversion back is the one told for the way forward
         Ok(AlterColumnSetDataTypeChange {
-            schema: schema.name.clone(),
-            table: table.name.clone(),
-            column: column.name.clone(),
-            data_type: column.data_type.clone(),
+            using: self.revert_using.clone(),
+            revert_using: self.using.clone(),
+            ..AlterColumnSetDataTypeChange::new(column)
         }
         .into())
     }
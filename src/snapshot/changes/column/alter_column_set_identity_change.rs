@@ -0,0 +1,56 @@
+use super::super::Change;
+use super::ColumnIdentity;
+use crate::snapshot::{Column, Database, SnapshotError};
+use crate::util::sqlfmt::{sql_qa, sql_qn};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AlterColumnSetIdentityChange {
+    pub schema: String,
+    pub table: String,
+    pub column: String,
+    pub identity: ColumnIdentity,
+}
+
+impl AlterColumnSetIdentityChange {
+    pub fn new(t: &Column) -> Self {
+        Self {
+            schema: t.schema_name.clone(),
+            table: t.table_name.clone(),
+            column: t.name.clone(),
+            identity: ColumnIdentity::new(t.identity.as_ref().unwrap()),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let table = schema.get_relation_mut(&self.table)?.as_table_mut()?;
+        let column = table.get_column_mut(&self.column)?;
+
+        column.identity = Some(self.identity.to_identity());
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER TABLE {} ALTER COLUMN {} SET GENERATED {} SET INCREMENT BY {} SET MINVALUE {} SET MAXVALUE {} SET START WITH {} SET CACHE {} SET {};",
+            sql_qa(&self.schema, &self.table),
+            sql_qn(&self.column),
+            self.identity.render_generated_sql(),
+            self.identity.increment,
+            self.identity.min_value,
+            self.identity.max_value,
+            self.identity.start,
+            self.identity.cache,
+            if self.identity.cycle { "CYCLE" } else { "NO CYCLE" },
+        )
+    }
+
+    pub fn revert(&self, source: &Database) -> Result<Change, SnapshotError> {
+        let schema = source.get_schema(&self.schema)?;
+        let table = schema.get_relation(&self.table)?.as_table()?;
+        let column = table.get_column(&self.column)?;
+
+        Ok(AlterColumnSetIdentityChange::new(column).into())
+    }
+}
@@ -0,0 +1,50 @@
+use super::super::Change;
+use crate::snapshot::{Column, Database, SnapshotError};
+use crate::util::sqlfmt::{sql_qa, sql_qn};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AlterColumnSetStatisticsChange {
+    pub schema: String,
+    pub table: String,
+    pub column: String,
+    pub statistics: Option<i64>,
+}
+
+impl AlterColumnSetStatisticsChange {
+    pub fn new(t: &Column) -> Self {
+        Self {
+            schema: t.schema_name.clone(),
+            table: t.table_name.clone(),
+            column: t.name.clone(),
+            statistics: t.statistics,
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let table = schema.get_relation_mut(&self.table)?.as_table_mut()?;
+        let column = table.get_column_mut(&self.column)?;
+
+        column.statistics = self.statistics;
+        Ok(())
+    }
+
+    // -1 reverts to the server's `default_statistics_target`
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER TABLE {} ALTER COLUMN {} SET STATISTICS {};",
+            sql_qa(&self.schema, &self.table),
+            sql_qn(&self.column),
+            self.statistics.unwrap_or(-1),
+        )
+    }
+
+    pub fn revert(&self, target: &Database) -> Result<Change, SnapshotError> {
+        let schema = target.get_schema(&self.schema)?;
+        let table = schema.get_relation(&self.table)?.as_table()?;
+        let column = table.get_column(&self.column)?;
+
+        Ok(AlterColumnSetStatisticsChange::new(column).into())
+    }
+}
@@ -0,0 +1,60 @@
+use crate::snapshot::Identity;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ColumnIdentity {
+    pub always: bool,
+    pub increment: i64,
+    pub min_value: i64,
+    pub max_value: i64,
+    pub start: i64,
+    pub cache: i64,
+    pub cycle: bool,
+}
+
+impl ColumnIdentity {
+    pub fn new(t: &Identity) -> Self {
+        Self {
+            always: t.always,
+            increment: t.increment,
+            min_value: t.min_value,
+            max_value: t.max_value,
+            start: t.start,
+            cache: t.cache,
+            cycle: t.cycle,
+        }
+    }
+
+    pub fn to_identity(&self) -> Identity {
+        Identity {
+            always: self.always,
+            increment: self.increment,
+            min_value: self.min_value,
+            max_value: self.max_value,
+            start: self.start,
+            cache: self.cache,
+            cycle: self.cycle,
+        }
+    }
+
+    pub fn render_generated_sql(&self) -> &'static str {
+        if self.always {
+            "ALWAYS"
+        } else {
+            "BY DEFAULT"
+        }
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "GENERATED {} AS IDENTITY (INCREMENT BY {} MINVALUE {} MAXVALUE {} START WITH {} CACHE {} {})",
+            self.render_generated_sql(),
+            self.increment,
+            self.min_value,
+            self.max_value,
+            self.start,
+            self.cache,
+            if self.cycle { "CYCLE" } else { "NO CYCLE" },
+        )
+    }
+}
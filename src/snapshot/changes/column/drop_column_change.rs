@@ -41,14 +41,6 @@ impl DropColumnChange {
         let table = schema.get_relation(&self.table)?.as_table()?;
         let column = table.get_column(&self.column)?;
 
-        Ok(AddColumnChange {
-            schema: schema.name.clone(),
-            table: table.name.clone(),
-            column: column.name.clone(),
-            data_type: column.data_type.clone(),
-            not_null: column.not_null,
-            default: column.default.clone(),
-        }
-        .into())
+        Ok(AddColumnChange::new(column).into())
     }
 }
@@ -1,13 +1,29 @@
 mod add_column_change;
+mod alter_column_add_identity_change;
+mod alter_column_drop_expression_change;
+mod alter_column_drop_identity_change;
+mod alter_column_set_compression_change;
 mod alter_column_set_data_type_change;
 mod alter_column_set_default_change;
+mod alter_column_set_identity_change;
 mod alter_column_set_null_change;
+mod alter_column_set_statistics_change;
+mod alter_column_set_storage_change;
+mod column_identity;
 mod drop_column_change;
 mod rename_column_change;
 
 pub use add_column_change::*;
+pub use alter_column_add_identity_change::*;
+pub use alter_column_drop_expression_change::*;
+pub use alter_column_drop_identity_change::*;
+pub use alter_column_set_compression_change::*;
 pub use alter_column_set_data_type_change::*;
 pub use alter_column_set_default_change::*;
+pub use alter_column_set_identity_change::*;
 pub use alter_column_set_null_change::*;
+pub use alter_column_set_statistics_change::*;
+pub use alter_column_set_storage_change::*;
+pub use column_identity::*;
 pub use drop_column_change::*;
-pub use rename_column_change::*;
\ No newline at end of file
+pub use rename_column_change::*;
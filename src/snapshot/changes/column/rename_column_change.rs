@@ -22,14 +22,12 @@ impl RenameColumnChange {
     }
 
     pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
-        let schema = source.get_schema_mut(&self.from_schema)?;
-        let table = schema.get_relation_mut(&self.from_table)?.as_table_mut()?;
-        let mut column = table.remove_column(&self.from_column)?;
-
-        column.name = self.to_column.clone();
-        table.add_column(column)?;
-
-        Ok(())
+        source.rename_column(
+            &self.from_schema,
+            &self.from_table,
+            &self.from_column,
+            &self.to_column,
+        )
     }
 
     pub fn render_sql(&self) -> String {
@@ -41,15 +39,15 @@ impl RenameColumnChange {
         )
     }
 
-    pub fn revert(&self, target: &Database) -> Result<Change, SnapshotError> {
-        let schema = target.get_schema(&self.from_schema)?;
+    pub fn revert(&self, source: &Database) -> Result<Change, SnapshotError> {
+        let schema = source.get_schema(&self.from_schema)?;
         let table = schema.get_relation(&self.from_table)?.as_table()?;
-        let column = table.get_column(&self.to_column)?;
+        table.get_column(&self.from_column)?;
 
         Ok(RenameColumnChange {
             from_schema: schema.name.clone(),
             from_table: table.name.clone(),
-            from_column: column.name.clone(),
+            from_column: self.to_column.clone(),
             to_column: self.from_column.clone(),
         }
         .into())
@@ -0,0 +1,271 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    snapshot::{
+        changes::Change, defaults::get_default_description, Column, Database, Function, Index,
+        MaterializedView, Schema, Sequence, SnapshotError, Table, View,
+    },
+    util::sqlfmt::{sql_qa, sql_qn, sql_sl},
+};
+
+/// Object a `COMMENT ON` statement is attached to.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(tag = "object")]
+pub enum CommentTarget {
+    Schema {
+        schema: String,
+    },
+    Table {
+        schema: String,
+        table: String,
+    },
+    Column {
+        schema: String,
+        table: String,
+        column: String,
+    },
+    Constraint {
+        schema: String,
+        table: String,
+        constraint: String,
+    },
+    Index {
+        schema: String,
+        index: String,
+    },
+    View {
+        schema: String,
+        view: String,
+    },
+    MaterializedView {
+        schema: String,
+        view: String,
+    },
+    Sequence {
+        schema: String,
+        sequence: String,
+    },
+    Function {
+        schema: String,
+        function: String,
+    },
+}
+
+/// Sets the comment of an object; an empty comment removes it.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CommentChange {
+    pub target: CommentTarget,
+    pub comment: String,
+}
+
+impl CommentChange {
+    pub fn new(target: CommentTarget, comment: &str) -> Self {
+        Self {
+            target,
+            comment: comment.to_string(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        *self.target.get_comment_mut(source)? = self.comment.clone();
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "COMMENT ON {} IS {};",
+            self.target.render_sql(),
+            if self.comment == get_default_description() {
+                "NULL".to_string()
+            } else {
+                sql_sl(&self.comment)
+            }
+        )
+    }
+
+    pub fn revert(&self, source: &Database) -> Result<Change, SnapshotError> {
+        let comment = self.target.get_comment(source)?;
+        Ok(CommentChange::new(self.target.clone(), comment).into())
+    }
+}
+
+impl CommentTarget {
+    pub fn schema(t: &Schema) -> Self {
+        CommentTarget::Schema {
+            schema: t.name.clone(),
+        }
+    }
+    pub fn table(t: &Table) -> Self {
+        CommentTarget::Table {
+            schema: t.schema_name.clone(),
+            table: t.name.clone(),
+        }
+    }
+    pub fn column(t: &Column) -> Self {
+        CommentTarget::Column {
+            schema: t.schema_name.clone(),
+            table: t.table_name.clone(),
+            column: t.name.clone(),
+        }
+    }
+    pub fn constraint(schema: &str, table: &str, constraint: &str) -> Self {
+        CommentTarget::Constraint {
+            schema: schema.to_string(),
+            table: table.to_string(),
+            constraint: constraint.to_string(),
+        }
+    }
+    pub fn index(t: &Index) -> Self {
+        CommentTarget::Index {
+            schema: t.schema_name.clone(),
+            index: t.name.clone(),
+        }
+    }
+    pub fn view(t: &View) -> Self {
+        CommentTarget::View {
+            schema: t.schema_name.clone(),
+            view: t.name.clone(),
+        }
+    }
+    pub fn materialized_view(t: &MaterializedView) -> Self {
+        CommentTarget::MaterializedView {
+            schema: t.schema_name.clone(),
+            view: t.name.clone(),
+        }
+    }
+    pub fn sequence(t: &Sequence) -> Self {
+        CommentTarget::Sequence {
+            schema: t.schema_name.clone(),
+            sequence: t.name.clone(),
+        }
+    }
+    pub fn function(t: &Function) -> Self {
+        CommentTarget::Function {
+            schema: t.schema_name.clone(),
+            function: t.name.clone(),
+        }
+    }
+
+    fn get_comment<'a>(&self, source: &'a Database) -> Result<&'a str, SnapshotError> {
+        Ok(match self {
+            CommentTarget::Schema { schema } => &source.get_schema(schema)?.comment,
+            CommentTarget::Table { schema, table } => {
+                &source.get_schema(schema)?.get_relation(table)?.as_table()?.comment
+            }
+            CommentTarget::Column {
+                schema,
+                table,
+                column,
+            } => {
+                let table = source.get_schema(schema)?.get_relation(table)?.as_table()?;
+                &table.get_column(column)?.comment
+            }
+            CommentTarget::Constraint {
+                schema,
+                table,
+                constraint,
+            } => {
+                let table = source.get_schema(schema)?.get_relation(table)?.as_table()?;
+                table.get_constraint(constraint)?.get_comment()
+            }
+            CommentTarget::Index { schema, index } => {
+                &source.get_schema(schema)?.get_relation(index)?.as_index()?.comment
+            }
+            CommentTarget::View { schema, view } => {
+                &source.get_schema(schema)?.get_relation(view)?.as_view()?.comment
+            }
+            CommentTarget::MaterializedView { schema, view } => {
+                let relation = source.get_schema(schema)?.get_relation(view)?;
+                &relation.as_materialized_view()?.comment
+            }
+            CommentTarget::Sequence { schema, sequence } => {
+                let relation = source.get_schema(schema)?.get_relation(sequence)?;
+                &relation.as_sequence()?.comment
+            }
+            CommentTarget::Function { schema, function } => {
+                &source.get_schema(schema)?.get_function(function)?.comment
+            }
+        })
+    }
+
+    fn get_comment_mut<'a>(
+        &self,
+        source: &'a mut Database,
+    ) -> Result<&'a mut String, SnapshotError> {
+        Ok(match self {
+            CommentTarget::Schema { schema } => &mut source.get_schema_mut(schema)?.comment,
+            CommentTarget::Table { schema, table } => {
+                let relation = source.get_schema_mut(schema)?.get_relation_mut(table)?;
+                &mut relation.as_table_mut()?.comment
+            }
+            CommentTarget::Column {
+                schema,
+                table,
+                column,
+            } => {
+                let relation = source.get_schema_mut(schema)?.get_relation_mut(table)?;
+                &mut relation.as_table_mut()?.get_column_mut(column)?.comment
+            }
+            CommentTarget::Constraint {
+                schema,
+                table,
+                constraint,
+            } => {
+                let relation = source.get_schema_mut(schema)?.get_relation_mut(table)?;
+                let table = relation.as_table_mut()?;
+                table.get_constraint_mut(constraint)?.get_comment_mut()
+            }
+            CommentTarget::Index { schema, index } => {
+                let relation = source.get_schema_mut(schema)?.get_relation_mut(index)?;
+                &mut relation.as_index_mut()?.comment
+            }
+            CommentTarget::View { schema, view } => {
+                let relation = source.get_schema_mut(schema)?.get_relation_mut(view)?;
+                &mut relation.as_view_mut()?.comment
+            }
+            CommentTarget::MaterializedView { schema, view } => {
+                let relation = source.get_schema_mut(schema)?.get_relation_mut(view)?;
+                &mut relation.as_materialized_view_mut()?.comment
+            }
+            CommentTarget::Sequence { schema, sequence } => {
+                let relation = source.get_schema_mut(schema)?.get_relation_mut(sequence)?;
+                &mut relation.as_sequence_mut()?.comment
+            }
+            CommentTarget::Function { schema, function } => {
+                &mut source.get_schema_mut(schema)?.get_function_mut(function)?.comment
+            }
+        })
+    }
+
+    fn render_sql(&self) -> String {
+        match self {
+            CommentTarget::Schema { schema } => format!("SCHEMA {}", sql_qn(schema)),
+            CommentTarget::Table { schema, table } => format!("TABLE {}", sql_qa(schema, table)),
+            CommentTarget::Column {
+                schema,
+                table,
+                column,
+            } => format!("COLUMN {}.{}", sql_qa(schema, table), sql_qn(column)),
+            CommentTarget::Constraint {
+                schema,
+                table,
+                constraint,
+            } => format!(
+                "CONSTRAINT {} ON {}",
+                sql_qn(constraint),
+                sql_qa(schema, table)
+            ),
+            CommentTarget::Index { schema, index } => format!("INDEX {}", sql_qa(schema, index)),
+            CommentTarget::View { schema, view } => format!("VIEW {}", sql_qa(schema, view)),
+            CommentTarget::MaterializedView { schema, view } => {
+                format!("MATERIALIZED VIEW {}", sql_qa(schema, view))
+            }
+            CommentTarget::Sequence { schema, sequence } => {
+                format!("SEQUENCE {}", sql_qa(schema, sequence))
+            }
+            CommentTarget::Function { schema, function } => {
+                format!("FUNCTION {}", sql_qa(schema, function))
+            }
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod comment_change;
+
+pub use comment_change::*;
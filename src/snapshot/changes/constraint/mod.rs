@@ -0,0 +1,3 @@
+mod rename_constraint_change;
+
+pub use rename_constraint_change::*;
@@ -0,0 +1,56 @@
+use crate::snapshot::{changes::Change, Constraint, Database, SnapshotError};
+use crate::util::sqlfmt::{sql_qa, sql_qn};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct RenameConstraintChange {
+    pub schema: String,
+    pub table: String,
+    pub from_constraint: String,
+    pub to_constraint: String,
+}
+
+impl RenameConstraintChange {
+    pub fn new(s: &Constraint, t: &str) -> Self {
+        Self {
+            schema: s.get_schema_name().to_string(),
+            table: s.get_table_name().to_string(),
+            from_constraint: s.get_name().to_string(),
+            to_constraint: t.to_string(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        source.rename_constraint(
+            &self.schema,
+            &self.table,
+            &self.from_constraint,
+            &self.to_constraint,
+        )
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER TABLE {} RENAME CONSTRAINT {} TO {};",
+            sql_qa(&self.schema, &self.table),
+            sql_qn(&self.from_constraint),
+            sql_qn(&self.to_constraint),
+        )
+    }
+
+    pub fn revert(&self, source: &Database) -> Result<Change, SnapshotError> {
+        source
+            .get_schema(&self.schema)?
+            .get_relation(&self.table)?
+            .as_table()?
+            .get_constraint(&self.from_constraint)?;
+
+        Ok(RenameConstraintChange {
+            schema: self.schema.clone(),
+            table: self.table.clone(),
+            from_constraint: self.to_constraint.clone(),
+            to_constraint: self.from_constraint.clone(),
+        }
+        .into())
+    }
+}
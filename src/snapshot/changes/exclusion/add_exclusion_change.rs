@@ -0,0 +1,115 @@
+use super::DropExclusionChange;
+use crate::{
+    snapshot::{
+        changes::Change, defaults::get_default_description, Database, Exclusion, ExclusionElement,
+        SnapshotError,
+    },
+    util::sqlfmt::{sql_l, sql_qa, sql_qn},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AddExclusionChange {
+    pub schema: String,
+    pub table: String,
+    pub constraint: String,
+    pub method: String,
+    pub elements: Vec<AddExclusionChangeElement>,
+    pub predicate: Option<String>,
+    pub deferrable: bool,
+    pub initially_deferred: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AddExclusionChangeElement {
+    pub expression: String,
+    pub operator: String,
+}
+
+impl AddExclusionChange {
+    pub fn new(t: &Exclusion) -> Self {
+        Self {
+            schema: t.schema_name.clone(),
+            table: t.table_name.clone(),
+            constraint: t.name.clone(),
+            method: t.method.clone(),
+            elements: t
+                .elements
+                .iter()
+                .map(|e| AddExclusionChangeElement {
+                    expression: e.expression.clone(),
+                    operator: e.operator.clone(),
+                })
+                .collect(),
+            predicate: t.predicate.clone(),
+            deferrable: t.deferrable,
+            initially_deferred: t.initially_deferred,
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let table = schema.get_relation_mut(&self.table)?.as_table_mut()?;
+
+        let exclusion = Exclusion {
+            schema_name: self.schema.clone(),
+            table_name: self.table.clone(),
+            name: self.constraint.clone(),
+            method: self.method.clone(),
+            elements: self
+                .elements
+                .iter()
+                .map(|e| ExclusionElement {
+                    expression: e.expression.clone(),
+                    operator: e.operator.clone(),
+                })
+                .collect(),
+            predicate: self.predicate.clone(),
+            deferrable: self.deferrable,
+            initially_deferred: self.initially_deferred,
+            comment: get_default_description(),
+        };
+        table.add_constraint(exclusion.into())?;
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER TABLE {} ADD CONSTRAINT {} EXCLUDE USING {} ({}){} {} {}",
+            sql_qa(&self.schema, &self.table),
+            sql_qn(&self.constraint),
+            self.method,
+            sql_l(
+                self.elements
+                    .iter()
+                    .map(|e| format!("{} WITH {}", e.expression, e.operator))
+            ),
+            match &self.predicate {
+                Some(predicate) => format!(" WHERE ({})", predicate),
+                None => "".to_string(),
+            },
+            if self.deferrable {
+                "DEFERRABLE".to_string()
+            } else {
+                "NOT DEFERRABLE".to_string()
+            },
+            if self.initially_deferred {
+                "INITIALLY DEFERRED".to_string()
+            } else {
+                "INITIALLY IMMEDIATE".to_string()
+            },
+        ) + ";"
+    }
+
+    pub fn revert(&self, target: &Database) -> Result<Change, SnapshotError> {
+        let schema = target.get_schema(&self.schema)?;
+        let table = schema.get_relation(&self.table)?.as_table()?;
+
+        Ok(DropExclusionChange {
+            schema: schema.name.clone(),
+            table: table.name.clone(),
+            constraint: self.constraint.clone(),
+        }
+        .into())
+    }
+}
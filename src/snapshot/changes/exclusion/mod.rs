@@ -0,0 +1,7 @@
+mod add_exclusion_change;
+mod alter_exclusion_change;
+mod drop_exclusion_change;
+
+pub use add_exclusion_change::*;
+pub use alter_exclusion_change::*;
+pub use drop_exclusion_change::*;
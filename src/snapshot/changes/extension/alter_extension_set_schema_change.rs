@@ -0,0 +1,39 @@
+use crate::{
+    snapshot::{changes::Change, Database, Extension, SnapshotError},
+    util::sqlfmt::sql_qn,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AlterExtensionSetSchemaChange {
+    pub extension: String,
+    pub schema: String,
+}
+
+impl AlterExtensionSetSchemaChange {
+    pub fn new(t: &Extension) -> Self {
+        Self {
+            extension: t.name.clone(),
+            schema: t.schema_name.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let extension = source.get_extension_mut(&self.extension)?;
+        extension.schema_name = self.schema.clone();
+        return Ok(());
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER EXTENSION {} SET SCHEMA {};",
+            sql_qn(&self.extension),
+            sql_qn(&self.schema)
+        )
+    }
+
+    pub fn revert(&self, source: &Database) -> Result<Change, SnapshotError> {
+        let extension = source.get_extension(&self.extension)?;
+        Ok(AlterExtensionSetSchemaChange::new(extension).into())
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{
+    snapshot::{changes::Change, Database, Extension, SnapshotError},
+    util::sqlfmt::{sql_qn, sql_sl},
+};
+use serde::{Deserialize, Serialize};
+
+/// `ALTER EXTENSION ... UPDATE`, to the default version when `version` is
+/// `None`.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AlterExtensionUpdateChange {
+    pub extension: String,
+    pub version: Option<String>,
+}
+
+impl AlterExtensionUpdateChange {
+    pub fn new(t: &Extension) -> Self {
+        Self {
+            extension: t.name.clone(),
+            version: t.version.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let extension = source.get_extension_mut(&self.extension)?;
+        extension.version = self.version.clone();
+        return Ok(());
+    }
+
+    pub fn render_sql(&self) -> String {
+        match &self.version {
+            Some(version) => format!(
+                "ALTER EXTENSION {} UPDATE TO {};",
+                sql_qn(&self.extension),
+                sql_sl(version)
+            ),
+            None => format!("ALTER EXTENSION {} UPDATE;", sql_qn(&self.extension)),
+        }
+    }
+
+    pub fn revert(&self, source: &Database) -> Result<Change, SnapshotError> {
+        let extension = source.get_extension(&self.extension)?;
+        Ok(AlterExtensionUpdateChange::new(extension).into())
+    }
+}
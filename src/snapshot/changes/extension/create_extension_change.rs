@@ -0,0 +1,52 @@
+use super::DropExtensionChange;
+use crate::{
+    snapshot::{changes::Change, Database, Extension, SnapshotError},
+    util::sqlfmt::{sql_qn, sql_sl},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CreateExtensionChange {
+    pub extension: String,
+    pub schema: String,
+    pub version: Option<String>,
+}
+
+impl CreateExtensionChange {
+    pub fn new(t: &Extension) -> Self {
+        Self {
+            extension: t.name.clone(),
+            schema: t.schema_name.clone(),
+            version: t.version.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        source.add_extension(Extension {
+            name: self.extension.clone(),
+            schema_name: self.schema.clone(),
+            version: self.version.clone(),
+        })?;
+        return Ok(());
+    }
+
+    pub fn render_sql(&self) -> String {
+        let mut sql = format!(
+            "CREATE EXTENSION {} WITH SCHEMA {}",
+            sql_qn(&self.extension),
+            sql_qn(&self.schema)
+        );
+        if let Some(version) = &self.version {
+            sql.push_str(&format!(" VERSION {}", sql_sl(version)));
+        }
+        sql.push(';');
+        sql
+    }
+
+    pub fn revert(&self, _source: &Database) -> Result<Change, SnapshotError> {
+        Ok(DropExtensionChange {
+            extension: self.extension.clone(),
+        }
+        .into())
+    }
+}
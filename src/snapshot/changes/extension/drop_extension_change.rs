@@ -0,0 +1,33 @@
+use super::CreateExtensionChange;
+use crate::{
+    snapshot::{changes::Change, Database, Extension, SnapshotError},
+    util::sqlfmt::sql_qn,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct DropExtensionChange {
+    pub extension: String,
+}
+
+impl DropExtensionChange {
+    pub fn new(s: &Extension) -> Self {
+        Self {
+            extension: s.name.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        source.remove_extension(&self.extension)?;
+        return Ok(());
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!("DROP EXTENSION {};", sql_qn(&self.extension))
+    }
+
+    pub fn revert(&self, source: &Database) -> Result<Change, SnapshotError> {
+        let extension = source.get_extension(&self.extension)?;
+        Ok(CreateExtensionChange::new(extension).into())
+    }
+}
@@ -0,0 +1,9 @@
+mod alter_extension_set_schema_change;
+mod alter_extension_update_change;
+mod create_extension_change;
+mod drop_extension_change;
+
+pub use alter_extension_set_schema_change::*;
+pub use alter_extension_update_change::*;
+pub use create_extension_change::*;
+pub use drop_extension_change::*;
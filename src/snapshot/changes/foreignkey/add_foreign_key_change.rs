@@ -1,6 +1,6 @@
 use super::super::Change;
 use super::DropForeignKeyChange;
-use crate::snapshot::{Database, ForeignKey, SnapshotError};
+use crate::snapshot::{defaults::get_default_description, Database, ForeignKey, SnapshotError};
 use crate::util::sqlfmt::{sql_qa, sql_ql, sql_qn};
 use serde::{Deserialize, Serialize};
 
@@ -55,6 +55,7 @@ impl AddForeignKeyChange {
             delete_rule: self.delete_rule.clone(),
             deferrable: self.deferrable,
             initially_deferred: self.initially_deferred,
+            comment: get_default_description(),
         };
 
         table.add_constraint(foreign_key.into())?;
@@ -1,5 +1,5 @@
 use super::{super::Change, DropFunctionChange};
-use crate::snapshot::{Database, Function, SnapshotError};
+use crate::snapshot::{defaults::get_default_description, Database, Function, SnapshotError};
 use crate::util::sqlfmt::sql_qa;
 use serde::{Deserialize, Serialize};
 
@@ -35,6 +35,10 @@ impl CreateFunctionChange {
             language: self.language.clone(),
             returns: self.returns.clone(),
             volatility: self.volatility.clone(),
+            owner: None,
+            comment: get_default_description(),
+            grants: vec![],
+            revokes: vec![],
         })?;
         Ok(())
     }
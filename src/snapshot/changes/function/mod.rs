@@ -1,5 +1,7 @@
 mod create_functon_change;
 mod drop_function_change;
+mod replace_function_change;
 
 pub use create_functon_change::*;
 pub use drop_function_change::*;
+pub use replace_function_change::*;
@@ -0,0 +1,55 @@
+use super::super::Change;
+use crate::snapshot::{Database, Function, SnapshotError};
+use crate::util::sqlfmt::sql_qa;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ReplaceFunctionChange {
+    pub schema: String,
+    pub function: String,
+    pub body: String,
+    pub language: String,
+    pub returns: String,
+    pub volatility: String,
+}
+
+impl ReplaceFunctionChange {
+    pub fn new(t: &Function) -> Self {
+        Self {
+            schema: t.schema_name.clone(),
+            function: t.name.clone(),
+            body: t.body.clone(),
+            language: t.language.clone(),
+            returns: t.returns.clone(),
+            volatility: t.volatility.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let function = schema.get_function_mut(&self.function)?;
+
+        function.body = self.body.clone();
+        function.language = self.language.clone();
+        function.volatility = self.volatility.clone();
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "CREATE OR REPLACE FUNCTION {} {} RETURNS {} LANGUAGE {} {}",
+            sql_qa(&self.schema, &self.function),
+            self.body,
+            self.returns,
+            self.language,
+            self.volatility,
+        ) + ";"
+    }
+
+    pub fn revert(&self, target: &Database) -> Result<Change, SnapshotError> {
+        let schema = target.get_schema(&self.schema)?;
+        let function = schema.get_function(&self.function)?;
+
+        Ok(ReplaceFunctionChange::new(function).into())
+    }
+}
@@ -0,0 +1,222 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    snapshot::{
+        changes::{Change, RevokeChange},
+        insert_grant, remove_grant, Column, Database, Function, Grant, Schema, Sequence,
+        SnapshotError, Table,
+    },
+    util::sqlfmt::{sql_l, sql_qa, sql_qn, sql_role},
+};
+
+/// Object a `GRANT` or `REVOKE` statement applies to.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(tag = "object")]
+pub enum GrantTarget {
+    Schema {
+        schema: String,
+    },
+    Table {
+        schema: String,
+        table: String,
+    },
+    Column {
+        schema: String,
+        table: String,
+        column: String,
+    },
+    Sequence {
+        schema: String,
+        sequence: String,
+    },
+    Function {
+        schema: String,
+        function: String,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct GrantChange {
+    pub target: GrantTarget,
+    pub grantee: String,
+    pub privileges: Vec<String>,
+}
+
+impl GrantChange {
+    pub fn new(target: GrantTarget, grantee: &str, privileges: Vec<String>) -> Self {
+        Self {
+            target,
+            grantee: grantee.to_string(),
+            privileges,
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let path = self.target.path();
+        for privilege in &self.privileges {
+            let grant = Grant::new(&self.grantee, privilege);
+            // a privilege the object started out with is given back
+            if remove_grant(self.target.get_revokes_mut(source)?, &grant) {
+                continue;
+            }
+            if !insert_grant(self.target.get_grants_mut(source)?, grant) {
+                return Err(SnapshotError::privilege_already_granted(
+                    &path,
+                    &self.grantee,
+                    privilege,
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "GRANT {} ON {} TO {};",
+            self.target.render_privileges_sql(&self.privileges),
+            self.target.render_sql(),
+            sql_role(&self.grantee)
+        )
+    }
+
+    pub fn revert(&self, _target: &Database) -> Result<Change, SnapshotError> {
+        Ok(RevokeChange::new(self.target.clone(), &self.grantee, self.privileges.clone()).into())
+    }
+}
+
+impl GrantTarget {
+    pub fn schema(t: &Schema) -> Self {
+        GrantTarget::Schema {
+            schema: t.name.clone(),
+        }
+    }
+    pub fn table(t: &Table) -> Self {
+        GrantTarget::Table {
+            schema: t.schema_name.clone(),
+            table: t.name.clone(),
+        }
+    }
+    pub fn column(t: &Column) -> Self {
+        GrantTarget::Column {
+            schema: t.schema_name.clone(),
+            table: t.table_name.clone(),
+            column: t.name.clone(),
+        }
+    }
+    pub fn sequence(t: &Sequence) -> Self {
+        GrantTarget::Sequence {
+            schema: t.schema_name.clone(),
+            sequence: t.name.clone(),
+        }
+    }
+    pub fn function(t: &Function) -> Self {
+        GrantTarget::Function {
+            schema: t.schema_name.clone(),
+            function: t.name.clone(),
+        }
+    }
+
+    pub(super) fn path(&self) -> Vec<String> {
+        match self {
+            GrantTarget::Schema { schema } => vec![schema.clone()],
+            GrantTarget::Table { schema, table } => vec![schema.clone(), table.clone()],
+            GrantTarget::Column {
+                schema,
+                table,
+                column,
+            } => vec![schema.clone(), table.clone(), column.clone()],
+            GrantTarget::Sequence { schema, sequence } => vec![schema.clone(), sequence.clone()],
+            GrantTarget::Function { schema, function } => vec![schema.clone(), function.clone()],
+        }
+    }
+
+    pub(super) fn get_grants_mut<'a>(
+        &self,
+        source: &'a mut Database,
+    ) -> Result<&'a mut Vec<Grant>, SnapshotError> {
+        Ok(match self {
+            GrantTarget::Schema { schema } => &mut source.get_schema_mut(schema)?.grants,
+            GrantTarget::Table { schema, table } => {
+                let relation = source.get_schema_mut(schema)?.get_relation_mut(table)?;
+                &mut relation.as_table_mut()?.grants
+            }
+            GrantTarget::Column {
+                schema,
+                table,
+                column,
+            } => {
+                let relation = source.get_schema_mut(schema)?.get_relation_mut(table)?;
+                &mut relation.as_table_mut()?.get_column_mut(column)?.grants
+            }
+            GrantTarget::Sequence { schema, sequence } => {
+                let relation = source.get_schema_mut(schema)?.get_relation_mut(sequence)?;
+                &mut relation.as_sequence_mut()?.grants
+            }
+            GrantTarget::Function { schema, function } => {
+                &mut source
+                    .get_schema_mut(schema)?
+                    .get_function_mut(function)?
+                    .grants
+            }
+        })
+    }
+
+    pub(super) fn get_revokes_mut<'a>(
+        &self,
+        source: &'a mut Database,
+    ) -> Result<&'a mut Vec<Grant>, SnapshotError> {
+        Ok(match self {
+            GrantTarget::Schema { schema } => &mut source.get_schema_mut(schema)?.revokes,
+            GrantTarget::Table { schema, table } => {
+                let relation = source.get_schema_mut(schema)?.get_relation_mut(table)?;
+                &mut relation.as_table_mut()?.revokes
+            }
+            GrantTarget::Column {
+                schema,
+                table,
+                column,
+            } => {
+                let relation = source.get_schema_mut(schema)?.get_relation_mut(table)?;
+                &mut relation.as_table_mut()?.get_column_mut(column)?.revokes
+            }
+            GrantTarget::Sequence { schema, sequence } => {
+                let relation = source.get_schema_mut(schema)?.get_relation_mut(sequence)?;
+                &mut relation.as_sequence_mut()?.revokes
+            }
+            GrantTarget::Function { schema, function } => {
+                &mut source
+                    .get_schema_mut(schema)?
+                    .get_function_mut(function)?
+                    .revokes
+            }
+        })
+    }
+
+    // Column privileges are spelled `SELECT ("col")` and granted on the table.
+    pub(super) fn render_privileges_sql(&self, privileges: &[String]) -> String {
+        let suffix = match self {
+            GrantTarget::Column { column, .. } => format!(" ({})", sql_qn(column)),
+            _ => String::new(),
+        };
+        sql_l(
+            privileges
+                .iter()
+                .map(|p| format!("{}{}", p.to_uppercase(), suffix)),
+        )
+    }
+
+    pub(super) fn render_sql(&self) -> String {
+        match self {
+            GrantTarget::Schema { schema } => format!("SCHEMA {}", sql_qn(schema)),
+            GrantTarget::Table { schema, table } | GrantTarget::Column { schema, table, .. } => {
+                format!("TABLE {}", sql_qa(schema, table))
+            }
+            GrantTarget::Sequence { schema, sequence } => {
+                format!("SEQUENCE {}", sql_qa(schema, sequence))
+            }
+            GrantTarget::Function { schema, function } => {
+                format!("FUNCTION {}", sql_qa(schema, function))
+            }
+        }
+    }
+}
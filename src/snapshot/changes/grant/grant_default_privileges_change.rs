@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    snapshot::{
+        changes::{Change, RevokeDefaultPrivilegesChange},
+        insert_grant, Database, DefaultGrant, SnapshotError,
+    },
+    util::sqlfmt::{sql_l, sql_qn, sql_role},
+};
+
+/// `ALTER DEFAULT PRIVILEGES IN SCHEMA ... GRANT`, covering objects the
+/// current role creates in the schema from then on.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct GrantDefaultPrivilegesChange {
+    pub schema: String,
+    pub object_type: String,
+    pub grantee: String,
+    pub privileges: Vec<String>,
+}
+
+impl GrantDefaultPrivilegesChange {
+    pub fn new(schema: &str, object_type: &str, grantee: &str, privileges: Vec<String>) -> Self {
+        Self {
+            schema: schema.to_string(),
+            object_type: object_type.to_string(),
+            grantee: grantee.to_string(),
+            privileges,
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        for privilege in &self.privileges {
+            let grant = DefaultGrant::new(&self.object_type, &self.grantee, privilege);
+            if !insert_grant(&mut schema.default_grants, grant) {
+                return Err(SnapshotError::privilege_already_granted(
+                    &[self.schema.clone(), self.object_type.clone()],
+                    &self.grantee,
+                    privilege,
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER DEFAULT PRIVILEGES IN SCHEMA {} GRANT {} ON {} TO {};",
+            sql_qn(&self.schema),
+            sql_l(self.privileges.iter().map(|p| p.to_uppercase())),
+            self.object_type.to_uppercase(),
+            sql_role(&self.grantee)
+        )
+    }
+
+    pub fn revert(&self, _target: &Database) -> Result<Change, SnapshotError> {
+        Ok(RevokeDefaultPrivilegesChange::new(
+            &self.schema,
+            &self.object_type,
+            &self.grantee,
+            self.privileges.clone(),
+        )
+        .into())
+    }
+}
@@ -0,0 +1,9 @@
+mod grant_change;
+mod grant_default_privileges_change;
+mod revoke_change;
+mod revoke_default_privileges_change;
+
+pub use grant_change::*;
+pub use grant_default_privileges_change::*;
+pub use revoke_change::*;
+pub use revoke_default_privileges_change::*;
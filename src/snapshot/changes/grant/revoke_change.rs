@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    snapshot::{
+        changes::{Change, GrantChange, GrantTarget},
+        insert_grant, remove_grant, Database, Grant, SnapshotError,
+    },
+    util::sqlfmt::sql_role,
+};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct RevokeChange {
+    pub target: GrantTarget,
+    pub grantee: String,
+    pub privileges: Vec<String>,
+}
+
+impl RevokeChange {
+    pub fn new(target: GrantTarget, grantee: &str, privileges: Vec<String>) -> Self {
+        Self {
+            target,
+            grantee: grantee.to_string(),
+            privileges,
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let path = self.target.path();
+        for privilege in &self.privileges {
+            let grant = Grant::new(&self.grantee, privilege);
+            if remove_grant(self.target.get_grants_mut(source)?, &grant) {
+                continue;
+            }
+            // otherwise a privilege the object started out with is taken away
+            if !insert_grant(self.target.get_revokes_mut(source)?, grant) {
+                return Err(SnapshotError::privilege_not_granted(
+                    &path,
+                    &self.grantee,
+                    privilege,
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "REVOKE {} ON {} FROM {};",
+            self.target.render_privileges_sql(&self.privileges),
+            self.target.render_sql(),
+            sql_role(&self.grantee)
+        )
+    }
+
+    pub fn revert(&self, _target: &Database) -> Result<Change, SnapshotError> {
+        Ok(GrantChange::new(self.target.clone(), &self.grantee, self.privileges.clone()).into())
+    }
+}
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    snapshot::{
+        changes::{Change, GrantDefaultPrivilegesChange},
+        remove_grant, Database, DefaultGrant, SnapshotError,
+    },
+    util::sqlfmt::{sql_l, sql_qn, sql_role},
+};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct RevokeDefaultPrivilegesChange {
+    pub schema: String,
+    pub object_type: String,
+    pub grantee: String,
+    pub privileges: Vec<String>,
+}
+
+impl RevokeDefaultPrivilegesChange {
+    pub fn new(schema: &str, object_type: &str, grantee: &str, privileges: Vec<String>) -> Self {
+        Self {
+            schema: schema.to_string(),
+            object_type: object_type.to_string(),
+            grantee: grantee.to_string(),
+            privileges,
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        for privilege in &self.privileges {
+            let grant = DefaultGrant::new(&self.object_type, &self.grantee, privilege);
+            if !remove_grant(&mut schema.default_grants, &grant) {
+                return Err(SnapshotError::privilege_not_granted(
+                    &[self.schema.clone(), self.object_type.clone()],
+                    &self.grantee,
+                    privilege,
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER DEFAULT PRIVILEGES IN SCHEMA {} REVOKE {} ON {} FROM {};",
+            sql_qn(&self.schema),
+            sql_l(self.privileges.iter().map(|p| p.to_uppercase())),
+            self.object_type.to_uppercase(),
+            sql_role(&self.grantee)
+        )
+    }
+
+    pub fn revert(&self, _target: &Database) -> Result<Change, SnapshotError> {
+        Ok(GrantDefaultPrivilegesChange::new(
+            &self.schema,
+            &self.object_type,
+            &self.grantee,
+            self.privileges.clone(),
+        )
+        .into())
+    }
+}
@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+
+use super::Change;
+
+/// What a change may cost beyond altering the schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Hazard {
+    /// The revert does not bring back what the change took away, like the rows
+    /// of a dropped table.
+    Irreversible,
+    /// The change throws data away.
+    DataLosing,
+    /// The change rewrites or scans a table while locking it against writes.
+    LockHeavy,
+}
+
+impl std::fmt::Display for Hazard {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Hazard::Irreversible => write!(f, "irreversible"),
+            Hazard::DataLosing => write!(f, "data-losing"),
+            Hazard::LockHeavy => write!(f, "lock-heavy"),
+        }
+    }
+}
+
+impl Change {
+    /// The hazards of the change. Every change is listed, so a new one has to
+    /// declare its own.
+    pub fn hazards(&self) -> Vec<Hazard> {
+        match self {
+            // the revert brings back an empty structure
+            Change::DropColumnChange(_) | Change::DropTableChange(_) => {
+                vec![Hazard::Irreversible, Hazard::DataLosing]
+            }
+            // the revert starts counting over
+            Change::DropSequenceChange(_) => vec![Hazard::Irreversible, Hazard::DataLosing],
+            Change::AlterColumnDropIdentityChange(_) => vec![Hazard::Irreversible],
+            // a generated column cannot be restored in place
+            Change::AlterColumnDropExpressionChange(_) => vec![Hazard::Irreversible],
+            // a schema takes whatever is left in it along, and a materialized
+            // view its rows until the next refresh
+            Change::DropSchemaChange(_) | Change::DropMaterializedViewChange(_) => {
+                vec![Hazard::DataLosing]
+            }
+            // values may not survive the cast
+            Change::AlterColumnSetDataTypeChange(_) => vec![Hazard::DataLosing, Hazard::LockHeavy],
+
+            Change::AddColumnChange(c) if c.generated.is_some() || c.identity.is_some() => {
+                vec![Hazard::LockHeavy]
+            }
+            Change::AlterColumnSetNotNullChange(c) if c.not_null => vec![Hazard::LockHeavy],
+            Change::AddCheckChange(_)
+            | Change::AddExclusionChange(_)
+            | Change::AddForeignKeyChange(_)
+            | Change::AddPrimaryKeyChange(_)
+            | Change::AddUniqueChange(_)
+            | Change::AlterIndexSetTablespaceChange(_)
+            | Change::AlterTableSetLoggedChange(_)
+            | Change::AlterTableSetTablespaceChange(_)
+            | Change::AttachPartitionChange(_)
+            | Change::CreateIndexChange(_)
+            | Change::RefreshMaterializedViewChange(_) => vec![Hazard::LockHeavy],
+
+            Change::AddColumnChange(_)
+            | Change::AlterCheckChange(_)
+            | Change::AlterColumnAddIdentityChange(_)
+            | Change::AlterColumnSetCompressionChange(_)
+            | Change::AlterColumnSetDefaultChange(_)
+            | Change::AlterColumnSetIdentityChange(_)
+            | Change::AlterColumnSetNotNullChange(_)
+            | Change::AlterColumnSetStatisticsChange(_)
+            | Change::AlterColumnSetStorageChange(_)
+            | Change::AlterExclusionChange(_)
+            | Change::AlterExtensionSetSchemaChange(_)
+            | Change::AlterExtensionUpdateChange(_)
+            | Change::AlterForeignKeyChange(_)
+            | Change::AlterIndexSetStorageParametersChange(_)
+            | Change::AlterPolicyChange(_)
+            | Change::AlterPrimaryKeyChange(_)
+            | Change::AlterPublicationAddTableChange(_)
+            | Change::AlterPublicationDropTableChange(_)
+            | Change::AlterSequenceChange(_)
+            | Change::AlterStatisticsChange(_)
+            | Change::AlterTableInheritChange(_)
+            | Change::AlterTableNoInheritChange(_)
+            | Change::AlterTableReplicaIdentityChange(_)
+            | Change::AlterTableRowLevelSecurityChange(_)
+            | Change::AlterTableSetStorageParametersChange(_)
+            | Change::AlterUniqueChange(_)
+            | Change::CommentChange(_)
+            | Change::CreateExtensionChange(_)
+            | Change::CreateFunctionChange(_)
+            | Change::CreateMaterializedViewChange(_)
+            | Change::CreatePolicyChange(_)
+            | Change::CreateProcedureChange(_)
+            | Change::CreatePublicationChange(_)
+            | Change::CreateSchemaChange(_)
+            | Change::CreateSequenceChange(_)
+            | Change::CreateStatisticsChange(_)
+            | Change::CreateTableChange(_)
+            | Change::CreateViewChange(_)
+            | Change::DetachPartitionChange(_)
+            | Change::DropCheckChange(_)
+            | Change::DropExclusionChange(_)
+            | Change::DropExtensionChange(_)
+            | Change::DropForeignKeyChange(_)
+            | Change::DropFunctionChange(_)
+            | Change::DropIndexChange(_)
+            | Change::DropPolicyChange(_)
+            | Change::DropPrimaryKeyChange(_)
+            | Change::DropProcedureChange(_)
+            | Change::DropPublicationChange(_)
+            | Change::DropStatisticsChange(_)
+            | Change::DropUniqueChange(_)
+            | Change::DropViewChange(_)
+            | Change::GrantChange(_)
+            | Change::GrantDefaultPrivilegesChange(_)
+            | Change::OwnerChange(_)
+            | Change::RenameColumnChange(_)
+            | Change::RenameConstraintChange(_)
+            | Change::RenameIndexChange(_)
+            | Change::RenameMaterializedViewChange(_)
+            | Change::RenameSchemaChange(_)
+            | Change::RenameSequenceChange(_)
+            | Change::RenameTableChange(_)
+            | Change::RenameViewChange(_)
+            | Change::ReplaceFunctionChange(_)
+            | Change::ReplaceProcedureChange(_)
+            | Change::ReplaceViewChange(_)
+            | Change::RevokeChange(_)
+            | Change::RevokeDefaultPrivilegesChange(_) => vec![],
+        }
+    }
+}
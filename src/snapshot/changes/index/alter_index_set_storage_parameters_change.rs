@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    snapshot::{
+        changes::{find_parameter, parameter_name, Change},
+        Database, Index, SnapshotError,
+    },
+    util::sqlfmt::{sql_l, sql_qa},
+};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AlterIndexSetStorageParametersChange {
+    pub schema: String,
+    pub index: String,
+    /// Parameters to set, each as `name=value`.
+    pub set: Vec<String>,
+    /// Names of the parameters to reset.
+    pub reset: Vec<String>,
+}
+
+impl AlterIndexSetStorageParametersChange {
+    pub fn new(s: &Index, t: &Index) -> Self {
+        Self {
+            schema: t.schema_name.clone(),
+            index: t.name.clone(),
+            set: t
+                .storage_parameters
+                .iter()
+                .filter(|p| !s.storage_parameters.contains(p))
+                .cloned()
+                .collect(),
+            reset: s
+                .storage_parameters
+                .iter()
+                .map(|p| parameter_name(p))
+                .filter(|name| find_parameter(&t.storage_parameters, name).is_none())
+                .map(|name| name.to_string())
+                .collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.set.is_empty() && self.reset.is_empty()
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let index = schema.get_relation_mut(&self.index)?.as_index_mut()?;
+
+        index
+            .storage_parameters
+            .retain(|p| !self.reset.iter().any(|name| name == parameter_name(p)));
+        for parameter in &self.set {
+            let name = parameter_name(parameter);
+            match index
+                .storage_parameters
+                .iter_mut()
+                .find(|p| parameter_name(p) == name)
+            {
+                Some(p) => *p = parameter.clone(),
+                None => index.storage_parameters.push(parameter.clone()),
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        let mut actions = vec![];
+        if !self.set.is_empty() {
+            actions.push(format!("SET ({})", sql_l(&self.set)));
+        }
+        if !self.reset.is_empty() {
+            actions.push(format!("RESET ({})", sql_l(&self.reset)));
+        }
+        format!(
+            "ALTER INDEX {} {};",
+            sql_qa(&self.schema, &self.index),
+            actions.join(", "),
+        )
+    }
+
+    pub fn revert(&self, target: &Database) -> Result<Change, SnapshotError> {
+        let schema = target.get_schema(&self.schema)?;
+        let index = schema.get_relation(&self.index)?.as_index()?;
+
+        let mut set = vec![];
+        let mut reset = vec![];
+        let names = self
+            .set
+            .iter()
+            .map(|p| parameter_name(p))
+            .chain(self.reset.iter().map(|name| name.as_str()));
+        for name in names {
+            match find_parameter(&index.storage_parameters, name) {
+                Some(p) => set.push(p.clone()),
+                None => reset.push(name.to_string()),
+            }
+        }
+
+        Ok(AlterIndexSetStorageParametersChange {
+            schema: self.schema.clone(),
+            index: self.index.clone(),
+            set,
+            reset,
+        }
+        .into())
+    }
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    snapshot::{changes::Change, Database, Index, SnapshotError},
+    util::sqlfmt::{sql_qa, sql_qn},
+};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AlterIndexSetTablespaceChange {
+    pub schema: String,
+    pub index: String,
+    pub tablespace: Option<String>,
+}
+
+impl AlterIndexSetTablespaceChange {
+    pub fn new(t: &Index) -> Self {
+        Self {
+            schema: t.schema_name.clone(),
+            index: t.name.clone(),
+            tablespace: t.tablespace.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let index = schema.get_relation_mut(&self.index)?.as_index_mut()?;
+
+        index.tablespace = self.tablespace.clone();
+
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER INDEX {} SET TABLESPACE {};",
+            sql_qa(&self.schema, &self.index),
+            // back to the default tablespace when none is given
+            sql_qn(self.tablespace.as_deref().unwrap_or("pg_default")),
+        )
+    }
+
+    pub fn revert(&self, target: &Database) -> Result<Change, SnapshotError> {
+        let schema = target.get_schema(&self.schema)?;
+        let index = schema.get_relation(&self.index)?.as_index()?;
+
+        Ok(AlterIndexSetTablespaceChange::new(index).into())
+    }
+}
@@ -1,7 +1,11 @@
 use super::DropIndexChange;
 use crate::{
-    snapshot::{changes::Change, Database, Index, SnapshotError},
-    util::sqlfmt::{sql_l, sql_qa, sql_qn},
+    snapshot::{
+        changes::Change,
+        defaults::{get_default_description, get_default_index_nulls, get_default_index_order},
+        Database, Index, IndexKey, SnapshotError,
+    },
+    util::sqlfmt::{sql_collation, sql_l, sql_qa, sql_ql, sql_qn},
 };
 use serde::{Deserialize, Serialize};
 
@@ -12,8 +16,22 @@ pub struct CreateIndexChange {
     pub index: String,
 
     pub unique: bool,
+    pub nulls_not_distinct: bool,
     pub method: String,
-    pub key_expressions: Vec<String>,
+    pub keys: Vec<CreateIndexChangeKey>,
+    pub include: Vec<String>,
+    pub predicate: Option<String>,
+    pub storage_parameters: Vec<String>,
+    pub tablespace: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CreateIndexChangeKey {
+    pub expression: String,
+    pub collation: Option<String>,
+    pub opclass: Option<String>,
+    pub order: String,
+    pub nulls: String,
 }
 
 impl CreateIndexChange {
@@ -23,22 +41,53 @@ impl CreateIndexChange {
             table: t.table_name.clone(),
             index: t.name.clone(),
             unique: t.unique,
+            nulls_not_distinct: t.nulls_not_distinct,
             method: t.method.clone(),
-            key_expressions: t.key_expressions.clone(),
+            keys: t
+                .keys
+                .iter()
+                .map(|k| CreateIndexChangeKey {
+                    expression: k.expression.clone(),
+                    collation: k.collation.clone(),
+                    opclass: k.opclass.clone(),
+                    order: k.order.clone(),
+                    nulls: k.nulls.clone(),
+                })
+                .collect(),
+            include: t.include.clone(),
+            predicate: t.predicate.clone(),
+            storage_parameters: t.storage_parameters.clone(),
+            tablespace: t.tablespace.clone(),
         }
     }
 
     pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
         let schema = source.get_schema_mut(&self.schema)?;
-        let table = schema.get_relation(&self.table)?.as_table()?;
+        let table = schema.get_relation(&self.table)?.as_indexable()?;
 
         let index = Index {
             schema_name: self.schema.clone(),
-            table_name: table.name.clone(),
+            table_name: table.get_name().to_owned(),
             name: self.index.clone(),
             unique: self.unique,
+            nulls_not_distinct: self.nulls_not_distinct,
             method: self.method.clone(),
-            key_expressions: self.key_expressions.clone(),
+            keys: self
+                .keys
+                .iter()
+                .map(|k| IndexKey {
+                    expression: k.expression.clone(),
+                    collation: k.collation.clone(),
+                    opclass: k.opclass.clone(),
+                    order: k.order.clone(),
+                    nulls: k.nulls.clone(),
+                })
+                .collect(),
+            include: self.include.clone(),
+            predicate: self.predicate.clone(),
+            storage_parameters: self.storage_parameters.clone(),
+            tablespace: self.tablespace.clone(),
+            comment: get_default_description(),
         };
         schema.add_relation(index.into())?;
 
@@ -46,14 +95,31 @@ impl CreateIndexChange {
     }
 
     pub fn render_sql(&self) -> String {
-        format!(
+        let mut tokens = vec![format!(
             "CREATE {} {} ON {} USING {} ({})",
             if self.unique { "UNIQUE INDEX" } else { "INDEX" },
             sql_qn(&self.index),
             sql_qa(&self.schema, &self.table),
             self.method,
-            sql_l(&self.key_expressions),
-        ) + ";"
+            sql_l(self.keys.iter().map(|k| k.render_sql())),
+        )];
+        if !self.include.is_empty() {
+            tokens.push(format!("INCLUDE ({})", sql_ql(&self.include)));
+        }
+        if self.nulls_not_distinct {
+            tokens.push("NULLS NOT DISTINCT".to_string());
+        }
+        if !self.storage_parameters.is_empty() {
+            tokens.push(format!("WITH ({})", sql_l(&self.storage_parameters)));
+        }
+        if let Some(tablespace) = &self.tablespace {
+            tokens.push(format!("TABLESPACE {}", sql_qn(tablespace)));
+        }
+        if let Some(predicate) = &self.predicate {
+            tokens.push(format!("WHERE ({})", predicate));
+        }
+
+        tokens.join(" ") + ";"
     }
 
     pub fn revert(&self, target: &Database) -> Result<Change, SnapshotError> {
@@ -66,3 +132,24 @@ impl CreateIndexChange {
         .into())
     }
 }
+
+impl CreateIndexChangeKey {
+    // ordering is only spelled out when it isn't the default one, access
+    // methods other than btree rejecting it altogether
+    fn render_sql(&self) -> String {
+        let mut tokens = vec![self.expression.clone()];
+        if let Some(collation) = &self.collation {
+            tokens.push(format!("COLLATE {}", sql_collation(collation)));
+        }
+        if let Some(opclass) = &self.opclass {
+            tokens.push(opclass.clone());
+        }
+        if self.order != get_default_index_order() {
+            tokens.push(self.order.clone());
+        }
+        if self.nulls != get_default_index_nulls(&self.order) {
+            tokens.push(format!("NULLS {}", self.nulls));
+        }
+        tokens.join(" ")
+    }
+}
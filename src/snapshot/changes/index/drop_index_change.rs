@@ -34,14 +34,6 @@ impl DropIndexChange {
         let schema = target.get_schema(&self.schema)?;
         let index = schema.get_relation(&self.index)?.as_index()?;
 
-        Ok(CreateIndexChange {
-            schema: schema.name.clone(),
-            table: index.table_name.clone(),
-            index: index.name.clone(),
-            unique: index.unique,
-            method: index.method.clone(),
-            key_expressions: index.key_expressions.clone(),
-        }
-        .into())
+        Ok(CreateIndexChange::new(index).into())
     }
 }
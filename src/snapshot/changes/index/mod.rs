@@ -1,5 +1,11 @@
+mod alter_index_set_storage_parameters_change;
+mod alter_index_set_tablespace_change;
 mod create_index_change;
 mod drop_index_change;
+mod rename_index_change;
 
+pub use alter_index_set_storage_parameters_change::*;
+pub use alter_index_set_tablespace_change::*;
 pub use create_index_change::*;
-pub use drop_index_change::*;
\ No newline at end of file
+pub use drop_index_change::*;
+pub use rename_index_change::*;
@@ -0,0 +1,50 @@
+use crate::snapshot::{changes::Change, Database, Index, SnapshotError};
+use crate::util::sqlfmt::{sql_qa, sql_qn};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct RenameIndexChange {
+    pub schema: String,
+    pub from_index: String,
+    pub to_index: String,
+}
+
+impl RenameIndexChange {
+    pub fn new(s: &Index, t: &str) -> Self {
+        Self {
+            schema: s.schema_name.clone(),
+            from_index: s.name.clone(),
+            to_index: t.to_string(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        source
+            .get_schema(&self.schema)?
+            .get_relation(&self.from_index)?
+            .as_index()?;
+        source.rename_relation(&self.schema, &self.from_index, &self.to_index)
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER INDEX {} RENAME TO {};",
+            sql_qa(&self.schema, &self.from_index),
+            sql_qn(&self.to_index),
+        )
+    }
+
+    pub fn revert(&self, source: &Database) -> Result<Change, SnapshotError> {
+        source
+            .get_schema(&self.schema)?
+            .get_relation(&self.from_index)?
+            .as_index()?;
+
+        Ok(RenameIndexChange {
+            schema: self.schema.clone(),
+            from_index: self.to_index.clone(),
+            to_index: self.from_index.clone(),
+        }
+        .into())
+    }
+}
@@ -0,0 +1,77 @@
+use super::DropMaterializedViewChange;
+use crate::{
+    snapshot::{
+        changes::Change, defaults::get_default_description, Database, MaterializedView,
+        SnapshotError,
+    },
+    util::sqlfmt::{sql_l, sql_qa},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CreateMaterializedViewChange {
+    pub schema: String,
+    pub view: String,
+    pub query: String,
+    pub with_data: bool,
+    pub storage_parameters: Vec<String>,
+    pub refresh: bool,
+}
+
+impl CreateMaterializedViewChange {
+    pub fn new(t: &MaterializedView) -> Self {
+        Self {
+            schema: t.schema_name.clone(),
+            view: t.name.clone(),
+            query: t.query.clone(),
+            with_data: t.with_data,
+            storage_parameters: t.storage_parameters.clone(),
+            refresh: t.refresh,
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let view = MaterializedView {
+            schema_name: self.schema.clone(),
+            name: self.view.clone(),
+            query: self.query.clone(),
+            with_data: self.with_data,
+            storage_parameters: self.storage_parameters.clone(),
+            refresh: self.refresh,
+            owner: None,
+            comment: get_default_description(),
+        };
+        schema.add_relation(view.into())?;
+        return Ok(());
+    }
+
+    pub fn render_sql(&self) -> String {
+        let mut tokens = vec![format!(
+            "CREATE MATERIALIZED VIEW {}",
+            sql_qa(&self.schema, &self.view)
+        )];
+        if !self.storage_parameters.is_empty() {
+            tokens.push(format!("WITH ({})", sql_l(&self.storage_parameters)));
+        }
+        tokens.push(format!("AS {}", self.query));
+        // the refresh hook fills the view once its indexes exist
+        if self.with_data && !self.refresh {
+            tokens.push("WITH DATA".to_string());
+        } else {
+            tokens.push("WITH NO DATA".to_string());
+        }
+
+        tokens.join(" ") + ";"
+    }
+
+    pub fn revert(&self, source: &Database) -> Result<Change, SnapshotError> {
+        let schema = source.get_schema(&self.schema)?;
+
+        Ok(DropMaterializedViewChange {
+            schema: schema.name.clone(),
+            view: self.view.clone(),
+        }
+        .into())
+    }
+}
@@ -0,0 +1,44 @@
+use super::CreateMaterializedViewChange;
+use crate::{
+    snapshot::{changes::Change, Database, MaterializedView, SnapshotError},
+    util::sqlfmt::sql_qa,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct DropMaterializedViewChange {
+    pub schema: String,
+    pub view: String,
+}
+
+impl DropMaterializedViewChange {
+    pub fn new(s: &MaterializedView) -> Self {
+        Self {
+            schema: s.schema_name.clone(),
+            view: s.name.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        schema.get_relation(&self.view)?.as_materialized_view()?;
+        schema.remove_relation(&self.view)?;
+        return Ok(());
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!("DROP MATERIALIZED VIEW {};", sql_qa(&self.schema, &self.view))
+    }
+
+    pub fn revert(&self, source: &Database) -> Result<Change, SnapshotError> {
+        let schema = source.get_schema(&self.schema)?;
+        let view = schema.get_relation(&self.view)?.as_materialized_view()?;
+
+        // nothing refreshes the view on the way back, so it is filled right away
+        Ok(CreateMaterializedViewChange {
+            refresh: false,
+            ..CreateMaterializedViewChange::new(view)
+        }
+        .into())
+    }
+}
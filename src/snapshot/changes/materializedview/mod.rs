@@ -0,0 +1,9 @@
+mod create_materialized_view_change;
+mod drop_materialized_view_change;
+mod refresh_materialized_view_change;
+mod rename_materialized_view_change;
+
+pub use create_materialized_view_change::*;
+pub use drop_materialized_view_change::*;
+pub use refresh_materialized_view_change::*;
+pub use rename_materialized_view_change::*;
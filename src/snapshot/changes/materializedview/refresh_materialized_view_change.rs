@@ -0,0 +1,46 @@
+use crate::{
+    snapshot::{changes::Change, Database, MaterializedView, SnapshotError},
+    util::sqlfmt::sql_qa,
+};
+use serde::{Deserialize, Serialize};
+
+/// Populates a materialized view, or empties it when `with_data` is false.
+/// Its revert restores the population state the view had before.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct RefreshMaterializedViewChange {
+    pub schema: String,
+    pub view: String,
+    pub with_data: bool,
+}
+
+impl RefreshMaterializedViewChange {
+    pub fn new(t: &MaterializedView) -> Self {
+        Self {
+            schema: t.schema_name.clone(),
+            view: t.name.clone(),
+            with_data: t.with_data,
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let view = schema.get_relation_mut(&self.view)?.as_materialized_view_mut()?;
+        view.with_data = self.with_data;
+        return Ok(());
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "REFRESH MATERIALIZED VIEW {}{};",
+            sql_qa(&self.schema, &self.view),
+            if self.with_data { "" } else { " WITH NO DATA" },
+        )
+    }
+
+    pub fn revert(&self, source: &Database) -> Result<Change, SnapshotError> {
+        let schema = source.get_schema(&self.schema)?;
+        let view = schema.get_relation(&self.view)?.as_materialized_view()?;
+
+        Ok(RefreshMaterializedViewChange::new(view).into())
+    }
+}
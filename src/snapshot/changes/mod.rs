@@ -4,24 +4,50 @@ use serde::{Deserialize, Serialize};
 
 mod check;
 mod column;
+mod comment;
+mod constraint;
+mod exclusion;
+mod extension;
 mod foreignkey;
 mod function;
+mod grant;
+mod hazard;
 mod index;
+mod materializedview;
+mod owner;
+mod partition;
+mod policy;
 mod primarykey;
+mod procedure;
+mod publication;
 mod schema;
 mod sequence;
+mod statistics;
 mod table;
 mod unique;
 mod view;
 
 pub use check::*;
 pub use column::*;
+pub use comment::*;
+pub use constraint::*;
+pub use exclusion::*;
+pub use extension::*;
 pub use foreignkey::*;
 pub use function::*;
+pub use grant::*;
+pub use hazard::*;
 pub use index::*;
+pub use materializedview::*;
+pub use owner::*;
+pub use partition::*;
+pub use policy::*;
 pub use primarykey::*;
+pub use procedure::*;
+pub use publication::*;
 pub use schema::*;
 pub use sequence::*;
+pub use statistics::*;
 pub use table::*;
 pub use unique::*;
 pub use view::*;
@@ -70,32 +96,89 @@ define_change_impl!(
     Change,
     AddCheckChange,
     AddColumnChange,
+    AddExclusionChange,
     AddForeignKeyChange,
     AddPrimaryKeyChange,
     AddUniqueChange,
     AlterCheckChange,
+    AlterColumnAddIdentityChange,
+    AlterColumnDropExpressionChange,
+    AlterColumnDropIdentityChange,
+    AlterColumnSetCompressionChange,
     AlterColumnSetDataTypeChange,
     AlterColumnSetDefaultChange,
+    AlterColumnSetIdentityChange,
     AlterColumnSetNotNullChange,
+    AlterColumnSetStatisticsChange,
+    AlterColumnSetStorageChange,
+    AlterExclusionChange,
+    AlterExtensionSetSchemaChange,
+    AlterExtensionUpdateChange,
     AlterForeignKeyChange,
+    AlterIndexSetStorageParametersChange,
+    AlterIndexSetTablespaceChange,
+    AlterPolicyChange,
     AlterPrimaryKeyChange,
+    AlterPublicationAddTableChange,
+    AlterPublicationDropTableChange,
+    AlterSequenceChange,
+    AlterStatisticsChange,
+    AlterTableInheritChange,
+    AlterTableNoInheritChange,
+    AlterTableReplicaIdentityChange,
+    AlterTableRowLevelSecurityChange,
+    AlterTableSetLoggedChange,
+    AlterTableSetStorageParametersChange,
+    AlterTableSetTablespaceChange,
     AlterUniqueChange,
+    AttachPartitionChange,
+    CommentChange,
+    CreateExtensionChange,
     CreateFunctionChange,
     CreateIndexChange,
+    CreateMaterializedViewChange,
+    CreatePolicyChange,
+    CreateProcedureChange,
+    CreatePublicationChange,
     CreateSchemaChange,
     CreateSequenceChange,
+    CreateStatisticsChange,
     CreateTableChange,
     CreateViewChange,
+    DetachPartitionChange,
     DropCheckChange,
     DropColumnChange,
+    DropExclusionChange,
+    DropExtensionChange,
     DropForeignKeyChange,
     DropFunctionChange,
     DropIndexChange,
+    DropMaterializedViewChange,
+    DropPolicyChange,
     DropPrimaryKeyChange,
+    DropProcedureChange,
+    DropPublicationChange,
     DropSchemaChange,
     DropSequenceChange,
+    DropStatisticsChange,
     DropTableChange,
     DropUniqueChange,
     DropViewChange,
-    RenameColumnChange
+    GrantChange,
+    GrantDefaultPrivilegesChange,
+    OwnerChange,
+    RefreshMaterializedViewChange,
+    RenameColumnChange,
+    RenameConstraintChange,
+    RenameIndexChange,
+    RenameMaterializedViewChange,
+    RenameSchemaChange,
+    RenameSequenceChange,
+    RenameTableChange,
+    RenameViewChange,
+    ReplaceFunctionChange,
+    ReplaceProcedureChange,
+    ReplaceViewChange,
+    RevokeChange,
+    RevokeDefaultPrivilegesChange
 );
@@ -0,0 +1,3 @@
+mod owner_change;
+
+pub use owner_change::*;
@@ -0,0 +1,195 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    snapshot::{
+        changes::Change, Database, Function, MaterializedView, Procedure, Schema, Sequence,
+        SnapshotError, Table, View,
+    },
+    util::sqlfmt::{sql_qa, sql_qn},
+};
+
+/// Object an `ALTER ... OWNER TO` statement is applied to.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(tag = "object")]
+pub enum OwnerTarget {
+    Schema { schema: String },
+    Table { schema: String, table: String },
+    View { schema: String, view: String },
+    MaterializedView { schema: String, view: String },
+    Sequence { schema: String, sequence: String },
+    Function { schema: String, function: String },
+    Procedure { schema: String, procedure: String },
+}
+
+/// Hands an object over to another role; without one, the object goes to the
+/// role running the migration.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct OwnerChange {
+    pub target: OwnerTarget,
+    pub owner: Option<String>,
+}
+
+impl OwnerChange {
+    pub fn new(target: OwnerTarget, owner: Option<&String>) -> Self {
+        Self {
+            target,
+            owner: owner.cloned(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        *self.target.get_owner_mut(source)? = self.owner.clone();
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER {} OWNER TO {};",
+            self.target.render_sql(),
+            match &self.owner {
+                Some(owner) => sql_qn(owner),
+                None => "CURRENT_USER".to_string(),
+            }
+        )
+    }
+
+    pub fn revert(&self, source: &Database) -> Result<Change, SnapshotError> {
+        let owner = self.target.get_owner(source)?;
+        Ok(OwnerChange::new(self.target.clone(), owner.as_ref()).into())
+    }
+}
+
+impl OwnerTarget {
+    pub fn schema(t: &Schema) -> Self {
+        OwnerTarget::Schema {
+            schema: t.name.clone(),
+        }
+    }
+    pub fn table(t: &Table) -> Self {
+        OwnerTarget::Table {
+            schema: t.schema_name.clone(),
+            table: t.name.clone(),
+        }
+    }
+    pub fn view(t: &View) -> Self {
+        OwnerTarget::View {
+            schema: t.schema_name.clone(),
+            view: t.name.clone(),
+        }
+    }
+    pub fn materialized_view(t: &MaterializedView) -> Self {
+        OwnerTarget::MaterializedView {
+            schema: t.schema_name.clone(),
+            view: t.name.clone(),
+        }
+    }
+    pub fn sequence(t: &Sequence) -> Self {
+        OwnerTarget::Sequence {
+            schema: t.schema_name.clone(),
+            sequence: t.name.clone(),
+        }
+    }
+    pub fn function(t: &Function) -> Self {
+        OwnerTarget::Function {
+            schema: t.schema_name.clone(),
+            function: t.name.clone(),
+        }
+    }
+    pub fn procedure(t: &Procedure) -> Self {
+        OwnerTarget::Procedure {
+            schema: t.schema_name.clone(),
+            procedure: t.name.clone(),
+        }
+    }
+
+    fn get_owner<'a>(&self, source: &'a Database) -> Result<&'a Option<String>, SnapshotError> {
+        Ok(match self {
+            OwnerTarget::Schema { schema } => &source.get_schema(schema)?.owner,
+            OwnerTarget::Table { schema, table } => {
+                &source
+                    .get_schema(schema)?
+                    .get_relation(table)?
+                    .as_table()?
+                    .owner
+            }
+            OwnerTarget::View { schema, view } => {
+                &source
+                    .get_schema(schema)?
+                    .get_relation(view)?
+                    .as_view()?
+                    .owner
+            }
+            OwnerTarget::MaterializedView { schema, view } => {
+                let relation = source.get_schema(schema)?.get_relation(view)?;
+                &relation.as_materialized_view()?.owner
+            }
+            OwnerTarget::Sequence { schema, sequence } => {
+                let relation = source.get_schema(schema)?.get_relation(sequence)?;
+                &relation.as_sequence()?.owner
+            }
+            OwnerTarget::Function { schema, function } => {
+                &source.get_schema(schema)?.get_function(function)?.owner
+            }
+            OwnerTarget::Procedure { schema, procedure } => {
+                &source.get_schema(schema)?.get_procedure(procedure)?.owner
+            }
+        })
+    }
+
+    fn get_owner_mut<'a>(
+        &self,
+        source: &'a mut Database,
+    ) -> Result<&'a mut Option<String>, SnapshotError> {
+        Ok(match self {
+            OwnerTarget::Schema { schema } => &mut source.get_schema_mut(schema)?.owner,
+            OwnerTarget::Table { schema, table } => {
+                let relation = source.get_schema_mut(schema)?.get_relation_mut(table)?;
+                &mut relation.as_table_mut()?.owner
+            }
+            OwnerTarget::View { schema, view } => {
+                let relation = source.get_schema_mut(schema)?.get_relation_mut(view)?;
+                &mut relation.as_view_mut()?.owner
+            }
+            OwnerTarget::MaterializedView { schema, view } => {
+                let relation = source.get_schema_mut(schema)?.get_relation_mut(view)?;
+                &mut relation.as_materialized_view_mut()?.owner
+            }
+            OwnerTarget::Sequence { schema, sequence } => {
+                let relation = source.get_schema_mut(schema)?.get_relation_mut(sequence)?;
+                &mut relation.as_sequence_mut()?.owner
+            }
+            OwnerTarget::Function { schema, function } => {
+                &mut source
+                    .get_schema_mut(schema)?
+                    .get_function_mut(function)?
+                    .owner
+            }
+            OwnerTarget::Procedure { schema, procedure } => {
+                &mut source
+                    .get_schema_mut(schema)?
+                    .get_procedure_mut(procedure)?
+                    .owner
+            }
+        })
+    }
+
+    fn render_sql(&self) -> String {
+        match self {
+            OwnerTarget::Schema { schema } => format!("SCHEMA {}", sql_qn(schema)),
+            OwnerTarget::Table { schema, table } => format!("TABLE {}", sql_qa(schema, table)),
+            OwnerTarget::View { schema, view } => format!("VIEW {}", sql_qa(schema, view)),
+            OwnerTarget::MaterializedView { schema, view } => {
+                format!("MATERIALIZED VIEW {}", sql_qa(schema, view))
+            }
+            OwnerTarget::Sequence { schema, sequence } => {
+                format!("SEQUENCE {}", sql_qa(schema, sequence))
+            }
+            OwnerTarget::Function { schema, function } => {
+                format!("FUNCTION {}", sql_qa(schema, function))
+            }
+            OwnerTarget::Procedure { schema, procedure } => {
+                format!("PROCEDURE {}", sql_qa(schema, procedure))
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    snapshot::{changes::Change, Database, PartitionBound, SnapshotError, Table},
+    util::sqlfmt::sql_qa,
+};
+
+use super::DetachPartitionChange;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AttachPartitionChange {
+    pub schema: String,
+    pub table: String,
+    pub partition_schema: String,
+    pub partition: String,
+    pub bound: String,
+}
+
+impl AttachPartitionChange {
+    pub fn new(t: &Table) -> Self {
+        let partition_of = t.partition_of.as_ref().unwrap();
+        Self {
+            schema: partition_of.parent_schema.clone(),
+            table: partition_of.parent.clone(),
+            partition_schema: t.schema_name.clone(),
+            partition: t.name.clone(),
+            bound: partition_of.bound.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let parent = source
+            .get_schema(&self.schema)?
+            .get_relation(&self.table)?
+            .as_table()?;
+        if parent.partition_by.is_none() {
+            return Err(SnapshotError::relation_has_unexpected_type(
+                &self.schema,
+                &self.table,
+                "partitioned table",
+                "table",
+            ));
+        }
+
+        let partition = source
+            .get_schema_mut(&self.partition_schema)?
+            .get_relation_mut(&self.partition)?
+            .as_table_mut()?;
+        if partition.partition_of.is_some() {
+            return Err(SnapshotError::partition_already_exists(
+                &self.schema,
+                &self.table,
+                &self.partition,
+            ));
+        }
+        partition.partition_of = Some(PartitionBound {
+            parent_schema: self.schema.clone(),
+            parent: self.table.clone(),
+            bound: self.bound.clone(),
+        });
+
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER TABLE {} ATTACH PARTITION {} {};",
+            sql_qa(&self.schema, &self.table),
+            sql_qa(&self.partition_schema, &self.partition),
+            self.bound,
+        )
+    }
+
+    pub fn revert(&self, _target: &Database) -> Result<Change, SnapshotError> {
+        Ok(DetachPartitionChange {
+            schema: self.schema.clone(),
+            table: self.table.clone(),
+            partition_schema: self.partition_schema.clone(),
+            partition: self.partition.clone(),
+        }
+        .into())
+    }
+}
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    snapshot::{changes::Change, Database, SnapshotError, Table},
+    util::sqlfmt::sql_qa,
+};
+
+use super::AttachPartitionChange;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct DetachPartitionChange {
+    pub schema: String,
+    pub table: String,
+    pub partition_schema: String,
+    pub partition: String,
+}
+
+impl DetachPartitionChange {
+    pub fn new(s: &Table) -> Self {
+        let partition_of = s.partition_of.as_ref().unwrap();
+        Self {
+            schema: partition_of.parent_schema.clone(),
+            table: partition_of.parent.clone(),
+            partition_schema: s.schema_name.clone(),
+            partition: s.name.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let partition = source
+            .get_schema_mut(&self.partition_schema)?
+            .get_relation_mut(&self.partition)?
+            .as_table_mut()?;
+        match &partition.partition_of {
+            Some(partition_of)
+                if partition_of.parent_schema == self.schema && partition_of.parent == self.table =>
+            {
+                partition.partition_of = None;
+                Ok(())
+            }
+            _ => Err(SnapshotError::partition_not_found(
+                &self.schema,
+                &self.table,
+                &self.partition,
+            )),
+        }
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER TABLE {} DETACH PARTITION {};",
+            sql_qa(&self.schema, &self.table),
+            sql_qa(&self.partition_schema, &self.partition),
+        )
+    }
+
+    pub fn revert(&self, target: &Database) -> Result<Change, SnapshotError> {
+        let schema = target.get_schema(&self.partition_schema)?;
+        let partition = schema.get_relation(&self.partition)?.as_table()?;
+
+        Ok(AttachPartitionChange::new(partition).into())
+    }
+}
@@ -0,0 +1,5 @@
+mod attach_partition_change;
+mod detach_partition_change;
+
+pub use attach_partition_change::*;
+pub use detach_partition_change::*;
@@ -0,0 +1,65 @@
+use super::super::Change;
+use crate::snapshot::{Database, Policy, SnapshotError};
+use crate::util::sqlfmt::{sql_l, sql_qa, sql_qn, sql_role};
+use serde::{Deserialize, Serialize};
+
+/// `ALTER POLICY` cannot change the command nor drop a `USING` or
+/// `WITH CHECK` clause, such policies are recreated instead.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AlterPolicyChange {
+    pub schema: String,
+    pub table: String,
+    pub policy: String,
+    pub roles: Vec<String>,
+    pub using: Option<String>,
+    pub with_check: Option<String>,
+}
+
+impl AlterPolicyChange {
+    pub fn new(t: &Policy) -> Self {
+        Self {
+            schema: t.schema_name.clone(),
+            table: t.table_name.clone(),
+            policy: t.name.clone(),
+            roles: t.roles.clone(),
+            using: t.using.clone(),
+            with_check: t.with_check.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let table = schema.get_relation_mut(&self.table)?.as_table_mut()?;
+        let policy = table.get_policy_mut(&self.policy)?;
+
+        policy.roles = self.roles.clone();
+        policy.using = self.using.clone();
+        policy.with_check = self.with_check.clone();
+
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        let mut sql = format!(
+            "ALTER POLICY {} ON {} TO {}",
+            sql_qn(&self.policy),
+            sql_qa(&self.schema, &self.table),
+            sql_l(self.roles.iter().map(|r| sql_role(r))),
+        );
+        if let Some(using) = &self.using {
+            sql.push_str(&format!(" USING ({})", using));
+        }
+        if let Some(with_check) = &self.with_check {
+            sql.push_str(&format!(" WITH CHECK ({})", with_check));
+        }
+        sql + ";"
+    }
+
+    pub fn revert(&self, target: &Database) -> Result<Change, SnapshotError> {
+        let schema = target.get_schema(&self.schema)?;
+        let table = schema.get_relation(&self.table)?.as_table()?;
+        let policy = table.get_policy(&self.policy)?;
+
+        Ok(AlterPolicyChange::new(policy).into())
+    }
+}
@@ -0,0 +1,80 @@
+use super::super::Change;
+use super::DropPolicyChange;
+use crate::snapshot::{Database, Policy, SnapshotError};
+use crate::util::sqlfmt::{sql_l, sql_qa, sql_qn, sql_role};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CreatePolicyChange {
+    pub schema: String,
+    pub table: String,
+    pub policy: String,
+    pub command: String,
+    pub permissive: bool,
+    pub roles: Vec<String>,
+    pub using: Option<String>,
+    pub with_check: Option<String>,
+}
+
+impl CreatePolicyChange {
+    pub fn new(t: &Policy) -> Self {
+        Self {
+            schema: t.schema_name.clone(),
+            table: t.table_name.clone(),
+            policy: t.name.clone(),
+            command: t.command.clone(),
+            permissive: t.permissive,
+            roles: t.roles.clone(),
+            using: t.using.clone(),
+            with_check: t.with_check.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let table = schema.get_relation_mut(&self.table)?.as_table_mut()?;
+
+        table.add_policy(Policy {
+            schema_name: self.schema.clone(),
+            table_name: self.table.clone(),
+            name: self.policy.clone(),
+            command: self.command.clone(),
+            permissive: self.permissive,
+            roles: self.roles.clone(),
+            using: self.using.clone(),
+            with_check: self.with_check.clone(),
+        })?;
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        let mut sql = format!(
+            "CREATE POLICY {} ON {} AS {} FOR {} TO {}",
+            sql_qn(&self.policy),
+            sql_qa(&self.schema, &self.table),
+            if self.permissive {
+                "PERMISSIVE"
+            } else {
+                "RESTRICTIVE"
+            },
+            self.command,
+            sql_l(self.roles.iter().map(|r| sql_role(r))),
+        );
+        if let Some(using) = &self.using {
+            sql.push_str(&format!(" USING ({})", using));
+        }
+        if let Some(with_check) = &self.with_check {
+            sql.push_str(&format!(" WITH CHECK ({})", with_check));
+        }
+        sql + ";"
+    }
+
+    pub fn revert(&self, _target: &Database) -> Result<Change, SnapshotError> {
+        Ok(DropPolicyChange {
+            schema: self.schema.clone(),
+            table: self.table.clone(),
+            policy: self.policy.clone(),
+        }
+        .into())
+    }
+}
@@ -0,0 +1,45 @@
+use super::super::Change;
+use super::CreatePolicyChange;
+use crate::snapshot::{Database, Policy, SnapshotError};
+use crate::util::sqlfmt::{sql_qa, sql_qn};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct DropPolicyChange {
+    pub schema: String,
+    pub table: String,
+    pub policy: String,
+}
+
+impl DropPolicyChange {
+    pub fn new(s: &Policy) -> Self {
+        Self {
+            schema: s.schema_name.clone(),
+            table: s.table_name.clone(),
+            policy: s.name.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let table = schema.get_relation_mut(&self.table)?.as_table_mut()?;
+        table.remove_policy(&self.policy)?;
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "DROP POLICY {} ON {}",
+            sql_qn(&self.policy),
+            sql_qa(&self.schema, &self.table),
+        ) + ";"
+    }
+
+    pub fn revert(&self, target: &Database) -> Result<Change, SnapshotError> {
+        let schema = target.get_schema(&self.schema)?;
+        let table = schema.get_relation(&self.table)?.as_table()?;
+        let policy = table.get_policy(&self.policy)?;
+
+        Ok(CreatePolicyChange::new(policy).into())
+    }
+}
@@ -0,0 +1,7 @@
+mod alter_policy_change;
+mod create_policy_change;
+mod drop_policy_change;
+
+pub use alter_policy_change::*;
+pub use create_policy_change::*;
+pub use drop_policy_change::*;
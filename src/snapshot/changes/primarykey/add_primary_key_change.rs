@@ -1,6 +1,8 @@
 use super::DropPrimaryKeyChange;
 use crate::{
-    snapshot::{changes::Change, Database, PrimaryKey, SnapshotError},
+    snapshot::{
+        changes::Change, defaults::get_default_description, Database, PrimaryKey, SnapshotError,
+    },
     util::sqlfmt::{sql_qa, sql_ql, sql_qn},
 };
 use serde::{Deserialize, Serialize};
@@ -39,6 +41,7 @@ impl AddPrimaryKeyChange {
                 columns: self.columns.clone(),
                 deferrable: self.deferrable,
                 initially_deferred: self.initially_deferred,
+                comment: get_default_description(),
             }
             .into(),
         )?;
@@ -0,0 +1,60 @@
+use super::{super::Change, DropProcedureChange};
+use crate::snapshot::{Database, Procedure, SnapshotError};
+use crate::util::sqlfmt::{sql_dq, sql_qa};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CreateProcedureChange {
+    pub schema: String,
+    pub procedure: String,
+    pub arguments: String,
+    pub body: String,
+    pub language: String,
+}
+
+impl CreateProcedureChange {
+    pub fn new(t: &Procedure) -> Self {
+        Self {
+            schema: t.schema_name.clone(),
+            procedure: t.name.clone(),
+            arguments: t.arguments.clone(),
+            body: t.body.clone(),
+            language: t.language.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+
+        schema.add_procedure(Procedure {
+            schema_name: self.schema.clone(),
+            name: self.procedure.clone(),
+            arguments: self.arguments.clone(),
+            body: self.body.clone(),
+            language: self.language.clone(),
+            owner: None,
+        })?;
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "CREATE PROCEDURE {}({}) LANGUAGE {} AS {}",
+            sql_qa(&self.schema, &self.procedure),
+            self.arguments,
+            self.language,
+            sql_dq(&self.body),
+        ) + ";"
+    }
+
+    pub fn revert(&self, target: &Database) -> Result<Change, SnapshotError> {
+        let schema = target.get_schema(&self.schema)?;
+
+        Ok(DropProcedureChange {
+            schema: schema.name.clone(),
+            procedure: self.procedure.clone(),
+            arguments: self.arguments.clone(),
+        }
+        .into())
+    }
+}
@@ -0,0 +1,43 @@
+use super::{super::Change, CreateProcedureChange};
+use crate::snapshot::{Database, Procedure, SnapshotError};
+use crate::util::sqlfmt::sql_qa;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct DropProcedureChange {
+    pub schema: String,
+    pub procedure: String,
+    pub arguments: String,
+}
+
+impl DropProcedureChange {
+    pub fn new(s: &Procedure) -> Self {
+        Self {
+            schema: s.schema_name.clone(),
+            procedure: s.name.clone(),
+            arguments: s.arguments.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+
+        schema.remove_procedure(&self.procedure)?;
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "DROP PROCEDURE {}({})",
+            sql_qa(&self.schema, &self.procedure),
+            self.arguments,
+        ) + ";"
+    }
+
+    pub fn revert(&self, target: &Database) -> Result<Change, SnapshotError> {
+        let schema = target.get_schema(&self.schema)?;
+        let procedure = schema.get_procedure(&self.procedure)?;
+
+        Ok(CreateProcedureChange::new(procedure).into())
+    }
+}
@@ -0,0 +1,7 @@
+mod create_procedure_change;
+mod drop_procedure_change;
+mod replace_procedure_change;
+
+pub use create_procedure_change::*;
+pub use drop_procedure_change::*;
+pub use replace_procedure_change::*;
@@ -0,0 +1,51 @@
+use super::super::Change;
+use crate::snapshot::{Database, Procedure, SnapshotError};
+use crate::util::sqlfmt::{sql_dq, sql_qa};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ReplaceProcedureChange {
+    pub schema: String,
+    pub procedure: String,
+    pub arguments: String,
+    pub body: String,
+    pub language: String,
+}
+
+impl ReplaceProcedureChange {
+    pub fn new(t: &Procedure) -> Self {
+        Self {
+            schema: t.schema_name.clone(),
+            procedure: t.name.clone(),
+            arguments: t.arguments.clone(),
+            body: t.body.clone(),
+            language: t.language.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let procedure = schema.get_procedure_mut(&self.procedure)?;
+
+        procedure.body = self.body.clone();
+        procedure.language = self.language.clone();
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "CREATE OR REPLACE PROCEDURE {}({}) LANGUAGE {} AS {}",
+            sql_qa(&self.schema, &self.procedure),
+            self.arguments,
+            self.language,
+            sql_dq(&self.body),
+        ) + ";"
+    }
+
+    pub fn revert(&self, target: &Database) -> Result<Change, SnapshotError> {
+        let schema = target.get_schema(&self.schema)?;
+        let procedure = schema.get_procedure(&self.procedure)?;
+
+        Ok(ReplaceProcedureChange::new(procedure).into())
+    }
+}
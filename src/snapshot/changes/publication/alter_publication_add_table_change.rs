@@ -0,0 +1,54 @@
+use super::{AlterPublicationDropTableChange, PublicationTableItem};
+use crate::{
+    snapshot::{changes::Change, Database, PublicationTable, SnapshotError},
+    util::sqlfmt::sql_qn,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AlterPublicationAddTableChange {
+    pub publication: String,
+    pub table: PublicationTableItem,
+}
+
+impl AlterPublicationAddTableChange {
+    pub fn new(publication: &str, t: &PublicationTable) -> Self {
+        Self {
+            publication: publication.to_string(),
+            table: PublicationTableItem::new(t),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let publication = source.get_publication_mut(&self.publication)?;
+        if publication
+            .get_table(&self.table.schema, &self.table.table)
+            .is_some()
+        {
+            return Err(SnapshotError::publication_table_already_exists(
+                &self.publication,
+                &self.table.schema,
+                &self.table.table,
+            ));
+        }
+        publication.tables.push(self.table.to_publication_table());
+        return Ok(());
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER PUBLICATION {} ADD {};",
+            sql_qn(&self.publication),
+            self.table.render_sql(),
+        )
+    }
+
+    pub fn revert(&self, _source: &Database) -> Result<Change, SnapshotError> {
+        Ok(AlterPublicationDropTableChange {
+            publication: self.publication.clone(),
+            schema: self.table.schema.clone(),
+            table: self.table.table.clone(),
+        }
+        .into())
+    }
+}
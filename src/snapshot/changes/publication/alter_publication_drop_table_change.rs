@@ -0,0 +1,59 @@
+use super::AlterPublicationAddTableChange;
+use crate::{
+    snapshot::{changes::Change, Database, PublicationTable, SnapshotError},
+    util::sqlfmt::{sql_qa, sql_qn},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AlterPublicationDropTableChange {
+    pub publication: String,
+    pub schema: String,
+    pub table: String,
+}
+
+impl AlterPublicationDropTableChange {
+    pub fn new(publication: &str, s: &PublicationTable) -> Self {
+        Self {
+            publication: publication.to_string(),
+            schema: s.schema_name.clone(),
+            table: s.table_name.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let publication = source.get_publication_mut(&self.publication)?;
+        let len = publication.tables.len();
+        publication
+            .tables
+            .retain(|t| t.schema_name != self.schema || t.table_name != self.table);
+        if publication.tables.len() == len {
+            return Err(SnapshotError::publication_table_not_found(
+                &self.publication,
+                &self.schema,
+                &self.table,
+            ));
+        }
+        return Ok(());
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER PUBLICATION {} DROP TABLE {};",
+            sql_qn(&self.publication),
+            sql_qa(&self.schema, &self.table),
+        )
+    }
+
+    pub fn revert(&self, source: &Database) -> Result<Change, SnapshotError> {
+        let publication = source.get_publication(&self.publication)?;
+        let table = publication.get_table(&self.schema, &self.table).ok_or(
+            SnapshotError::publication_table_not_found(
+                &self.publication,
+                &self.schema,
+                &self.table,
+            ),
+        )?;
+        Ok(AlterPublicationAddTableChange::new(&self.publication, table).into())
+    }
+}
@@ -0,0 +1,51 @@
+use super::{DropPublicationChange, PublicationTableItem};
+use crate::{
+    snapshot::{changes::Change, Database, Publication, SnapshotError},
+    util::sqlfmt::{sql_l, sql_qn},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CreatePublicationChange {
+    pub publication: String,
+    pub tables: Vec<PublicationTableItem>,
+}
+
+impl CreatePublicationChange {
+    pub fn new(t: &Publication) -> Self {
+        Self {
+            publication: t.name.clone(),
+            tables: t.tables.iter().map(PublicationTableItem::new).collect(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        source.add_publication(Publication {
+            name: self.publication.clone(),
+            tables: self
+                .tables
+                .iter()
+                .map(PublicationTableItem::to_publication_table)
+                .collect(),
+        })?;
+        return Ok(());
+    }
+
+    pub fn render_sql(&self) -> String {
+        if self.tables.is_empty() {
+            return format!("CREATE PUBLICATION {};", sql_qn(&self.publication));
+        }
+        format!(
+            "CREATE PUBLICATION {} FOR {};",
+            sql_qn(&self.publication),
+            sql_l(self.tables.iter().map(PublicationTableItem::render_sql)),
+        )
+    }
+
+    pub fn revert(&self, _source: &Database) -> Result<Change, SnapshotError> {
+        Ok(DropPublicationChange {
+            publication: self.publication.clone(),
+        }
+        .into())
+    }
+}
@@ -0,0 +1,33 @@
+use super::CreatePublicationChange;
+use crate::{
+    snapshot::{changes::Change, Database, Publication, SnapshotError},
+    util::sqlfmt::sql_qn,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct DropPublicationChange {
+    pub publication: String,
+}
+
+impl DropPublicationChange {
+    pub fn new(s: &Publication) -> Self {
+        Self {
+            publication: s.name.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        source.remove_publication(&self.publication)?;
+        return Ok(());
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!("DROP PUBLICATION {};", sql_qn(&self.publication))
+    }
+
+    pub fn revert(&self, source: &Database) -> Result<Change, SnapshotError> {
+        let publication = source.get_publication(&self.publication)?;
+        Ok(CreatePublicationChange::new(publication).into())
+    }
+}
@@ -0,0 +1,11 @@
+mod alter_publication_add_table_change;
+mod alter_publication_drop_table_change;
+mod create_publication_change;
+mod drop_publication_change;
+mod publication_table_item;
+
+pub use alter_publication_add_table_change::*;
+pub use alter_publication_drop_table_change::*;
+pub use create_publication_change::*;
+pub use drop_publication_change::*;
+pub use publication_table_item::*;
@@ -0,0 +1,46 @@
+use crate::{
+    snapshot::PublicationTable,
+    util::sqlfmt::{sql_qa, sql_ql},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct PublicationTableItem {
+    pub schema: String,
+    pub table: String,
+    pub columns: Vec<String>,
+    pub row_filter: Option<String>,
+}
+
+impl PublicationTableItem {
+    pub fn new(t: &PublicationTable) -> Self {
+        Self {
+            schema: t.schema_name.clone(),
+            table: t.table_name.clone(),
+            columns: t.columns.clone(),
+            row_filter: t.row_filter.clone(),
+        }
+    }
+
+    pub fn to_publication_table(&self) -> PublicationTable {
+        PublicationTable {
+            schema_name: self.schema.clone(),
+            table_name: self.table.clone(),
+            columns: self.columns.clone(),
+            row_filter: self.row_filter.clone(),
+        }
+    }
+
+    /// `TABLE "s"."t" (a, b) WHERE (...)`, as used by `CREATE PUBLICATION`
+    /// and `ALTER PUBLICATION ... ADD`.
+    pub fn render_sql(&self) -> String {
+        let mut sql = format!("TABLE {}", sql_qa(&self.schema, &self.table));
+        if !self.columns.is_empty() {
+            sql.push_str(&format!(" ({})", sql_ql(&self.columns)));
+        }
+        if let Some(row_filter) = &self.row_filter {
+            sql.push_str(&format!(" WHERE ({})", row_filter));
+        }
+        sql
+    }
+}
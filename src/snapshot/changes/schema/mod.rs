@@ -1,5 +1,7 @@
 mod create_schema_change;
 mod drop_schema_change;
+mod rename_schema_change;
 
 pub use create_schema_change::*;
 pub use drop_schema_change::*;
+pub use rename_schema_change::*;
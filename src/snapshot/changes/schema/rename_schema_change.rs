@@ -0,0 +1,40 @@
+use crate::snapshot::{changes::Change, Database, Schema, SnapshotError};
+use crate::util::sqlfmt::sql_qn;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct RenameSchemaChange {
+    pub from_schema: String,
+    pub to_schema: String,
+}
+
+impl RenameSchemaChange {
+    pub fn new(s: &Schema, t: &str) -> Self {
+        Self {
+            from_schema: s.name.clone(),
+            to_schema: t.to_string(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        source.rename_schema(&self.from_schema, &self.to_schema)
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER SCHEMA {} RENAME TO {};",
+            sql_qn(&self.from_schema),
+            sql_qn(&self.to_schema),
+        )
+    }
+
+    pub fn revert(&self, source: &Database) -> Result<Change, SnapshotError> {
+        source.get_schema(&self.from_schema)?;
+
+        Ok(RenameSchemaChange {
+            from_schema: self.to_schema.clone(),
+            to_schema: self.from_schema.clone(),
+        }
+        .into())
+    }
+}
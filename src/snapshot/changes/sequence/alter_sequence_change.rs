@@ -0,0 +1,195 @@
+use crate::snapshot::{changes::Change, Database, Sequence, SnapshotError};
+use crate::util::sqlfmt::{sql_qa, sql_qn};
+use serde::{Deserialize, Serialize};
+
+/// Column a sequence belongs to, going away along with it.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub enum SequenceOwnedBy {
+    Unowned,
+    Column { table: String, column: String },
+}
+
+impl SequenceOwnedBy {
+    fn of(sequence: &Sequence) -> Self {
+        match (&sequence.owned_by_table, &sequence.owned_by_column) {
+            (Some(table), Some(column)) => SequenceOwnedBy::Column {
+                table: table.clone(),
+                column: column.clone(),
+            },
+            _ => SequenceOwnedBy::Unowned,
+        }
+    }
+}
+
+/// Alters a sequence in place, which keeps its current value. Only the
+/// settings that change are given.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AlterSequenceChange {
+    pub schema: String,
+    pub sequence: String,
+
+    pub data_type: Option<String>,
+    pub increment: Option<i64>,
+    pub min_value: Option<i64>,
+    pub max_value: Option<i64>,
+    pub start: Option<i64>,
+    pub cache: Option<i64>,
+    pub cycle: Option<bool>,
+    pub owned_by: Option<SequenceOwnedBy>,
+}
+
+fn changed<T: PartialEq + Clone>(s: &T, t: &T) -> Option<T> {
+    if s != t {
+        Some(t.clone())
+    } else {
+        None
+    }
+}
+
+impl AlterSequenceChange {
+    pub fn new(s: &Sequence, t: &Sequence) -> Self {
+        Self {
+            schema: t.schema_name.clone(),
+            sequence: t.name.clone(),
+
+            data_type: changed(&s.data_type, &t.data_type),
+            increment: changed(&s.increment, &t.increment),
+            min_value: changed(&s.min_value, &t.min_value),
+            max_value: changed(&s.max_value, &t.max_value),
+            start: changed(&s.start, &t.start),
+            cache: changed(&s.cache, &t.cache),
+            cycle: changed(&s.cycle, &t.cycle),
+            owned_by: changed(&SequenceOwnedBy::of(s), &SequenceOwnedBy::of(t)),
+        }
+    }
+
+    /// Hands a sequence created unowned over to its column.
+    pub fn owned_by(t: &Sequence) -> Self {
+        Self {
+            schema: t.schema_name.clone(),
+            sequence: t.name.clone(),
+
+            data_type: None,
+            increment: None,
+            min_value: None,
+            max_value: None,
+            start: None,
+            cache: None,
+            cycle: None,
+            owned_by: Some(SequenceOwnedBy::of(t)),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data_type.is_none()
+            && self.increment.is_none()
+            && self.min_value.is_none()
+            && self.max_value.is_none()
+            && self.start.is_none()
+            && self.cache.is_none()
+            && self.cycle.is_none()
+            && self.owned_by.is_none()
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let sequence = schema.get_relation_mut(&self.sequence)?.as_sequence_mut()?;
+
+        if let Some(data_type) = &self.data_type {
+            sequence.data_type = data_type.clone();
+        }
+        if let Some(increment) = self.increment {
+            sequence.increment = increment;
+        }
+        if let Some(min_value) = self.min_value {
+            sequence.min_value = min_value;
+        }
+        if let Some(max_value) = self.max_value {
+            sequence.max_value = max_value;
+        }
+        if let Some(start) = self.start {
+            sequence.start = start;
+        }
+        if let Some(cache) = self.cache {
+            sequence.cache = cache;
+        }
+        if let Some(cycle) = self.cycle {
+            sequence.cycle = cycle;
+        }
+        match &self.owned_by {
+            Some(SequenceOwnedBy::Column { table, column }) => {
+                sequence.owned_by_table = Some(table.clone());
+                sequence.owned_by_column = Some(column.clone());
+            }
+            Some(SequenceOwnedBy::Unowned) => {
+                sequence.owned_by_table = None;
+                sequence.owned_by_column = None;
+            }
+            None => {}
+        }
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        let mut tokens = vec![format!(
+            "ALTER SEQUENCE {}",
+            sql_qa(&self.schema, &self.sequence)
+        )];
+        if let Some(data_type) = &self.data_type {
+            tokens.push(format!("AS {}", data_type));
+        }
+        if let Some(increment) = self.increment {
+            tokens.push(format!("INCREMENT BY {}", increment));
+        }
+        if let Some(min_value) = self.min_value {
+            tokens.push(format!("MINVALUE {}", min_value));
+        }
+        if let Some(max_value) = self.max_value {
+            tokens.push(format!("MAXVALUE {}", max_value));
+        }
+        // the recorded start only, the current value is left alone
+        if let Some(start) = self.start {
+            tokens.push(format!("START WITH {}", start));
+        }
+        if let Some(cache) = self.cache {
+            tokens.push(format!("CACHE {}", cache));
+        }
+        if let Some(cycle) = self.cycle {
+            tokens.push(if cycle { "CYCLE" } else { "NO CYCLE" }.to_string());
+        }
+        match &self.owned_by {
+            Some(SequenceOwnedBy::Column { table, column }) => tokens.push(format!(
+                "OWNED BY {}.{}",
+                sql_qa(&self.schema, table),
+                sql_qn(column)
+            )),
+            Some(SequenceOwnedBy::Unowned) => tokens.push("OWNED BY NONE".to_string()),
+            None => {}
+        }
+        tokens.join(" ") + ";"
+    }
+
+    pub fn revert(&self, source: &Database) -> Result<Change, SnapshotError> {
+        let schema = source.get_schema(&self.schema)?;
+        let sequence = schema.get_relation(&self.sequence)?.as_sequence()?;
+
+        // bring back what this one changes, from the way it was
+        Ok(AlterSequenceChange {
+            schema: self.schema.clone(),
+            sequence: self.sequence.clone(),
+
+            data_type: self.data_type.as_ref().map(|_| sequence.data_type.clone()),
+            increment: self.increment.map(|_| sequence.increment),
+            min_value: self.min_value.map(|_| sequence.min_value),
+            max_value: self.max_value.map(|_| sequence.max_value),
+            start: self.start.map(|_| sequence.start),
+            cache: self.cache.map(|_| sequence.cache),
+            cycle: self.cycle.map(|_| sequence.cycle),
+            owned_by: self
+                .owned_by
+                .as_ref()
+                .map(|_| SequenceOwnedBy::of(sequence)),
+        }
+        .into())
+    }
+}
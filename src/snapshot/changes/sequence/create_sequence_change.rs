@@ -1,6 +1,8 @@
 use super::DropSequenceChange;
 use crate::{
-    snapshot::{changes::Change, Database, Sequence, SnapshotError},
+    snapshot::{
+        changes::Change, defaults::get_default_description, Database, Sequence, SnapshotError,
+    },
     util::sqlfmt::sql_qa,
 };
 use serde::{Deserialize, Serialize};
@@ -50,6 +52,10 @@ impl CreateSequenceChange {
             cycle: self.cycle,
             owned_by_table: None,
             owned_by_column: None,
+            owner: None,
+            comment: get_default_description(),
+            grants: vec![],
+            revokes: vec![],
         };
 
         schema.add_relation(sequence.into())?;
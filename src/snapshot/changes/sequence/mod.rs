@@ -1,5 +1,9 @@
+mod alter_sequence_change;
 mod create_sequence_change;
 mod drop_sequence_change;
+mod rename_sequence_change;
 
+pub use alter_sequence_change::*;
 pub use create_sequence_change::*;
 pub use drop_sequence_change::*;
+pub use rename_sequence_change::*;
@@ -0,0 +1,50 @@
+use crate::snapshot::{changes::Change, Database, Sequence, SnapshotError};
+use crate::util::sqlfmt::{sql_qa, sql_qn};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct RenameSequenceChange {
+    pub schema: String,
+    pub from_sequence: String,
+    pub to_sequence: String,
+}
+
+impl RenameSequenceChange {
+    pub fn new(s: &Sequence, t: &str) -> Self {
+        Self {
+            schema: s.schema_name.clone(),
+            from_sequence: s.name.clone(),
+            to_sequence: t.to_string(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        source
+            .get_schema(&self.schema)?
+            .get_relation(&self.from_sequence)?
+            .as_sequence()?;
+        source.rename_relation(&self.schema, &self.from_sequence, &self.to_sequence)
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER SEQUENCE {} RENAME TO {};",
+            sql_qa(&self.schema, &self.from_sequence),
+            sql_qn(&self.to_sequence),
+        )
+    }
+
+    pub fn revert(&self, source: &Database) -> Result<Change, SnapshotError> {
+        source
+            .get_schema(&self.schema)?
+            .get_relation(&self.from_sequence)?
+            .as_sequence()?;
+
+        Ok(RenameSequenceChange {
+            schema: self.schema.clone(),
+            from_sequence: self.to_sequence.clone(),
+            to_sequence: self.from_sequence.clone(),
+        }
+        .into())
+    }
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    snapshot::{changes::Change, Database, SnapshotError, Statistics},
+    util::sqlfmt::sql_qa,
+};
+
+/// Sets the statistics target of a statistics object; without one, the
+/// system default applies again.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AlterStatisticsChange {
+    pub schema: String,
+    pub table: String,
+    pub statistics: String,
+    pub target: Option<i64>,
+}
+
+impl AlterStatisticsChange {
+    pub fn new(t: &Statistics) -> Self {
+        Self {
+            schema: t.schema_name.clone(),
+            table: t.table_name.clone(),
+            statistics: t.name.clone(),
+            target: t.target,
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let table = schema.get_relation_mut(&self.table)?.as_table_mut()?;
+        table.get_statistics_mut(&self.statistics)?.target = self.target;
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER STATISTICS {} SET STATISTICS {};",
+            sql_qa(&self.schema, &self.statistics),
+            self.target.unwrap_or(-1),
+        )
+    }
+
+    pub fn revert(&self, target: &Database) -> Result<Change, SnapshotError> {
+        let schema = target.get_schema(&self.schema)?;
+        let table = schema.get_relation(&self.table)?.as_table()?;
+        let statistics = table.get_statistics(&self.statistics)?;
+
+        Ok(AlterStatisticsChange::new(statistics).into())
+    }
+}
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    snapshot::{
+        changes::Change, defaults::get_statistics_kinds, Database, SnapshotError, Statistics,
+    },
+    util::sqlfmt::{sql_l, sql_qa},
+};
+
+use super::DropStatisticsChange;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CreateStatisticsChange {
+    pub schema: String,
+    pub table: String,
+    pub statistics: String,
+    pub kinds: Vec<String>,
+    pub expressions: Vec<String>,
+}
+
+impl CreateStatisticsChange {
+    pub fn new(t: &Statistics) -> Self {
+        Self {
+            schema: t.schema_name.clone(),
+            table: t.table_name.clone(),
+            statistics: t.name.clone(),
+            kinds: t.kinds.clone(),
+            expressions: t.expressions.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let table = schema.get_relation_mut(&self.table)?.as_table_mut()?;
+
+        table.add_statistics(Statistics {
+            schema_name: self.schema.clone(),
+            table_name: self.table.clone(),
+            name: self.statistics.clone(),
+            kinds: self.kinds.clone(),
+            expressions: self.expressions.clone(),
+            target: None,
+        })?;
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "CREATE STATISTICS {}{} ON {} FROM {};",
+            sql_qa(&self.schema, &self.statistics),
+            // statistics on a single expression take no kinds at all
+            if self.kinds.is_empty() || self.kinds == get_statistics_kinds() {
+                "".to_string()
+            } else {
+                format!(" ({})", sql_l(&self.kinds))
+            },
+            sql_l(&self.expressions),
+            sql_qa(&self.schema, &self.table),
+        )
+    }
+
+    pub fn revert(&self, _target: &Database) -> Result<Change, SnapshotError> {
+        Ok(DropStatisticsChange {
+            schema: self.schema.clone(),
+            table: self.table.clone(),
+            statistics: self.statistics.clone(),
+        }
+        .into())
+    }
+}
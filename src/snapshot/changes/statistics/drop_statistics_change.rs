@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    snapshot::{changes::Change, Database, SnapshotError, Statistics},
+    util::sqlfmt::sql_qa,
+};
+
+use super::CreateStatisticsChange;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct DropStatisticsChange {
+    pub schema: String,
+    pub table: String,
+    pub statistics: String,
+}
+
+impl DropStatisticsChange {
+    pub fn new(s: &Statistics) -> Self {
+        Self {
+            schema: s.schema_name.clone(),
+            table: s.table_name.clone(),
+            statistics: s.name.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let table = schema.get_relation_mut(&self.table)?.as_table_mut()?;
+        table.remove_statistics(&self.statistics)?;
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "DROP STATISTICS {};",
+            sql_qa(&self.schema, &self.statistics)
+        )
+    }
+
+    pub fn revert(&self, target: &Database) -> Result<Change, SnapshotError> {
+        let schema = target.get_schema(&self.schema)?;
+        let table = schema.get_relation(&self.table)?.as_table()?;
+        let statistics = table.get_statistics(&self.statistics)?;
+
+        Ok(CreateStatisticsChange::new(statistics).into())
+    }
+}
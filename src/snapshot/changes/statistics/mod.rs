@@ -0,0 +1,7 @@
+mod alter_statistics_change;
+mod create_statistics_change;
+mod drop_statistics_change;
+
+pub use alter_statistics_change::*;
+pub use create_statistics_change::*;
+pub use drop_statistics_change::*;
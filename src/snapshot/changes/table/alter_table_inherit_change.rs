@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    snapshot::{changes::Change, Database, Inheritance, SnapshotError},
+    util::sqlfmt::sql_qa,
+};
+
+use super::AlterTableNoInheritChange;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AlterTableInheritChange {
+    pub schema: String,
+    pub table: String,
+    pub parent_schema: String,
+    pub parent: String,
+}
+
+impl AlterTableInheritChange {
+    pub fn new(schema: &str, table: &str, parent: &Inheritance) -> Self {
+        Self {
+            schema: schema.to_string(),
+            table: table.to_string(),
+            parent_schema: parent.parent_schema.clone(),
+            parent: parent.parent.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        source
+            .get_schema(&self.parent_schema)?
+            .get_relation(&self.parent)?
+            .as_table()?;
+
+        let schema = source.get_schema_mut(&self.schema)?;
+        let table = schema.get_relation_mut(&self.table)?.as_table_mut()?;
+        let inheritance = Inheritance {
+            parent_schema: self.parent_schema.clone(),
+            parent: self.parent.clone(),
+        };
+        if table.inherits.contains(&inheritance) {
+            return Err(SnapshotError::inheritance_already_exists(
+                &self.schema,
+                &self.table,
+                &self.parent,
+            ));
+        }
+        table.inherits.push(inheritance);
+
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER TABLE {} INHERIT {};",
+            sql_qa(&self.schema, &self.table),
+            sql_qa(&self.parent_schema, &self.parent),
+        )
+    }
+
+    pub fn revert(&self, _target: &Database) -> Result<Change, SnapshotError> {
+        Ok(AlterTableNoInheritChange {
+            schema: self.schema.clone(),
+            table: self.table.clone(),
+            parent_schema: self.parent_schema.clone(),
+            parent: self.parent.clone(),
+        }
+        .into())
+    }
+}
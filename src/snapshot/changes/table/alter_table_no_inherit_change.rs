@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    snapshot::{changes::Change, Database, Inheritance, SnapshotError},
+    util::sqlfmt::sql_qa,
+};
+
+use super::AlterTableInheritChange;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AlterTableNoInheritChange {
+    pub schema: String,
+    pub table: String,
+    pub parent_schema: String,
+    pub parent: String,
+}
+
+impl AlterTableNoInheritChange {
+    pub fn new(schema: &str, table: &str, parent: &Inheritance) -> Self {
+        Self {
+            schema: schema.to_string(),
+            table: table.to_string(),
+            parent_schema: parent.parent_schema.clone(),
+            parent: parent.parent.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let table = schema.get_relation_mut(&self.table)?.as_table_mut()?;
+
+        match table
+            .inherits
+            .iter()
+            .position(|p| p.parent_schema == self.parent_schema && p.parent == self.parent)
+        {
+            Some(i) => {
+                table.inherits.remove(i);
+                Ok(())
+            }
+            None => Err(SnapshotError::inheritance_not_found(
+                &self.schema,
+                &self.table,
+                &self.parent,
+            )),
+        }
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER TABLE {} NO INHERIT {};",
+            sql_qa(&self.schema, &self.table),
+            sql_qa(&self.parent_schema, &self.parent),
+        )
+    }
+
+    pub fn revert(&self, _target: &Database) -> Result<Change, SnapshotError> {
+        Ok(AlterTableInheritChange {
+            schema: self.schema.clone(),
+            table: self.table.clone(),
+            parent_schema: self.parent_schema.clone(),
+            parent: self.parent.clone(),
+        }
+        .into())
+    }
+}
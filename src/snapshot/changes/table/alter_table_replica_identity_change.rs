@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    snapshot::{changes::Change, Database, SnapshotError, Table},
+    util::sqlfmt::{sql_qa, sql_qn},
+};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AlterTableReplicaIdentityChange {
+    pub schema: String,
+    pub table: String,
+    pub replica_identity: String,
+    pub index: Option<String>,
+}
+
+impl AlterTableReplicaIdentityChange {
+    pub fn new(t: &Table) -> Self {
+        Self {
+            schema: t.schema_name.clone(),
+            table: t.name.clone(),
+            replica_identity: t.replica_identity.clone(),
+            index: t.replica_identity_index.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let table = schema.get_relation_mut(&self.table)?.as_table_mut()?;
+
+        table.replica_identity = self.replica_identity.clone();
+        table.replica_identity_index = self.index.clone();
+
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        let identity = match &self.index {
+            Some(index) => format!("USING INDEX {}", sql_qn(index)),
+            None => self.replica_identity.to_uppercase(),
+        };
+        format!(
+            "ALTER TABLE {} REPLICA IDENTITY {};",
+            sql_qa(&self.schema, &self.table),
+            identity,
+        )
+    }
+
+    pub fn revert(&self, target: &Database) -> Result<Change, SnapshotError> {
+        let schema = target.get_schema(&self.schema)?;
+        let table = schema.get_relation(&self.table)?.as_table()?;
+
+        Ok(AlterTableReplicaIdentityChange::new(table).into())
+    }
+}
@@ -0,0 +1,49 @@
+use super::super::Change;
+use crate::snapshot::{Database, SnapshotError, Table};
+use crate::util::sqlfmt::sql_qa;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AlterTableRowLevelSecurityChange {
+    pub schema: String,
+    pub table: String,
+    pub enabled: bool,
+    pub forced: bool,
+}
+
+impl AlterTableRowLevelSecurityChange {
+    pub fn new(t: &Table) -> Self {
+        Self {
+            schema: t.schema_name.clone(),
+            table: t.name.clone(),
+            enabled: t.row_level_security,
+            forced: t.force_row_level_security,
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let table = schema.get_relation_mut(&self.table)?.as_table_mut()?;
+
+        table.row_level_security = self.enabled;
+        table.force_row_level_security = self.forced;
+
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER TABLE {} {} ROW LEVEL SECURITY, {} ROW LEVEL SECURITY",
+            sql_qa(&self.schema, &self.table),
+            if self.enabled { "ENABLE" } else { "DISABLE" },
+            if self.forced { "FORCE" } else { "NO FORCE" },
+        ) + ";"
+    }
+
+    pub fn revert(&self, target: &Database) -> Result<Change, SnapshotError> {
+        let schema = target.get_schema(&self.schema)?;
+        let table = schema.get_relation(&self.table)?.as_table()?;
+
+        Ok(AlterTableRowLevelSecurityChange::new(table).into())
+    }
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    snapshot::{changes::Change, Database, SnapshotError, Table},
+    util::sqlfmt::sql_qa,
+};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AlterTableSetLoggedChange {
+    pub schema: String,
+    pub table: String,
+    pub unlogged: bool,
+}
+
+impl AlterTableSetLoggedChange {
+    pub fn new(t: &Table) -> Self {
+        Self {
+            schema: t.schema_name.clone(),
+            table: t.name.clone(),
+            unlogged: t.unlogged,
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let table = schema.get_relation_mut(&self.table)?.as_table_mut()?;
+
+        table.unlogged = self.unlogged;
+
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER TABLE {} SET {};",
+            sql_qa(&self.schema, &self.table),
+            if self.unlogged { "UNLOGGED" } else { "LOGGED" },
+        )
+    }
+
+    pub fn revert(&self, target: &Database) -> Result<Change, SnapshotError> {
+        let schema = target.get_schema(&self.schema)?;
+        let table = schema.get_relation(&self.table)?.as_table()?;
+
+        Ok(AlterTableSetLoggedChange::new(table).into())
+    }
+}
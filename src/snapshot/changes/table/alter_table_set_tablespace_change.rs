@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    snapshot::{changes::Change, Database, SnapshotError, Table},
+    util::sqlfmt::{sql_qa, sql_qn},
+};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AlterTableSetTablespaceChange {
+    pub schema: String,
+    pub table: String,
+    pub tablespace: Option<String>,
+}
+
+impl AlterTableSetTablespaceChange {
+    pub fn new(t: &Table) -> Self {
+        Self {
+            schema: t.schema_name.clone(),
+            table: t.name.clone(),
+            tablespace: t.tablespace.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let table = schema.get_relation_mut(&self.table)?.as_table_mut()?;
+
+        table.tablespace = self.tablespace.clone();
+
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER TABLE {} SET TABLESPACE {};",
+            sql_qa(&self.schema, &self.table),
+            // back to the default tablespace when none is given
+            sql_qn(self.tablespace.as_deref().unwrap_or("pg_default")),
+        )
+    }
+
+    pub fn revert(&self, target: &Database) -> Result<Change, SnapshotError> {
+        let schema = target.get_schema(&self.schema)?;
+        let table = schema.get_relation(&self.table)?.as_table()?;
+
+        Ok(AlterTableSetTablespaceChange::new(table).into())
+    }
+}
@@ -1,8 +1,12 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    snapshot::{changes::Change, Column, Database, SnapshotError, Table},
-    util::sqlfmt::{sql_l, sql_qa, sql_qn},
+    snapshot::{
+        changes::{Change, ColumnIdentity},
+        defaults::get_default_description,
+        Column, Database, PartitionKey, SnapshotError, Table,
+    },
+    util::sqlfmt::{sql_collation, sql_l, sql_qa, sql_qn},
 };
 
 use super::DropTableChange;
@@ -12,14 +16,28 @@ pub struct CreateTableChange {
     pub schema: String,
     pub table: String,
     pub columns: Vec<CreateTableChangeColumn>,
+    pub partition_by: Option<CreateTableChangePartitionKey>,
+    pub unlogged: bool,
+    pub storage_parameters: Vec<String>,
+    pub tablespace: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreateTableChangeColumn {
     pub name: String,
     pub data_type: String,
+    pub collation: Option<String>,
+    pub compression: Option<String>,
     pub not_null: bool,
     pub default: Option<String>,
+    pub generated: Option<String>,
+    pub identity: Option<ColumnIdentity>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CreateTableChangePartitionKey {
+    pub strategy: String,
+    pub key_expressions: Vec<String>,
 }
 
 impl CreateTableChange {
@@ -33,10 +51,24 @@ impl CreateTableChange {
                 .map(|c| CreateTableChangeColumn {
                     name: c.name.clone(),
                     data_type: c.data_type.clone(),
+                    collation: c.collation.clone(),
+                    compression: c.compression.clone(),
                     not_null: c.not_null,
                     default: c.default.clone(),
+                    generated: c.generated.clone(),
+                    identity: c.identity.as_ref().map(ColumnIdentity::new),
                 })
                 .collect(),
+            partition_by: t
+                .partition_by
+                .as_ref()
+                .map(|p| CreateTableChangePartitionKey {
+                    strategy: p.strategy.clone(),
+                    key_expressions: p.key_expressions.clone(),
+                }),
+            unlogged: t.unlogged,
+            storage_parameters: t.storage_parameters.clone(),
+            tablespace: t.tablespace.clone(),
         }
     }
 
@@ -44,6 +76,13 @@ impl CreateTableChange {
         let schema = source.get_schema_mut(&self.schema)?;
 
         let mut table = Table::new(&self.schema, &self.table);
+        table.partition_by = self.partition_by.as_ref().map(|p| PartitionKey {
+            strategy: p.strategy.clone(),
+            key_expressions: p.key_expressions.clone(),
+        });
+        table.unlogged = self.unlogged;
+        table.storage_parameters = self.storage_parameters.clone();
+        table.tablespace = self.tablespace.clone();
 
         for column in &self.columns {
             let column = Column {
@@ -51,8 +90,20 @@ impl CreateTableChange {
                 table_name: self.table.clone(),
                 name: column.name.clone(),
                 data_type: column.data_type.clone(),
+                collation: column.collation.clone(),
                 not_null: column.not_null,
                 default: column.default.clone(),
+                generated: column.generated.clone(),
+                identity: column.identity.as_ref().map(|i| i.to_identity()),
+                storage: None,
+                compression: column.compression.clone(),
+                statistics: None,
+                comment: get_default_description(),
+                grants: vec![],
+                revokes: vec![],
+                using: None,
+                using_from: None,
+                revert_using: None,
             };
             table.add_column(column)?;
         }
@@ -64,18 +115,46 @@ impl CreateTableChange {
 
     pub fn render_sql(&self) -> String {
         format!(
-            "CREATE TABLE {} ({});",
+            "CREATE {}TABLE {} ({}){}{}{};",
+            if self.unlogged { "UNLOGGED " } else { "" },
             sql_qa(&self.schema, &self.table),
             sql_l(self.columns.iter().map(|c| {
                 let mut tokens = vec![sql_qn(&c.name), c.data_type.clone()];
-                if !c.not_null {
-                    tokens.push("NULL".to_string());
+                if let Some(compression) = &c.compression {
+                    tokens.push(format!("COMPRESSION {}", compression));
                 }
+                if let Some(collation) = &c.collation {
+                    tokens.push(format!("COLLATE {}", sql_collation(collation)));
+                }
+                tokens.push(if c.not_null { "NOT NULL" } else { "NULL" }.to_string());
                 if let Some(default) = &c.default {
                     tokens.push(format!("DEFAULT {}", default));
                 }
+                if let Some(generated) = &c.generated {
+                    tokens.push(format!("GENERATED ALWAYS AS ({}) STORED", generated));
+                }
+                if let Some(identity) = &c.identity {
+                    tokens.push(identity.render_sql());
+                }
                 tokens.join(" ")
-            }))
+            })),
+            match &self.partition_by {
+                Some(p) => format!(
+                    " PARTITION BY {} ({})",
+                    p.strategy.to_uppercase(),
+                    sql_l(&p.key_expressions)
+                ),
+                None => "".to_string(),
+            },
+            if self.storage_parameters.is_empty() {
+                "".to_string()
+            } else {
+                format!(" WITH ({})", sql_l(&self.storage_parameters))
+            },
+            match &self.tablespace {
+                Some(tablespace) => format!(" TABLESPACE {}", sql_qn(tablespace)),
+                None => "".to_string(),
+            }
         )
     }
 
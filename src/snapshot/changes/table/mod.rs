@@ -1,5 +1,21 @@
+mod alter_table_inherit_change;
+mod alter_table_no_inherit_change;
+mod alter_table_replica_identity_change;
+mod alter_table_row_level_security_change;
+mod alter_table_set_logged_change;
+mod alter_table_set_storage_parameters_change;
+mod alter_table_set_tablespace_change;
 mod create_table_change;
 mod drop_table_change;
+mod rename_table_change;
 
+pub use alter_table_inherit_change::*;
+pub use alter_table_no_inherit_change::*;
+pub use alter_table_replica_identity_change::*;
+pub use alter_table_row_level_security_change::*;
+pub use alter_table_set_logged_change::*;
+pub use alter_table_set_storage_parameters_change::*;
+pub use alter_table_set_tablespace_change::*;
 pub use create_table_change::*;
 pub use drop_table_change::*;
+pub use rename_table_change::*;
@@ -0,0 +1,50 @@
+use crate::snapshot::{changes::Change, Database, SnapshotError, Table};
+use crate::util::sqlfmt::{sql_qa, sql_qn};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct RenameTableChange {
+    pub schema: String,
+    pub from_table: String,
+    pub to_table: String,
+}
+
+impl RenameTableChange {
+    pub fn new(s: &Table, t: &str) -> Self {
+        Self {
+            schema: s.schema_name.clone(),
+            from_table: s.name.clone(),
+            to_table: t.to_string(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        source
+            .get_schema(&self.schema)?
+            .get_relation(&self.from_table)?
+            .as_table()?;
+        source.rename_relation(&self.schema, &self.from_table, &self.to_table)
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER TABLE {} RENAME TO {};",
+            sql_qa(&self.schema, &self.from_table),
+            sql_qn(&self.to_table),
+        )
+    }
+
+    pub fn revert(&self, source: &Database) -> Result<Change, SnapshotError> {
+        source
+            .get_schema(&self.schema)?
+            .get_relation(&self.from_table)?
+            .as_table()?;
+
+        Ok(RenameTableChange {
+            schema: self.schema.clone(),
+            from_table: self.to_table.clone(),
+            to_table: self.from_table.clone(),
+        }
+        .into())
+    }
+}
@@ -1,6 +1,6 @@
 use super::DropUniqueChange;
 use crate::{
-    snapshot::{changes::Change, Database, SnapshotError, Unique},
+    snapshot::{changes::Change, defaults::get_default_description, Database, SnapshotError, Unique},
     util::sqlfmt::{sql_qa, sql_ql, sql_qn},
 };
 use serde::{Deserialize, Serialize};
@@ -38,6 +38,7 @@ impl AddUniqueChange {
             columns: self.columns.clone(),
             deferrable: self.deferrable,
             initially_deferred: self.initially_deferred,
+            comment: get_default_description(),
         };
         table.add_constraint(unique.into())?;
         Ok(())
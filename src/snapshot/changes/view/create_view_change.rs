@@ -1,5 +1,7 @@
 use super::DropViewChange;
-use crate::snapshot::{changes::Change, Database, SnapshotError, View};
+use crate::snapshot::{
+    changes::Change, defaults::get_default_description, Database, SnapshotError, View,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -24,6 +26,8 @@ impl CreateViewChange {
             schema_name: self.schema.clone(),
             name: self.view.clone(),
             query: self.query.clone(),
+            owner: None,
+            comment: get_default_description(),
         };
         schema.add_relation(view.into())?;
         return Ok(());
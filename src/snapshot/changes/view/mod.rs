@@ -1,5 +1,9 @@
 mod create_view_change;
 mod drop_view_change;
+mod rename_view_change;
+mod replace_view_change;
 
 pub use create_view_change::*;
 pub use drop_view_change::*;
+pub use rename_view_change::*;
+pub use replace_view_change::*;
@@ -0,0 +1,50 @@
+use crate::snapshot::{changes::Change, Database, SnapshotError, View};
+use crate::util::sqlfmt::{sql_qa, sql_qn};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct RenameViewChange {
+    pub schema: String,
+    pub from_view: String,
+    pub to_view: String,
+}
+
+impl RenameViewChange {
+    pub fn new(s: &View, t: &str) -> Self {
+        Self {
+            schema: s.schema_name.clone(),
+            from_view: s.name.clone(),
+            to_view: t.to_string(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        source
+            .get_schema(&self.schema)?
+            .get_relation(&self.from_view)?
+            .as_view()?;
+        source.rename_relation(&self.schema, &self.from_view, &self.to_view)
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "ALTER VIEW {} RENAME TO {};",
+            sql_qa(&self.schema, &self.from_view),
+            sql_qn(&self.to_view),
+        )
+    }
+
+    pub fn revert(&self, source: &Database) -> Result<Change, SnapshotError> {
+        source
+            .get_schema(&self.schema)?
+            .get_relation(&self.from_view)?
+            .as_view()?;
+
+        Ok(RenameViewChange {
+            schema: self.schema.clone(),
+            from_view: self.to_view.clone(),
+            to_view: self.from_view.clone(),
+        }
+        .into())
+    }
+}
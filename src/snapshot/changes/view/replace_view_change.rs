@@ -0,0 +1,43 @@
+use crate::snapshot::{changes::Change, Database, SnapshotError, View};
+use crate::util::sqlfmt::sql_qa;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ReplaceViewChange {
+    pub schema: String,
+    pub view: String,
+    pub query: String,
+}
+
+impl ReplaceViewChange {
+    pub fn new(t: &View) -> Self {
+        Self {
+            schema: t.schema_name.clone(),
+            view: t.name.clone(),
+            query: t.query.clone(),
+        }
+    }
+
+    pub fn apply(&self, source: &mut Database) -> Result<(), SnapshotError> {
+        let schema = source.get_schema_mut(&self.schema)?;
+        let view = schema.get_relation_mut(&self.view)?.as_view_mut()?;
+
+        view.query = self.query.clone();
+        Ok(())
+    }
+
+    pub fn render_sql(&self) -> String {
+        format!(
+            "CREATE OR REPLACE VIEW {} AS {};",
+            sql_qa(&self.schema, &self.view),
+            self.query
+        )
+    }
+
+    pub fn revert(&self, source: &Database) -> Result<Change, SnapshotError> {
+        let schema = source.get_schema(&self.schema)?;
+        let view = schema.get_relation(&self.view)?.as_view()?;
+
+        Ok(ReplaceViewChange::new(view).into())
+    }
+}
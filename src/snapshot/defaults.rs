@@ -42,6 +42,14 @@ pub fn get_default_index_desc_nulls() -> String {
     "FIRST".to_string()
 }
 
+pub fn get_default_index_nulls(order: &str) -> String {
+    if order == "DESC" {
+        get_default_index_desc_nulls()
+    } else {
+        get_default_index_asc_nulls()
+    }
+}
+
 pub fn get_default_column_default() -> String {
     "".to_string()
 }
@@ -62,6 +70,14 @@ pub fn get_function_volatility() -> String {
     "VOLATILE".to_string()
 }
 
+pub fn get_statistics_kinds() -> Vec<String> {
+    vec![
+        "ndistinct".to_string(),
+        "dependencies".to_string(),
+        "mcv".to_string(),
+    ]
+}
+
 pub fn get_sequence_data_type() -> String {
     "int8".to_string()
 }
@@ -109,3 +125,7 @@ pub fn get_sequence_start(increment: i64, min_value: i64, max_value: i64) -> i64
         max_value
     }
 }
+
+pub fn get_table_replica_identity() -> String {
+    "default".to_string()
+}
@@ -0,0 +1,870 @@
+use std::collections::{BTreeSet, HashMap};
+
+use super::changes::*;
+use super::types::*;
+
+/// Database object a change creates, drops or relies on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Object {
+    Schema(String),
+    Relation(String, String),
+    Column(String, String, String),
+    Constraint(String, String, String),
+    /// Functions and procedures alike
+    Function(String, String),
+    Extension(String),
+}
+
+fn schema(schema: &str) -> Object {
+    Object::Schema(schema.to_string())
+}
+
+fn relation(schema: &str, relation: &str) -> Object {
+    Object::Relation(schema.to_string(), relation.to_string())
+}
+
+fn column(schema: &str, table: &str, column: &str) -> Object {
+    Object::Column(schema.to_string(), table.to_string(), column.to_string())
+}
+
+fn constraint(schema: &str, table: &str, constraint: &str) -> Object {
+    Object::Constraint(
+        schema.to_string(),
+        table.to_string(),
+        constraint.to_string(),
+    )
+}
+
+fn function(schema: &str, function: &str) -> Object {
+    Object::Function(schema.to_string(), function.to_string())
+}
+
+fn extension(extension: &str) -> Object {
+    Object::Extension(extension.to_string())
+}
+
+/// What a change does to the objects around it.
+#[derive(Default)]
+struct Dependencies {
+    creates: Vec<Object>,
+    drops: Vec<Object>,
+    /// Objects that have to exist, as in the target, before the change runs.
+    requires: Vec<Object>,
+    /// Objects that have to still exist, as in the source, when the change
+    /// runs.
+    uses: Vec<Object>,
+}
+
+impl Dependencies {
+    fn creates(mut self, objects: impl IntoIterator<Item = Object>) -> Self {
+        self.creates.extend(objects);
+        self
+    }
+    fn drops(mut self, objects: impl IntoIterator<Item = Object>) -> Self {
+        self.drops.extend(objects);
+        self
+    }
+    fn requires(mut self, objects: impl IntoIterator<Item = Object>) -> Self {
+        self.requires.extend(objects);
+        self
+    }
+    fn uses(mut self, objects: impl IntoIterator<Item = Object>) -> Self {
+        self.uses.extend(objects);
+        self
+    }
+}
+
+/// Orders `changes` so that objects are created before anything relying on
+/// them and dropped after anything relying on them. Changes keep their
+/// original order wherever their dependencies allow it, and a cycle is broken
+/// by running its earliest change first.
+pub(super) fn sort_changes(s: &Database, t: &Database, changes: Vec<Change>) -> Vec<Change> {
+    // foreign keys wait for every new table, so tables referencing each other
+    // never form a cycle
+    let tables: Vec<Object> = changes
+        .iter()
+        .filter_map(|c| match c {
+            Change::CreateTableChange(c) => Some(relation(&c.schema, &c.table)),
+            _ => None,
+        })
+        .collect();
+    // what an extension provides is unknown, so anything that may use a type
+    // or a function waits for every new extension and goes before every
+    // extension being dropped
+    let extensions: Vec<Object> = changes
+        .iter()
+        .filter_map(|c| match c {
+            Change::CreateExtensionChange(c) => Some(extension(&c.extension)),
+            Change::DropExtensionChange(c) => Some(extension(&c.extension)),
+            _ => None,
+        })
+        .collect();
+    let dependencies: Vec<Dependencies> = changes
+        .iter()
+        .map(|c| describe(c, s, t, &tables, &extensions))
+        .collect();
+
+    let mut creators: HashMap<&Object, Vec<usize>> = HashMap::new();
+    let mut droppers: HashMap<&Object, Vec<usize>> = HashMap::new();
+    for (i, d) in dependencies.iter().enumerate() {
+        for o in &d.creates {
+            creators.entry(o).or_default().push(i);
+        }
+        for o in &d.drops {
+            droppers.entry(o).or_default().push(i);
+        }
+    }
+
+    let mut successors: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); changes.len()];
+    let mut add_edge = |from: usize, to: usize| {
+        if from != to {
+            successors[from].insert(to);
+        }
+    };
+    for (i, d) in dependencies.iter().enumerate() {
+        for o in &d.requires {
+            for &c in creators.get(o).into_iter().flatten() {
+                add_edge(c, i);
+            }
+        }
+        for o in &d.uses {
+            for &c in droppers.get(o).into_iter().flatten() {
+                add_edge(i, c);
+            }
+        }
+        // an object being recreated goes away first
+        for o in &d.creates {
+            for &c in droppers.get(o).into_iter().flatten() {
+                add_edge(c, i);
+            }
+        }
+    }
+
+    let mut predecessors = vec![0; changes.len()];
+    for next in successors.iter().flatten() {
+        predecessors[*next] += 1;
+    }
+    let mut remaining: BTreeSet<usize> = (0..changes.len()).collect();
+    let mut ready: BTreeSet<usize> = remaining
+        .iter()
+        .copied()
+        .filter(|&i| predecessors[i] == 0)
+        .collect();
+    let mut order: Vec<usize> = Vec::with_capacity(changes.len());
+    while let Some(&earliest) = remaining.iter().next() {
+        let i = ready.iter().next().copied().unwrap_or(earliest);
+        ready.remove(&i);
+        remaining.remove(&i);
+        order.push(i);
+        for &next in &successors[i] {
+            if remaining.contains(&next) {
+                predecessors[next] -= 1;
+                if predecessors[next] == 0 {
+                    ready.insert(next);
+                }
+            }
+        }
+    }
+
+    let mut changes: Vec<Option<Change>> = changes.into_iter().map(Some).collect();
+    order
+        .into_iter()
+        .map(|i| changes[i].take().unwrap())
+        .collect()
+}
+
+/// Views and materialized views of `db` reading any of the given columns or
+/// relations, directly or through other views, as `(schema, name)` pairs.
+pub(super) fn find_dependent_views(
+    db: &Database,
+    columns: &[(&str, &str, &str)],
+    relations: &[(&str, &str)],
+) -> Vec<(String, String)> {
+    let mut read: Vec<Object> = columns
+        .iter()
+        .map(|(schema, table, c)| column(schema, table, c))
+        .chain(relations.iter().map(|(schema, name)| relation(schema, name)))
+        .collect();
+    let mut views: Vec<(String, String)> = vec![];
+
+    // each round picks up the views reading those found in the one before
+    loop {
+        let mut found = false;
+        for s in db.iter_schemas() {
+            for rel in s.iter_relations() {
+                let query = match rel {
+                    Relation::View(view) => &view.query,
+                    Relation::MaterializedView(view) => &view.query,
+                    _ => continue,
+                };
+                let name = (s.name.clone(), rel.get_name().to_string());
+                if views.contains(&name)
+                    || !references(query, &s.name, None, db)
+                        .iter()
+                        .any(|o| read.contains(o))
+                {
+                    continue;
+                }
+                read.push(relation(&name.0, &name.1));
+                views.push(name);
+                found = true;
+            }
+        }
+        if !found {
+            return views;
+        }
+    }
+}
+
+fn describe(
+    change: &Change,
+    s: &Database,
+    t: &Database,
+    tables: &[Object],
+    extensions: &[Object],
+) -> Dependencies {
+    let d = Dependencies::default();
+    match change {
+        Change::CreateSchemaChange(c) => d.creates([schema(&c.schema_name)]),
+        Change::DropSchemaChange(c) => d.drops([schema(&c.schema_name)]),
+        Change::CreateExtensionChange(c) => d
+            .creates([extension(&c.extension)])
+            .requires([schema(&c.schema)]),
+        Change::DropExtensionChange(c) => {
+            let d = d.drops([extension(&c.extension)]);
+            match s.get_extension(&c.extension) {
+                Ok(e) => d.uses([schema(&e.schema_name)]),
+                Err(_) => d,
+            }
+        }
+        Change::AlterExtensionSetSchemaChange(c) => {
+            let d = d.requires([schema(&c.schema)]);
+            match s.get_extension(&c.extension) {
+                Ok(e) => d.uses([schema(&e.schema_name)]),
+                Err(_) => d,
+            }
+        }
+        Change::AlterExtensionUpdateChange(c) => d.requires([extension(&c.extension)]),
+
+        // tables
+        Change::CreateTableChange(c) => {
+            let mut d = d
+                .creates([relation(&c.schema, &c.table)])
+                .creates(
+                    c.columns
+                        .iter()
+                        .map(|col| column(&c.schema, &c.table, &col.name)),
+                )
+                .requires([schema(&c.schema)])
+                .requires(extensions.to_vec());
+            for col in &c.columns {
+                d = d.requires(column_references(
+                    &c.schema,
+                    &col.data_type,
+                    col.default.as_deref(),
+                    t,
+                ));
+            }
+            d
+        }
+        Change::DropTableChange(c) => {
+            let d = d
+                .drops([relation(&c.schema, &c.table)])
+                .uses([schema(&c.schema)])
+                .uses(extensions.to_vec());
+            match find_table(s, &c.schema, &c.table) {
+                Some(table) => d
+                    .drops(
+                        table
+                            .iter_columns()
+                            .map(|col| column(&c.schema, &c.table, &col.name)),
+                    )
+                    .drops(
+                        table
+                            .iter_constraints()
+                            .map(|con| constraint(&c.schema, &c.table, con.get_name())),
+                    )
+                    // tables referencing others go first
+                    .uses(table.iter_constraints().filter_map(|con| match con {
+                        Constraint::ForeignKey(fk) => {
+                            Some(relation(&fk.target_schema, &fk.target_table))
+                        }
+                        _ => None,
+                    })),
+                None => d,
+            }
+        }
+        Change::AlterTableInheritChange(c) => d.requires([
+            relation(&c.schema, &c.table),
+            relation(&c.parent_schema, &c.parent),
+        ]),
+        Change::AlterTableNoInheritChange(c) => d.uses([
+            relation(&c.schema, &c.table),
+            relation(&c.parent_schema, &c.parent),
+        ]),
+        Change::AttachPartitionChange(c) => d.requires([
+            relation(&c.schema, &c.table),
+            relation(&c.partition_schema, &c.partition),
+        ]),
+        Change::DetachPartitionChange(c) => d.uses([
+            relation(&c.schema, &c.table),
+            relation(&c.partition_schema, &c.partition),
+        ]),
+        Change::AlterTableReplicaIdentityChange(c) => d
+            .requires([relation(&c.schema, &c.table)])
+            .requires(c.index.iter().map(|i| relation(&c.schema, i))),
+        Change::AlterTableRowLevelSecurityChange(c) => d.requires([relation(&c.schema, &c.table)]),
+        Change::AlterTableSetLoggedChange(c) => d.requires([relation(&c.schema, &c.table)]),
+        Change::AlterTableSetStorageParametersChange(c) => {
+            d.requires([relation(&c.schema, &c.table)])
+        }
+        Change::AlterTableSetTablespaceChange(c) => d.requires([relation(&c.schema, &c.table)]),
+
+        // columns
+        Change::AddColumnChange(c) => d
+            .creates([column(&c.schema, &c.table, &c.column)])
+            .requires([relation(&c.schema, &c.table)])
+            .requires(extensions.to_vec())
+            .requires(column_references(
+                &c.schema,
+                &c.data_type,
+                c.default.as_deref(),
+                t,
+            )),
+        Change::DropColumnChange(c) => d
+            .drops([column(&c.schema, &c.table, &c.column)])
+            .uses([relation(&c.schema, &c.table)])
+            .uses(extensions.to_vec()),
+        Change::RenameColumnChange(c) => d
+            .drops([column(&c.from_schema, &c.from_table, &c.from_column)])
+            .creates([column(&c.from_schema, &c.from_table, &c.to_column)])
+            .requires([relation(&c.from_schema, &c.from_table)]),
+        // views reading the column go away first and come back after
+        Change::AlterColumnSetDataTypeChange(c) => d
+            .drops([column(&c.schema, &c.table, &c.column)])
+            .creates([column(&c.schema, &c.table, &c.column)])
+            .requires(extensions.to_vec())
+            .uses(extensions.to_vec())
+            .requires(column_references(&c.schema, &c.data_type, None, t)),
+        Change::AlterColumnSetDefaultChange(c) => d
+            .requires([column(&c.schema, &c.table, &c.column)])
+            .requires(extensions.to_vec())
+            .uses(extensions.to_vec())
+            .requires(column_references(&c.schema, "", c.default.as_deref(), t)),
+        Change::AlterColumnAddIdentityChange(c) => {
+            d.requires([column(&c.schema, &c.table, &c.column)])
+        }
+        Change::AlterColumnDropExpressionChange(c) => {
+            d.requires([column(&c.schema, &c.table, &c.column)])
+        }
+        Change::AlterColumnDropIdentityChange(c) => {
+            d.requires([column(&c.schema, &c.table, &c.column)])
+        }
+        Change::AlterColumnSetCompressionChange(c) => {
+            d.requires([column(&c.schema, &c.table, &c.column)])
+        }
+        Change::AlterColumnSetIdentityChange(c) => {
+            d.requires([column(&c.schema, &c.table, &c.column)])
+        }
+        Change::AlterColumnSetNotNullChange(c) => {
+            d.requires([column(&c.schema, &c.table, &c.column)])
+        }
+        Change::AlterColumnSetStatisticsChange(c) => {
+            d.requires([column(&c.schema, &c.table, &c.column)])
+        }
+        Change::AlterColumnSetStorageChange(c) => {
+            d.requires([column(&c.schema, &c.table, &c.column)])
+        }
+
+        // constraints
+        Change::AddPrimaryKeyChange(c) => d
+            .creates([constraint(&c.schema, &c.table, &c.constraint)])
+            .requires([relation(&c.schema, &c.table)])
+            .requires(c.columns.iter().map(|col| column(&c.schema, &c.table, col))),
+        Change::AddUniqueChange(c) => d
+            .creates([constraint(&c.schema, &c.table, &c.constraint)])
+            .requires([relation(&c.schema, &c.table)])
+            .requires(c.columns.iter().map(|col| column(&c.schema, &c.table, col))),
+        Change::AddCheckChange(c) => d
+            .creates([constraint(&c.schema, &c.table, &c.constraint)])
+            .requires([relation(&c.schema, &c.table)])
+            .requires(references(&c.expression, &c.schema, Some(&c.table), t)),
+        Change::AddExclusionChange(c) => d
+            .creates([constraint(&c.schema, &c.table, &c.constraint)])
+            .requires([relation(&c.schema, &c.table)]),
+        Change::AddForeignKeyChange(c) => d
+            .creates([constraint(&c.schema, &c.table, &c.constraint)])
+            .requires([
+                relation(&c.schema, &c.table),
+                relation(&c.target_schema, &c.target_table),
+            ])
+            .requires(c.columns.iter().map(|col| column(&c.schema, &c.table, col)))
+            .requires(
+                c.target_columns
+                    .iter()
+                    .map(|col| column(&c.target_schema, &c.target_table, col)),
+            )
+            .requires(referenced_key(
+                t,
+                &c.target_schema,
+                &c.target_table,
+                &c.target_columns,
+            ))
+            .requires(tables.iter().cloned()),
+        Change::DropForeignKeyChange(c) => {
+            let d = d
+                .drops([constraint(&c.schema, &c.table, &c.constraint)])
+                .uses([relation(&c.schema, &c.table)]);
+            let fk = find_table(s, &c.schema, &c.table)
+                .and_then(|table| table.get_constraint(&c.constraint).ok());
+            match fk {
+                Some(Constraint::ForeignKey(fk)) => d
+                    .uses([relation(&fk.target_schema, &fk.target_table)])
+                    .uses(referenced_key(
+                        s,
+                        &fk.target_schema,
+                        &fk.target_table,
+                        &fk.target_columns,
+                    )),
+                _ => d,
+            }
+        }
+        Change::DropPrimaryKeyChange(c) => d
+            .drops([constraint(&c.schema, &c.table, &c.constraint)])
+            .uses([relation(&c.schema, &c.table)]),
+        Change::DropUniqueChange(c) => d
+            .drops([constraint(&c.schema, &c.table, &c.constraint)])
+            .uses([relation(&c.schema, &c.table)]),
+        Change::DropCheckChange(c) => d
+            .drops([constraint(&c.schema, &c.table, &c.constraint)])
+            .uses([relation(&c.schema, &c.table)]),
+        Change::DropExclusionChange(c) => d
+            .drops([constraint(&c.schema, &c.table, &c.constraint)])
+            .uses([relation(&c.schema, &c.table)]),
+        Change::AlterPrimaryKeyChange(c) => {
+            d.requires([constraint(&c.schema, &c.table, &c.constraint)])
+        }
+        Change::AlterUniqueChange(c) => {
+            d.requires([constraint(&c.schema, &c.table, &c.constraint)])
+        }
+        Change::AlterCheckChange(c) => d.requires([constraint(&c.schema, &c.table, &c.constraint)]),
+        Change::AlterExclusionChange(c) => {
+            d.requires([constraint(&c.schema, &c.table, &c.constraint)])
+        }
+        Change::AlterForeignKeyChange(c) => {
+            d.requires([constraint(&c.schema, &c.table, &c.constraint)])
+        }
+
+        // indexes
+        Change::CreateIndexChange(c) => {
+            let mut sql: Vec<&str> = c.keys.iter().map(|k| k.expression.as_str()).collect();
+            sql.extend(c.include.iter().map(|i| i.as_str()));
+            sql.extend(c.predicate.as_deref());
+            d.creates([relation(&c.schema, &c.index)])
+                .requires([relation(&c.schema, &c.table)])
+                .requires(extensions.to_vec())
+                .requires(references(&sql.join(" "), &c.schema, Some(&c.table), t))
+        }
+        Change::AlterIndexSetStorageParametersChange(c) => {
+            d.requires([relation(&c.schema, &c.index)])
+        }
+        Change::AlterIndexSetTablespaceChange(c) => d.requires([relation(&c.schema, &c.index)]),
+        Change::DropIndexChange(c) => {
+            let d = d
+                .drops([relation(&c.schema, &c.index)])
+                .uses(extensions.to_vec());
+            // an index goes away along with its table or any of its columns,
+            // so it is dropped first
+            match find_relation(s, &c.schema, &c.index) {
+                Some(Relation::Index(index)) => {
+                    let mut sql: Vec<&str> =
+                        index.keys.iter().map(|k| k.expression.as_str()).collect();
+                    sql.extend(index.include.iter().map(|i| i.as_str()));
+                    sql.extend(index.predicate.as_deref());
+                    d.uses([relation(&c.schema, &index.table_name)])
+                        .uses(references(
+                            &sql.join(" "),
+                            &c.schema,
+                            Some(&index.table_name),
+                            s,
+                        ))
+                }
+                _ => d,
+            }
+        }
+
+        // views
+        Change::CreateViewChange(c) => d
+            .creates([relation(&c.schema, &c.view)])
+            .requires([schema(&c.schema)])
+            .requires(references(&c.query, &c.schema, None, t)),
+        Change::ReplaceViewChange(c) => {
+            let d = d
+                .requires([relation(&c.schema, &c.view)])
+                .requires(references(&c.query, &c.schema, None, t));
+            // whatever the former query reads stays until it is replaced
+            match find_relation(s, &c.schema, &c.view) {
+                Some(Relation::View(view)) => d.uses(references(&view.query, &c.schema, None, s)),
+                _ => d,
+            }
+        }
+        Change::DropViewChange(c) => {
+            let d = d
+                .drops([relation(&c.schema, &c.view)])
+                .uses([schema(&c.schema)]);
+            match find_relation(s, &c.schema, &c.view) {
+                Some(Relation::View(view)) => d.uses(references(&view.query, &c.schema, None, s)),
+                _ => d,
+            }
+        }
+        Change::CreateMaterializedViewChange(c) => d
+            .creates([relation(&c.schema, &c.view)])
+            .requires([schema(&c.schema)])
+            .requires(references(&c.query, &c.schema, None, t)),
+        Change::DropMaterializedViewChange(c) => {
+            let d = d
+                .drops([relation(&c.schema, &c.view)])
+                .uses([schema(&c.schema)]);
+            match find_relation(s, &c.schema, &c.view) {
+                Some(Relation::MaterializedView(view)) => {
+                    d.uses(references(&view.query, &c.schema, None, s))
+                }
+                _ => d,
+            }
+        }
+        Change::RefreshMaterializedViewChange(c) => d.requires([relation(&c.schema, &c.view)]),
+
+        // sequences
+        Change::CreateSequenceChange(c) => d
+            .creates([relation(&c.schema, &c.sequence)])
+            .requires([schema(&c.schema)]),
+        Change::AlterSequenceChange(c) => {
+            let d = d.requires([relation(&c.schema, &c.sequence)]);
+            let d = match &c.owned_by {
+                Some(SequenceOwnedBy::Column { table, column: col }) => d
+                    .requires([relation(&c.schema, table)])
+                    .requires([column(&c.schema, table, col)]),
+                _ => d,
+            };
+            // the former owner lets go of the sequence before going away
+            match (&c.owned_by, find_relation(s, &c.schema, &c.sequence)) {
+                (Some(_), Some(Relation::Sequence(sequence))) => match &sequence.owned_by_table {
+                    Some(table) => d.uses([relation(&c.schema, table)]).uses(
+                        sequence
+                            .owned_by_column
+                            .iter()
+                            .map(|col| column(&c.schema, table, col)),
+                    ),
+                    None => d,
+                },
+                _ => d,
+            }
+        }
+        Change::DropSequenceChange(c) => {
+            let d = d
+                .drops([relation(&c.schema, &c.sequence)])
+                .uses([schema(&c.schema)]);
+            // a sequence goes away along with the table owning it, so it is
+            // dropped first
+            match find_relation(s, &c.schema, &c.sequence) {
+                Some(Relation::Sequence(sequence)) => match &sequence.owned_by_table {
+                    Some(table) => d.uses([relation(&c.schema, table)]).uses(
+                        sequence
+                            .owned_by_column
+                            .iter()
+                            .map(|col| column(&c.schema, table, col)),
+                    ),
+                    None => d,
+                },
+                _ => d,
+            }
+        }
+
+        // functions and procedures
+        Change::CreateFunctionChange(c) => d
+            .creates([function(&c.schema, &c.function)])
+            .requires([schema(&c.schema)])
+            .requires(extensions.to_vec())
+            .requires(references(&c.body, &c.schema, None, t)),
+        Change::ReplaceFunctionChange(c) => d
+            .requires([function(&c.schema, &c.function)])
+            .requires(extensions.to_vec())
+            .uses(extensions.to_vec())
+            .requires(references(&c.body, &c.schema, None, t)),
+        Change::DropFunctionChange(c) => d
+            .drops([function(&c.schema, &c.function)])
+            .uses([schema(&c.schema)])
+            .uses(extensions.to_vec()),
+        Change::CreateProcedureChange(c) => d
+            .creates([function(&c.schema, &c.procedure)])
+            .requires([schema(&c.schema)])
+            .requires(extensions.to_vec())
+            .requires(references(&c.body, &c.schema, None, t)),
+        Change::ReplaceProcedureChange(c) => d
+            .requires([function(&c.schema, &c.procedure)])
+            .requires(extensions.to_vec())
+            .uses(extensions.to_vec())
+            .requires(references(&c.body, &c.schema, None, t)),
+        Change::DropProcedureChange(c) => d
+            .drops([function(&c.schema, &c.procedure)])
+            .uses([schema(&c.schema)])
+            .uses(extensions.to_vec()),
+
+        // policies and statistics
+        Change::CreatePolicyChange(c) => {
+            let sql = format!(
+                "{} {}",
+                c.using.as_deref().unwrap_or_default(),
+                c.with_check.as_deref().unwrap_or_default()
+            );
+            d.requires([relation(&c.schema, &c.table)])
+                .requires(references(&sql, &c.schema, Some(&c.table), t))
+        }
+        Change::AlterPolicyChange(c) => {
+            let sql = format!(
+                "{} {}",
+                c.using.as_deref().unwrap_or_default(),
+                c.with_check.as_deref().unwrap_or_default()
+            );
+            d.requires([relation(&c.schema, &c.table)])
+                .requires(references(&sql, &c.schema, Some(&c.table), t))
+        }
+        Change::DropPolicyChange(c) => d.uses([relation(&c.schema, &c.table)]),
+        Change::CreateStatisticsChange(c) => {
+            d.requires([relation(&c.schema, &c.table)])
+                .requires(references(
+                    &c.expressions.join(" "),
+                    &c.schema,
+                    Some(&c.table),
+                    t,
+                ))
+        }
+        Change::AlterStatisticsChange(c) => d.requires([relation(&c.schema, &c.table)]),
+        Change::DropStatisticsChange(c) => d.uses([relation(&c.schema, &c.table)]),
+
+        // publications
+        Change::CreatePublicationChange(c) => {
+            d.requires(c.tables.iter().flat_map(publication_table_objects))
+        }
+        Change::AlterPublicationAddTableChange(c) => {
+            d.requires(publication_table_objects(&c.table))
+        }
+        Change::AlterPublicationDropTableChange(c) => d.uses([relation(&c.schema, &c.table)]),
+        Change::DropPublicationChange(c) => match s.get_publication(&c.publication) {
+            Ok(publication) => d.uses(
+                publication
+                    .tables
+                    .iter()
+                    .map(|table| relation(&table.schema_name, &table.table_name)),
+            ),
+            Err(_) => d,
+        },
+
+        // renames, which compare_diff runs ahead of anything else
+        Change::RenameSchemaChange(c) => d
+            .drops([schema(&c.from_schema)])
+            .creates([schema(&c.to_schema)]),
+        Change::RenameTableChange(c) => d
+            .drops([relation(&c.schema, &c.from_table)])
+            .creates([relation(&c.schema, &c.to_table)]),
+        Change::RenameViewChange(c) => d
+            .drops([relation(&c.schema, &c.from_view)])
+            .creates([relation(&c.schema, &c.to_view)]),
+        Change::RenameMaterializedViewChange(c) => d
+            .drops([relation(&c.schema, &c.from_view)])
+            .creates([relation(&c.schema, &c.to_view)]),
+        Change::RenameSequenceChange(c) => d
+            .drops([relation(&c.schema, &c.from_sequence)])
+            .creates([relation(&c.schema, &c.to_sequence)]),
+        Change::RenameIndexChange(c) => d
+            .drops([relation(&c.schema, &c.from_index)])
+            .creates([relation(&c.schema, &c.to_index)]),
+        Change::RenameConstraintChange(c) => d
+            .drops([constraint(&c.schema, &c.table, &c.from_constraint)])
+            .creates([constraint(&c.schema, &c.table, &c.to_constraint)])
+            .requires([relation(&c.schema, &c.table)]),
+
+        // attributes of other objects
+        Change::CommentChange(c) => d.requires([comment_target(&c.target)]),
+        Change::GrantChange(c) => d.requires([grant_target(&c.target)]),
+        Change::RevokeChange(c) => d.requires([grant_target(&c.target)]),
+        Change::OwnerChange(c) => d.requires([owner_target(&c.target)]),
+        Change::GrantDefaultPrivilegesChange(c) => d.requires([schema(&c.schema)]),
+        Change::RevokeDefaultPrivilegesChange(c) => d.requires([schema(&c.schema)]),
+    }
+}
+
+fn find_relation<'a>(db: &'a Database, schema: &str, name: &str) -> Option<&'a Relation> {
+    db.get_schema(schema).ok()?.get_relation(name).ok()
+}
+
+fn find_table<'a>(db: &'a Database, schema: &str, name: &str) -> Option<&'a Table> {
+    match find_relation(db, schema, name) {
+        Some(Relation::Table(table)) => Some(table),
+        _ => None,
+    }
+}
+
+// Primary key or unique constraint a foreign key to `columns` relies on.
+fn referenced_key(db: &Database, schema: &str, table: &str, columns: &[String]) -> Vec<Object> {
+    let table = match find_table(db, schema, table) {
+        Some(table) => table,
+        None => return vec![],
+    };
+    table
+        .iter_constraints()
+        .filter(|con| match con {
+            Constraint::PrimaryKey(pk) => pk.columns == columns,
+            Constraint::Unique(u) => u.columns == columns,
+            _ => false,
+        })
+        .map(|con| constraint(schema, &table.name, con.get_name()))
+        .collect()
+}
+
+// Relations a column's type and default name, like the sequence behind
+// `nextval('seq'::regclass)`.
+fn column_references(
+    schema: &str,
+    data_type: &str,
+    default: Option<&str>,
+    db: &Database,
+) -> Vec<Object> {
+    let sql = format!("{} {}", data_type, default.unwrap_or_default());
+    references(&sql, schema, None, db)
+        .into_iter()
+        .filter(|o| !matches!(o, Object::Column(..)))
+        .collect()
+}
+
+fn publication_table_objects(item: &PublicationTableItem) -> Vec<Object> {
+    let mut objects = vec![relation(&item.schema, &item.table)];
+    objects.extend(
+        item.columns
+            .iter()
+            .map(|col| column(&item.schema, &item.table, col)),
+    );
+    objects
+}
+
+// Relations, columns and functions named in `sql`, unqualified names being
+// looked up in `schema` and columns in `table` as well, if given, and `*`
+// naming every column of the tables. Identifiers are matched without parsing
+// the statement, so a false match merely adds an ordering constraint.
+fn references(sql: &str, schema: &str, table: Option<&str>, db: &Database) -> Vec<Object> {
+    let identifiers: Vec<Vec<&str>> = sql
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$' || c == '.' || c == '"'))
+        .filter(|word| !word.is_empty())
+        .map(|word| word.split('.').map(|part| part.trim_matches('"')).collect())
+        .collect();
+
+    let mut objects: Vec<Object> = vec![];
+    let mut tables: Vec<&Table> = table
+        .and_then(|table| find_table(db, schema, table))
+        .into_iter()
+        .collect();
+    for parts in &identifiers {
+        let (schema_name, name) = match parts.as_slice() {
+            [name] => (schema, *name),
+            [schema_name, name, ..] => (*schema_name, *name),
+            [] => continue,
+        };
+        let s = match db.get_schema(schema_name) {
+            Ok(s) => s,
+            Err(_) => continue,
+        };
+        if let Ok(rel) = s.get_relation(name) {
+            if let Relation::Table(table) = rel {
+                tables.push(table);
+            }
+            objects.push(relation(schema_name, name));
+        }
+        if s.has_function(name) || s.has_procedure(name) {
+            objects.push(function(schema_name, name));
+        }
+    }
+    for parts in &identifiers {
+        let name = match parts.last() {
+            Some(name) => *name,
+            None => continue,
+        };
+        for table in &tables {
+            if table.has_column(name) {
+                objects.push(column(&table.schema_name, &table.name, name));
+            }
+        }
+    }
+    // `*` stands for every column
+    if sql.contains('*') {
+        for table in &tables {
+            for c in table.iter_columns() {
+                objects.push(column(&table.schema_name, &table.name, &c.name));
+            }
+        }
+    }
+
+    let mut unique: Vec<Object> = vec![];
+    for object in objects {
+        if !unique.contains(&object) {
+            unique.push(object);
+        }
+    }
+    unique
+}
+
+fn comment_target(target: &CommentTarget) -> Object {
+    match target {
+        CommentTarget::Schema { schema: s } => schema(s),
+        CommentTarget::Table { schema, table } => relation(schema, table),
+        CommentTarget::Column {
+            schema,
+            table,
+            column: c,
+        } => column(schema, table, c),
+        CommentTarget::Constraint {
+            schema,
+            table,
+            constraint: c,
+        } => constraint(schema, table, c),
+        CommentTarget::Index { schema, index } => relation(schema, index),
+        CommentTarget::View { schema, view } => relation(schema, view),
+        CommentTarget::MaterializedView { schema, view } => relation(schema, view),
+        CommentTarget::Sequence { schema, sequence } => relation(schema, sequence),
+        CommentTarget::Function {
+            schema,
+            function: f,
+        } => function(schema, f),
+    }
+}
+
+fn grant_target(target: &GrantTarget) -> Object {
+    match target {
+        GrantTarget::Schema { schema: s } => schema(s),
+        GrantTarget::Table { schema, table } => relation(schema, table),
+        GrantTarget::Column {
+            schema,
+            table,
+            column: c,
+        } => column(schema, table, c),
+        GrantTarget::Sequence { schema, sequence } => relation(schema, sequence),
+        GrantTarget::Function {
+            schema,
+            function: f,
+        } => function(schema, f),
+    }
+}
+
+fn owner_target(target: &OwnerTarget) -> Object {
+    match target {
+        OwnerTarget::Schema { schema: s } => schema(s),
+        OwnerTarget::Table { schema, table } => relation(schema, table),
+        OwnerTarget::View { schema, view } => relation(schema, view),
+        OwnerTarget::MaterializedView { schema, view } => relation(schema, view),
+        OwnerTarget::Sequence { schema, sequence } => relation(schema, sequence),
+        OwnerTarget::Function {
+            schema,
+            function: f,
+        } => function(schema, f),
+        OwnerTarget::Procedure { schema, procedure } => function(schema, procedure),
+    }
+}
@@ -1,4 +1,12 @@
+use std::collections::BTreeMap;
+
 use super::changes::*;
+use super::dependency::{find_dependent_views, sort_changes};
+use super::normalize::{
+    keeps_output_columns, same_arguments, same_expression, same_expressions,
+    same_optional_expression, same_type,
+};
+use super::defaults::{get_default_description, get_table_replica_identity};
 use super::types::*;
 
 struct Changeset {
@@ -18,12 +26,109 @@ impl Changeset {
 }
 
 pub fn compare_diff(s: &Database, t: &Database) -> Vec<Change> {
-    return diff_database_update(s, t).changes;
+    // renames run ahead of anything else, which is then compared against
+    // their outcome
+    let mut renamed = s.clone();
+    let mut changes: Vec<Change> = Vec::new();
+    for rename in t.iter_renames() {
+        if let Some(change) = diff_rename(&renamed, t, rename) {
+            change.apply(&mut renamed).unwrap();
+            changes.push(change);
+        }
+    }
+
+    let rest = diff_database_update(&renamed, t).changes;
+    changes.extend(sort_changes(&renamed, t, rest));
+    return changes;
+}
+
+/// Turns a rename hint into a change, as long as the source still has the
+/// object under its old name only and the target under its new name only.
+fn diff_rename(s: &Database, t: &Database, rename: &Rename) -> Option<Change> {
+    let (from, to) = (rename.from.as_str(), rename.to.as_str());
+
+    match &rename.target {
+        RenameTarget::Schema => {
+            if s.has_schema(to) || t.has_schema(from) || !t.has_schema(to) {
+                return None;
+            }
+            let si = s.get_schema(from).ok()?;
+            Some(RenameSchemaChange::new(si, to).into())
+        }
+        RenameTarget::Relation { schema } => {
+            let (ss, ts) = (s.get_schema(schema).ok()?, t.get_schema(schema).ok()?);
+            if ss.has_relation(to) || ts.has_relation(from) {
+                return None;
+            }
+            match (ss.get_relation(from).ok()?, ts.get_relation(to).ok()?) {
+                (Relation::Table(si), Relation::Table(_)) => {
+                    Some(RenameTableChange::new(si, to).into())
+                }
+                (Relation::View(si), Relation::View(_)) => {
+                    Some(RenameViewChange::new(si, to).into())
+                }
+                (Relation::MaterializedView(si), Relation::MaterializedView(_)) => {
+                    Some(RenameMaterializedViewChange::new(si, to).into())
+                }
+                (Relation::Sequence(si), Relation::Sequence(_)) => {
+                    Some(RenameSequenceChange::new(si, to).into())
+                }
+                (Relation::Index(si), Relation::Index(_)) => {
+                    Some(RenameIndexChange::new(si, to).into())
+                }
+                // a relation changing its kind is recreated anyway
+                _ => None,
+            }
+        }
+        RenameTarget::Column { schema, table } => {
+            let st = s.get_schema(schema).ok()?.get_relation(table).ok()?.as_table().ok()?;
+            let tt = t.get_schema(schema).ok()?.get_relation(table).ok()?.as_table().ok()?;
+            if st.has_column(to) || tt.has_column(from) || !tt.has_column(to) {
+                return None;
+            }
+            let si = st.get_column(from).ok()?;
+            Some(RenameColumnChange::new(si, to).into())
+        }
+        RenameTarget::Constraint { schema, table } => {
+            let st = s.get_schema(schema).ok()?.get_relation(table).ok()?.as_table().ok()?;
+            let tt = t.get_schema(schema).ok()?.get_relation(table).ok()?.as_table().ok()?;
+            if st.has_constraint(to) || tt.has_constraint(from) || !tt.has_constraint(to) {
+                return None;
+            }
+            let si = st.get_constraint(from).ok()?;
+            Some(RenameConstraintChange::new(si, to).into())
+        }
+    }
 }
 
 fn diff_database_update(s: &Database, t: &Database) -> Changeset {
     let mut changes = Changeset::new();
 
+    let cascaded = collect_cascaded_views(s, t);
+    let partitions = collect_kept_partitions(s, t);
+    let inheritances = collect_kept_inheritances(s, t);
+
+    // extensions come first, as anything below may use what they provide,
+    // though after the schema they live in
+    for ti in t.iter_extensions() {
+        if s.has_extension(ti.get_name()) {
+            let si = s.get_extension(ti.get_name()).unwrap();
+            changes.append(&mut diff_extension_update(si, ti));
+        } else {
+            changes.append(&mut diff_extension_create(ti));
+        }
+    }
+
+    // publications let go of tables before any of them is dropped
+    for si in s.iter_publications() {
+        if t.has_publication(si.get_name()) {
+            let ti = t.get_publication(si.get_name()).unwrap();
+            changes.append(&mut diff_publication_drop_tables(s, t, si, ti));
+        } else {
+            changes.append(&mut diff_publication_drop(si));
+        }
+    }
+
     for ti in t.iter_schemas() {
         if !s.has_schema(ti.get_name()) {
             changes.append(&mut diff_schema_create(ti));
@@ -32,12 +137,141 @@ fn diff_database_update(s: &Database, t: &Database) -> Changeset {
     for si in s.iter_schemas() {
         if t.has_schema(si.get_name()) {
             let ti = t.get_schema(si.get_name()).unwrap();
-            changes.append(&mut diff_schema_update(si, ti));
+            let cascaded: Vec<&str> = cascaded
+                .iter()
+                .filter(|(schema, _)| *schema == si.name)
+                .map(|(_, name)| name.as_str())
+                .collect();
+            let partitions: Vec<&str> = partitions
+                .iter()
+                .filter(|(schema, _)| *schema == si.name)
+                .map(|(_, name)| name.as_str())
+                .collect();
+            let inheritances: Vec<(&str, &Inheritance)> = inheritances
+                .iter()
+                .filter(|(schema, _, _)| *schema == si.name)
+                .map(|(_, name, parent)| (name.as_str(), parent))
+                .collect();
+            changes.append(&mut diff_schema_update(
+                si,
+                ti,
+                &cascaded,
+                &partitions,
+                &inheritances,
+            ));
         } else {
             changes.append(&mut diff_schema_drop(si));
         }
     }
 
+    // and pick them up once they all exist
+    for ti in t.iter_publications() {
+        if s.has_publication(ti.get_name()) {
+            let si = s.get_publication(ti.get_name()).unwrap();
+            changes.append(&mut diff_publication_add_tables(s, t, si, ti));
+        } else {
+            changes.append(&mut diff_publication_create(ti));
+        }
+    }
+
+    // extensions go once nothing uses them, though before the schema they
+    // live in
+    for si in s.iter_extensions() {
+        if !t.has_extension(si.get_name()) {
+            changes.append(&mut diff_extension_drop(si));
+        }
+    }
+
+    return changes;
+}
+
+fn diff_extension_create(t: &Extension) -> Changeset {
+    let mut changes = Changeset::new();
+    changes.push(CreateExtensionChange::new(t));
+    return changes;
+}
+
+fn diff_extension_update(s: &Extension, t: &Extension) -> Changeset {
+    let mut changes = Changeset::new();
+
+    if s.schema_name != t.schema_name {
+        changes.push(AlterExtensionSetSchemaChange::new(t));
+    }
+    if t.version.is_some() && s.version != t.version {
+        changes.push(AlterExtensionUpdateChange::new(t));
+    }
+
+    return changes;
+}
+
+fn diff_extension_drop(s: &Extension) -> Changeset {
+    let mut changes = Changeset::new();
+    changes.push(DropExtensionChange::new(s));
+    return changes;
+}
+
+fn diff_publication_create(t: &Publication) -> Changeset {
+    let mut changes = Changeset::new();
+    changes.push(CreatePublicationChange::new(t));
+    return changes;
+}
+
+fn diff_publication_drop(s: &Publication) -> Changeset {
+    let mut changes = Changeset::new();
+    changes.push(DropPublicationChange::new(s));
+    return changes;
+}
+
+// Whether `table` stays published the same way, the table itself not being
+// recreated.
+fn publication_table_kept(
+    s: &Database,
+    t: &Database,
+    sp: &Publication,
+    tp: &Publication,
+    table: &PublicationTable,
+) -> bool {
+    let (schema, name) = (&table.schema_name, &table.table_name);
+    if sp.get_table(schema, name) != tp.get_table(schema, name) {
+        return false;
+    }
+    match (s.get_schema(schema), t.get_schema(schema)) {
+        (Ok(ss), Ok(ts)) => table_kept(ss, ts, name).is_some(),
+        _ => false,
+    }
+}
+
+fn diff_publication_drop_tables(
+    s: &Database,
+    t: &Database,
+    sp: &Publication,
+    tp: &Publication,
+) -> Changeset {
+    let mut changes = Changeset::new();
+
+    for st in &sp.tables {
+        if !publication_table_kept(s, t, sp, tp, st) {
+            changes.push(AlterPublicationDropTableChange::new(&sp.name, st));
+        }
+    }
+
+    return changes;
+}
+
+fn diff_publication_add_tables(
+    s: &Database,
+    t: &Database,
+    sp: &Publication,
+    tp: &Publication,
+) -> Changeset {
+    let mut changes = Changeset::new();
+
+    for tt in &tp.tables {
+        if !publication_table_kept(s, t, sp, tp, tt) {
+            changes.push(AlterPublicationAddTableChange::new(&tp.name, tt));
+        }
+    }
+
     return changes;
 }
 
@@ -45,26 +279,99 @@ fn diff_schema_create(t: &Schema) -> Changeset {
     let mut changes = Changeset::new();
 
     changes.push(CreateSchemaChange::new(t));
+    changes.append(&mut diff_owner(OwnerTarget::schema(t), &None, &t.owner));
+    changes.append(&mut diff_comment(
+        CommentTarget::schema(t),
+        &get_default_description(),
+        &t.comment,
+    ));
+    changes.append(&mut diff_grants(
+        GrantTarget::schema(t),
+        &[],
+        &[],
+        &t.grants,
+        &t.revokes,
+    ));
+    changes.append(&mut diff_default_grants(&t.name, &[], &t.default_grants));
 
     for ti in t.iter_relations() {
         changes.append(&mut diff_relation_create(ti));
     }
+    changes.append(&mut diff_partition_attach(t, &[]));
+    changes.append(&mut diff_inheritance_attach(t, &[]));
+    changes.append(&mut diff_replica_identity(None, t));
     for ti in t.iter_functions() {
         changes.append(&mut diff_function_create(ti));
     }
+    for ti in t.iter_procedures() {
+        changes.append(&mut diff_procedure_create(ti));
+    }
+
+    changes.append(&mut diff_materialized_view_refresh(None, t));
 
     return changes;
 }
 
-fn diff_schema_update(s: &Schema, t: &Schema) -> Changeset {
+fn diff_schema_update(
+    s: &Schema,
+    t: &Schema,
+    cascaded: &[&str],
+    partitions: &[&str],
+    inheritances: &[(&str, &Inheritance)],
+) -> Changeset {
     let mut changes = Changeset::new();
 
+    let mut recreated = collect_recreated_materialized_views(s, t);
+    for name in cascaded {
+        if !recreated.contains(name) {
+            recreated.push(name);
+        }
+    }
+    let sandwiched = collect_indexes_on(s, t, &recreated);
+
+    changes.append(&mut diff_owner(OwnerTarget::schema(t), &s.owner, &t.owner));
+    changes.append(&mut diff_comment(
+        CommentTarget::schema(t),
+        &s.comment,
+        &t.comment,
+    ));
+    changes.append(&mut diff_grants(
+        GrantTarget::schema(t),
+        &s.grants,
+        &s.revokes,
+        &t.grants,
+        &t.revokes,
+    ));
+    changes.append(&mut diff_default_grants(
+        &t.name,
+        &s.default_grants,
+        &t.default_grants,
+    ));
+    changes.append(&mut diff_partition_detach(s, partitions));
+    changes.append(&mut diff_inheritance_detach(s, inheritances));
+
+    let renamed = collect_renamed_indexes(s, t, &sandwiched);
+    for (si, to) in &renamed {
+        changes.push(RenameIndexChange::new(si, to));
+    }
+
     for ti in t.iter_relations() {
+        if sandwiched.contains(&ti.get_name())
+            || renamed.iter().any(|(_, to)| *to == ti.get_name())
+        {
+            continue;
+        }
         if !s.has_relation(ti.get_name()) {
             changes.append(&mut diff_relation_create(ti));
         }
     }
     for si in s.iter_relations() {
+        if sandwiched.contains(&si.get_name())
+            || recreated.contains(&si.get_name())
+            || renamed.iter().any(|(from, _)| from.name == si.get_name())
+        {
+            continue;
+        }
         if t.has_relation(si.get_name()) {
             let ti = t.get_relation(si.get_name()).unwrap();
             changes.append(&mut diff_relation_update(si, ti));
@@ -72,6 +379,15 @@ fn diff_schema_update(s: &Schema, t: &Schema) -> Changeset {
             changes.append(&mut diff_relation_drop(si));
         }
     }
+    changes.append(&mut diff_view_recreate(
+        s,
+        t,
+        &recreated,
+        &sandwiched,
+    ));
+    changes.append(&mut diff_partition_attach(t, partitions));
+    changes.append(&mut diff_inheritance_attach(t, inheritances));
+    changes.append(&mut diff_replica_identity(Some(s), t));
 
     for ti in t.iter_functions() {
         if !s.has_function(ti.get_name()) {
@@ -87,18 +403,39 @@ fn diff_schema_update(s: &Schema, t: &Schema) -> Changeset {
         }
     }
 
+    for ti in t.iter_procedures() {
+        if !s.has_procedure(ti.get_name()) {
+            changes.append(&mut diff_procedure_create(ti));
+        }
+    }
+    for si in s.iter_procedures() {
+        if t.has_procedure(si.get_name()) {
+            let ti = t.get_procedure(si.get_name()).unwrap();
+            changes.append(&mut diff_procedure_update(si, ti));
+        } else {
+            changes.append(&mut diff_procedure_drop(si));
+        }
+    }
+
+    changes.append(&mut diff_materialized_view_refresh(Some(s), t));
+
     return changes;
 }
 
 fn diff_schema_drop(s: &Schema) -> Changeset {
     let mut changes = Changeset::new();
 
+    changes.append(&mut diff_partition_detach(s, &[]));
+    changes.append(&mut diff_inheritance_detach(s, &[]));
     for si in s.iter_relations() {
         changes.append(&mut diff_relation_drop(si));
     }
     for si in s.iter_functions() {
         changes.append(&mut diff_function_drop(si));
     }
+    for si in s.iter_procedures() {
+        changes.append(&mut diff_procedure_drop(si));
+    }
 
     changes.push(DropSchemaChange::new(s));
 
@@ -109,6 +446,7 @@ fn diff_relation_create(t: &Relation) -> Changeset {
     match t {
         Relation::Table(t) => diff_table_create(t),
         Relation::View(t) => diff_view_create(t),
+        Relation::MaterializedView(t) => diff_materialized_view_create(t),
         Relation::Sequence(t) => diff_sequence_create(t),
         Relation::Index(t) => diff_index_create(t),
     }
@@ -124,6 +462,9 @@ fn diff_relation_update(s: &Relation, t: &Relation) -> Changeset {
         (Relation::View(s), Relation::View(t)) => {
             changes.append(&mut diff_view_update(s, t));
         }
+        (Relation::MaterializedView(s), Relation::MaterializedView(t)) => {
+            changes.append(&mut diff_materialized_view_update(s, t));
+        }
         (Relation::Sequence(s), Relation::Sequence(t)) => {
             changes.append(&mut diff_sequence_update(s, t));
         }
@@ -143,6 +484,7 @@ fn diff_relation_drop(s: &Relation) -> Changeset {
     match s {
         Relation::Table(t) => diff_table_drop(t),
         Relation::View(t) => diff_view_drop(t),
+        Relation::MaterializedView(t) => diff_materialized_view_drop(t),
         Relation::Sequence(t) => diff_sequence_drop(t),
         Relation::Index(t) => diff_index_drop(t),
     }
@@ -152,18 +494,79 @@ fn diff_table_create(t: &Table) -> Changeset {
     let mut changes = Changeset::new();
 
     changes.push(CreateTableChange::new(t));
+    changes.append(&mut diff_owner(OwnerTarget::table(t), &None, &t.owner));
+    changes.append(&mut diff_comment(
+        CommentTarget::table(t),
+        &get_default_description(),
+        &t.comment,
+    ));
+    changes.append(&mut diff_grants(
+        GrantTarget::table(t),
+        &[],
+        &[],
+        &t.grants,
+        &t.revokes,
+    ));
+    for ti in t.iter_columns() {
+        changes.append(&mut diff_column_settings_create(ti));
+        changes.append(&mut diff_comment(
+            CommentTarget::column(ti),
+            &get_default_description(),
+            &ti.comment,
+        ));
+        changes.append(&mut diff_grants(
+            GrantTarget::column(ti),
+            &[],
+            &[],
+            &ti.grants,
+            &ti.revokes,
+        ));
+    }
 
     // constraints
     for ti in t.iter_constraints() {
         changes.append(&mut diff_constraint_create(ti));
     }
 
+    // row level security
+    if t.row_level_security || t.force_row_level_security {
+        changes.push(AlterTableRowLevelSecurityChange::new(t));
+    }
+    for ti in t.iter_policies() {
+        changes.push(CreatePolicyChange::new(ti));
+    }
+
+    // statistics
+    for ti in t.iter_statistics() {
+        changes.append(&mut diff_statistics_create(ti));
+    }
+
     return changes;
 }
 
 fn diff_table_update(s: &Table, t: &Table) -> Changeset {
     let mut changes = Changeset::new();
 
+    if table_needs_recreate(s, t) {
+        changes.append(&mut diff_table_drop(s));
+        changes.append(&mut diff_table_create(t));
+        return changes;
+    }
+
+    // policies may depend on columns, so they are dropped before anything else
+    for si in s.iter_policies() {
+        match t.get_policy(si.get_name()) {
+            Ok(ti) if !policy_needs_recreate(si, ti) => {}
+            _ => changes.push(DropPolicyChange::new(si)),
+        }
+    }
+    for si in s.iter_statistics() {
+        match t.get_statistics(si.get_name()) {
+            Ok(ti) if !statistics_needs_recreate(si, ti) => {}
+            _ => changes.push(DropStatisticsChange::new(si)),
+        }
+    }
+
     // columns
     for ti in t.iter_columns() {
         if s.has_column(ti.get_name()) {
@@ -194,6 +597,61 @@ fn diff_table_update(s: &Table, t: &Table) -> Changeset {
         }
     }
 
+    // storage
+    if s.unlogged != t.unlogged {
+        changes.push(AlterTableSetLoggedChange::new(t));
+    }
+    let storage_parameters = AlterTableSetStorageParametersChange::new(s, t);
+    if !storage_parameters.is_empty() {
+        changes.push(storage_parameters);
+    }
+    if s.tablespace != t.tablespace {
+        changes.push(AlterTableSetTablespaceChange::new(t));
+    }
+
+    // row level security
+    if s.row_level_security != t.row_level_security
+        || s.force_row_level_security != t.force_row_level_security
+    {
+        changes.push(AlterTableRowLevelSecurityChange::new(t));
+    }
+    for ti in t.iter_policies() {
+        match s.get_policy(ti.get_name()) {
+            Ok(si) if !policy_needs_recreate(si, ti) => {
+                if !same_policy(si, ti) {
+                    changes.push(AlterPolicyChange::new(ti));
+                }
+            }
+            _ => changes.push(CreatePolicyChange::new(ti)),
+        }
+    }
+
+    // statistics
+    for ti in t.iter_statistics() {
+        match s.get_statistics(ti.get_name()) {
+            Ok(si) if !statistics_needs_recreate(si, ti) => {
+                if si.target != ti.target {
+                    changes.push(AlterStatisticsChange::new(ti));
+                }
+            }
+            _ => changes.append(&mut diff_statistics_create(ti)),
+        }
+    }
+
+    changes.append(&mut diff_owner(OwnerTarget::table(t), &s.owner, &t.owner));
+    changes.append(&mut diff_comment(
+        CommentTarget::table(t),
+        &s.comment,
+        &t.comment,
+    ));
+    changes.append(&mut diff_grants(
+        GrantTarget::table(t),
+        &s.grants,
+        &s.revokes,
+        &t.grants,
+        &t.revokes,
+    ));
+
     return changes;
 }
 
@@ -205,10 +663,244 @@ fn diff_table_drop(s: &Table) -> Changeset {
     return changes;
 }
 
+// `ALTER POLICY` can neither change the command of a policy nor remove a
+// clause from it.
+fn policy_needs_recreate(s: &Policy, t: &Policy) -> bool {
+    s.command != t.command
+        || s.permissive != t.permissive
+        || s.using.is_some() != t.using.is_some()
+        || s.with_check.is_some() != t.with_check.is_some()
+}
+
+fn same_policy(s: &Policy, t: &Policy) -> bool {
+    s.roles == t.roles
+        && same_optional_expression(&s.using, &t.using)
+        && same_optional_expression(&s.with_check, &t.with_check)
+}
+
+fn diff_statistics_create(t: &Statistics) -> Changeset {
+    let mut changes = Changeset::new();
+    changes.push(CreateStatisticsChange::new(t));
+    if t.target.is_some() {
+        changes.push(AlterStatisticsChange::new(t));
+    }
+    return changes;
+}
+
+// `ALTER STATISTICS` only changes the statistics target.
+fn statistics_needs_recreate(s: &Statistics, t: &Statistics) -> bool {
+    s.kinds != t.kinds || !same_expressions(&s.expressions, &t.expressions)
+}
+
+// The partition key of a table cannot be altered, so the table is dropped and
+// created again, losing its rows. The drop is tagged irreversible and
+// data-losing, so `stage` flags it and `down` refuses it without `--force`.
+fn table_needs_recreate(s: &Table, t: &Table) -> bool {
+    s.partition_by != t.partition_by
+}
+
+// The tables `name` in both schemas, unless the table is missing from either
+// or recreated.
+fn table_kept<'a>(s: &'a Schema, t: &'a Schema, name: &str) -> Option<(&'a Table, &'a Table)> {
+    match (s.get_relation(name), t.get_relation(name)) {
+        (Ok(Relation::Table(st)), Ok(Relation::Table(tt))) if !table_needs_recreate(st, tt) => {
+            Some((st, tt))
+        }
+        _ => None,
+    }
+}
+
+// Partitions staying attached to the same parent with the same bound, with
+// neither the partition nor its parent being recreated, as `(schema, name)`
+// pairs. The parent may live in another schema.
+fn collect_kept_partitions(s: &Database, t: &Database) -> Vec<(String, String)> {
+    let mut partitions = vec![];
+    for ss in s.iter_schemas() {
+        for si in ss.iter_relations() {
+            let Some((st, tt)) = table_kept_in(s, t, &ss.name, si.get_name()) else {
+                continue;
+            };
+            if let (Some(sp), Some(tp)) = (&st.partition_of, &tt.partition_of) {
+                if sp == tp && table_kept_in(s, t, &sp.parent_schema, &sp.parent).is_some() {
+                    partitions.push((ss.name.clone(), st.name.clone()));
+                }
+            }
+        }
+    }
+    partitions
+}
+
+// Tables keeping inheriting from a parent, with neither of them being
+// recreated, as `(schema, name, parent)`. The parent may live in another
+// schema.
+fn collect_kept_inheritances(s: &Database, t: &Database) -> Vec<(String, String, Inheritance)> {
+    let mut inheritances = vec![];
+    for ss in s.iter_schemas() {
+        for si in ss.iter_relations() {
+            let Some((st, tt)) = table_kept_in(s, t, &ss.name, si.get_name()) else {
+                continue;
+            };
+            for parent in &st.inherits {
+                if tt.inherits.contains(parent)
+                    && table_kept_in(s, t, &parent.parent_schema, &parent.parent).is_some()
+                {
+                    inheritances.push((ss.name.clone(), st.name.clone(), parent.clone()));
+                }
+            }
+        }
+    }
+    inheritances
+}
+
+// Like `table_kept`, for a table in any schema.
+fn table_kept_in<'a>(
+    s: &'a Database,
+    t: &'a Database,
+    schema: &str,
+    name: &str,
+) -> Option<(&'a Table, &'a Table)> {
+    match (s.get_schema(schema), t.get_schema(schema)) {
+        (Ok(ss), Ok(ts)) => table_kept(ss, ts, name),
+        _ => None,
+    }
+}
+
+// Partitions are detached before any relation is dropped, since dropping a
+// partitioned table drops its partitions too.
+fn diff_partition_detach(s: &Schema, kept: &[&str]) -> Changeset {
+    let mut changes = Changeset::new();
+
+    for si in s.iter_relations() {
+        if let Relation::Table(st) = si {
+            if st.partition_of.is_some() && !kept.contains(&si.get_name()) {
+                changes.push(DetachPartitionChange::new(st));
+            }
+        }
+    }
+
+    return changes;
+}
+
+// Partitions are attached once both they and their parent exist.
+fn diff_partition_attach(t: &Schema, kept: &[&str]) -> Changeset {
+    let mut changes = Changeset::new();
+
+    for ti in t.iter_relations() {
+        if let Relation::Table(tt) = ti {
+            if tt.partition_of.is_some() && !kept.contains(&ti.get_name()) {
+                changes.push(AttachPartitionChange::new(tt));
+            }
+        }
+    }
+
+    return changes;
+}
+
+// Like partitions, children stop inheriting before any relation is dropped,
+// since a parent cannot be dropped while it still has children.
+fn diff_inheritance_detach(s: &Schema, kept: &[(&str, &Inheritance)]) -> Changeset {
+    let mut changes = Changeset::new();
+
+    for si in s.iter_relations() {
+        if let Relation::Table(st) = si {
+            for parent in &st.inherits {
+                if !kept.contains(&(st.name.as_str(), parent)) {
+                    changes.push(AlterTableNoInheritChange::new(
+                        &st.schema_name,
+                        &st.name,
+                        parent,
+                    ));
+                }
+            }
+        }
+    }
+
+    return changes;
+}
+
+// Children start inheriting once both they and their parents exist.
+fn diff_inheritance_attach(t: &Schema, kept: &[(&str, &Inheritance)]) -> Changeset {
+    let mut changes = Changeset::new();
+
+    for ti in t.iter_relations() {
+        if let Relation::Table(tt) = ti {
+            for parent in &tt.inherits {
+                if !kept.contains(&(tt.name.as_str(), parent)) {
+                    changes.push(AlterTableInheritChange::new(
+                        &tt.schema_name,
+                        &tt.name,
+                        parent,
+                    ));
+                }
+            }
+        }
+    }
+
+    return changes;
+}
+
+// Replica identities are set once the indexes they may use exist. A table or
+// index being recreated starts over from the default identity.
+fn diff_replica_identity(s: Option<&Schema>, t: &Schema) -> Changeset {
+    let mut changes = Changeset::new();
+
+    for ti in t.iter_relations() {
+        if let Relation::Table(tt) = ti {
+            let st = s.and_then(|s| table_kept(s, t, &tt.name).map(|(st, _)| st));
+            let source = match st {
+                Some(st) => {
+                    let index_kept = match &st.replica_identity_index {
+                        Some(i) => s.unwrap().get_relation(i).ok() == t.get_relation(i).ok(),
+                        None => true,
+                    };
+                    if index_kept {
+                        (st.replica_identity.clone(), st.replica_identity_index.clone())
+                    } else {
+                        (get_table_replica_identity(), None)
+                    }
+                }
+                None => (get_table_replica_identity(), None),
+            };
+            if source != (tt.replica_identity.clone(), tt.replica_identity_index.clone()) {
+                changes.push(AlterTableReplicaIdentityChange::new(tt));
+            }
+        }
+    }
+
+    return changes;
+}
+
 fn diff_column_create(t: &Column) -> Changeset {
     let mut changes = Changeset::new();
 
     changes.push(AddColumnChange::new(t));
+    changes.append(&mut diff_column_settings_create(t));
+    changes.append(&mut diff_comment(
+        CommentTarget::column(t),
+        &get_default_description(),
+        &t.comment,
+    ));
+    changes.append(&mut diff_grants(
+        GrantTarget::column(t),
+        &[],
+        &[],
+        &t.grants,
+        &t.revokes,
+    ));
+
+    return changes;
+}
+
+// storage and statistics can't be given when adding a column
+fn diff_column_settings_create(t: &Column) -> Changeset {
+    let mut changes = Changeset::new();
+
+    if t.storage.is_some() {
+        changes.push(AlterColumnSetStorageChange::new(t));
+    }
+    if t.statistics.is_some() {
+        changes.push(AlterColumnSetStatisticsChange::new(t));
+    }
 
     return changes;
 }
@@ -216,15 +908,65 @@ fn diff_column_create(t: &Column) -> Changeset {
 fn diff_column_update(s: &Column, t: &Column) -> Changeset {
     let mut changes = Changeset::new();
 
-    if s.data_type != t.data_type {
-        changes.push(AlterColumnSetDataTypeChange::new(t));
+    // a column can only be made generated, or given another expression, by
+    // recreating it
+    if t.generated.is_some() && !same_optional_expression(&s.generated, &t.generated) {
+        changes.append(&mut diff_column_drop(s));
+        changes.append(&mut diff_column_create(t));
+        return changes;
+    }
+    if s.generated.is_some() && t.generated.is_none() {
+        changes.push(AlterColumnDropExpressionChange::new(s));
+    }
+    if s.identity.is_some() && t.identity.is_none() {
+        changes.push(AlterColumnDropIdentityChange::new(s));
+    }
+
+    if !same_type(&s.data_type, &t.data_type) || s.collation != t.collation {
+        let mut change = AlterColumnSetDataTypeChange::new(t);
+        // a conversion only goes with the type change it was written for
+        let converts = !same_type(&s.data_type, &t.data_type)
+            && t.using_from
+                .as_ref()
+                .is_some_and(|from| same_type(from, &s.data_type));
+        if !converts {
+            change.using = None;
+            change.revert_using = None;
+        }
+        changes.push(change);
     }
-    if s.default != t.default {
+    if !same_optional_expression(&s.default, &t.default) {
         changes.push(AlterColumnSetDefaultChange::new(t));
     }
     if s.not_null != t.not_null {
         changes.push(AlterColumnSetNotNullChange::new(t));
     }
+    if s.storage != t.storage {
+        changes.push(AlterColumnSetStorageChange::new(t));
+    }
+    if s.compression != t.compression {
+        changes.push(AlterColumnSetCompressionChange::new(t));
+    }
+    if s.statistics != t.statistics {
+        changes.push(AlterColumnSetStatisticsChange::new(t));
+    }
+    match (&s.identity, &t.identity) {
+        (None, Some(_)) => changes.push(AlterColumnAddIdentityChange::new(t)),
+        (Some(si), Some(ti)) if si != ti => changes.push(AlterColumnSetIdentityChange::new(t)),
+        _ => {}
+    }
+    changes.append(&mut diff_comment(
+        CommentTarget::column(t),
+        &s.comment,
+        &t.comment,
+    ));
+    changes.append(&mut diff_grants(
+        GrantTarget::column(t),
+        &s.grants,
+        &s.revokes,
+        &t.grants,
+        &t.revokes,
+    ));
 
     return changes;
 }
@@ -243,6 +985,7 @@ fn diff_constraint_create(t: &Constraint) -> Changeset {
         Constraint::Unique(t) => diff_unique_create(t),
         Constraint::ForeignKey(t) => diff_foreign_key_create(t),
         Constraint::Check(t) => diff_check_create(t),
+        Constraint::Exclusion(t) => diff_exclusion_create(t),
     }
 }
 fn diff_constraint_update(s: &Constraint, t: &Constraint) -> Changeset {
@@ -251,6 +994,7 @@ fn diff_constraint_update(s: &Constraint, t: &Constraint) -> Changeset {
         (Constraint::Unique(s), Constraint::Unique(t)) => diff_unique_update(s, t),
         (Constraint::ForeignKey(s), Constraint::ForeignKey(t)) => diff_foreign_key_update(s, t),
         (Constraint::Check(s), Constraint::Check(t)) => diff_check_update(s, t),
+        (Constraint::Exclusion(s), Constraint::Exclusion(t)) => diff_exclusion_update(s, t),
         _ => {
             let mut changes = Changeset::new();
             changes.append(&mut diff_constraint_drop(s));
@@ -265,12 +1009,18 @@ fn diff_constraint_drop(s: &Constraint) -> Changeset {
         Constraint::Unique(s) => diff_unique_drop(s),
         Constraint::ForeignKey(s) => diff_foreign_key_drop(s),
         Constraint::Check(s) => diff_check_drop(s),
+        Constraint::Exclusion(s) => diff_exclusion_drop(s),
     }
 }
 
 fn diff_primary_key_create(t: &PrimaryKey) -> Changeset {
     let mut changes = Changeset::new();
     changes.push(AddPrimaryKeyChange::new(t));
+    changes.append(&mut diff_comment(
+        CommentTarget::constraint(&t.schema_name, &t.table_name, &t.name),
+        &get_default_description(),
+        &t.comment,
+    ));
     return changes;
 }
 
@@ -278,11 +1028,18 @@ fn diff_primary_key_update(s: &PrimaryKey, t: &PrimaryKey) -> Changeset {
     let mut changes = Changeset::new();
 
     if s.columns != t.columns {
-        changes.push(DropPrimaryKeyChange::new(s));
-        changes.push(AddPrimaryKeyChange::new(t));
-    } else if s.deferrable != t.deferrable || s.initially_deferred != t.initially_deferred {
+        changes.append(&mut diff_primary_key_drop(s));
+        changes.append(&mut diff_primary_key_create(t));
+        return changes;
+    }
+    if s.deferrable != t.deferrable || s.initially_deferred != t.initially_deferred {
         changes.push(AlterPrimaryKeyChange::new(t));
     }
+    changes.append(&mut diff_comment(
+        CommentTarget::constraint(&t.schema_name, &t.table_name, &t.name),
+        &s.comment,
+        &t.comment,
+    ));
 
     return changes;
 }
@@ -290,11 +1047,7 @@ fn diff_primary_key_update(s: &PrimaryKey, t: &PrimaryKey) -> Changeset {
 fn diff_primary_key_drop(s: &PrimaryKey) -> Changeset {
     let mut changes = Changeset::new();
 
-    changes.push(DropPrimaryKeyChange {
-        schema: s.schema_name.clone(),
-        table: s.table_name.clone(),
-        constraint: s.name.clone(),
-    });
+    changes.push(DropPrimaryKeyChange::new(s));
 
     return changes;
 }
@@ -303,6 +1056,11 @@ fn diff_unique_create(t: &Unique) -> Changeset {
     let mut changes = Changeset::new();
 
     changes.push(AddUniqueChange::new(t));
+    changes.append(&mut diff_comment(
+        CommentTarget::constraint(&t.schema_name, &t.table_name, &t.name),
+        &get_default_description(),
+        &t.comment,
+    ));
 
     return changes;
 }
@@ -311,11 +1069,18 @@ fn diff_unique_update(s: &Unique, t: &Unique) -> Changeset {
     let mut changes = Changeset::new();
 
     if s.columns != t.columns {
-        changes.push(DropUniqueChange::new(s));
-        changes.push(AddUniqueChange::new(t));
-    } else if s.deferrable != t.deferrable || s.initially_deferred != t.initially_deferred {
+        changes.append(&mut diff_unique_drop(s));
+        changes.append(&mut diff_unique_create(t));
+        return changes;
+    }
+    if s.deferrable != t.deferrable || s.initially_deferred != t.initially_deferred {
         changes.push(AlterUniqueChange::new(t));
     }
+    changes.append(&mut diff_comment(
+        CommentTarget::constraint(&t.schema_name, &t.table_name, &t.name),
+        &s.comment,
+        &t.comment,
+    ));
 
     return changes;
 }
@@ -323,11 +1088,7 @@ fn diff_unique_update(s: &Unique, t: &Unique) -> Changeset {
 fn diff_unique_drop(s: &Unique) -> Changeset {
     let mut changes = Changeset::new();
 
-    changes.push(DropUniqueChange {
-        schema: s.schema_name.clone(),
-        table: s.table_name.clone(),
-        constraint: s.name.clone(),
-    });
+    changes.push(DropUniqueChange::new(s));
 
     return changes;
 }
@@ -335,6 +1096,11 @@ fn diff_unique_drop(s: &Unique) -> Changeset {
 fn diff_foreign_key_create(t: &ForeignKey) -> Changeset {
     let mut changes = Changeset::new();
     changes.push(AddForeignKeyChange::new(t));
+    changes.append(&mut diff_comment(
+        CommentTarget::constraint(&t.schema_name, &t.table_name, &t.name),
+        &get_default_description(),
+        &t.comment,
+    ));
     return changes;
 }
 
@@ -349,11 +1115,18 @@ fn diff_foreign_key_update(s: &ForeignKey, t: &ForeignKey) -> Changeset {
         || s.update_rule != t.update_rule
         || s.delete_rule != t.delete_rule
     {
-        changes.push(DropForeignKeyChange::new(s));
-        changes.push(AddForeignKeyChange::new(t));
-    } else if s.deferrable != t.deferrable || s.initially_deferred != t.initially_deferred {
+        changes.append(&mut diff_foreign_key_drop(s));
+        changes.append(&mut diff_foreign_key_create(t));
+        return changes;
+    }
+    if s.deferrable != t.deferrable || s.initially_deferred != t.initially_deferred {
         changes.push(AlterForeignKeyChange::new(t));
     }
+    changes.append(&mut diff_comment(
+        CommentTarget::constraint(&t.schema_name, &t.table_name, &t.name),
+        &s.comment,
+        &t.comment,
+    ));
 
     return changes;
 }
@@ -367,18 +1140,30 @@ fn diff_foreign_key_drop(s: &ForeignKey) -> Changeset {
 fn diff_check_create(t: &Check) -> Changeset {
     let mut changes = Changeset::new();
     changes.push(AddCheckChange::new(t));
+    changes.append(&mut diff_comment(
+        CommentTarget::constraint(&t.schema_name, &t.table_name, &t.name),
+        &get_default_description(),
+        &t.comment,
+    ));
     return changes;
 }
 
 fn diff_check_update(s: &Check, t: &Check) -> Changeset {
     let mut changes = Changeset::new();
 
-    if s.expression != t.expression {
-        changes.push(DropCheckChange::new(s));
-        changes.push(AddCheckChange::new(t));
-    } else if s.deferrable != t.deferrable || s.initially_deferred != t.initially_deferred {
+    if !same_expression(&s.expression, &t.expression) {
+        changes.append(&mut diff_check_drop(s));
+        changes.append(&mut diff_check_create(t));
+        return changes;
+    }
+    if s.deferrable != t.deferrable || s.initially_deferred != t.initially_deferred {
         changes.push(AlterCheckChange::new(t));
     }
+    changes.append(&mut diff_comment(
+        CommentTarget::constraint(&t.schema_name, &t.table_name, &t.name),
+        &s.comment,
+        &t.comment,
+    ));
 
     return changes;
 }
@@ -389,47 +1174,455 @@ fn diff_check_drop(s: &Check) -> Changeset {
     return changes;
 }
 
+fn diff_exclusion_create(t: &Exclusion) -> Changeset {
+    let mut changes = Changeset::new();
+    changes.push(AddExclusionChange::new(t));
+    changes.append(&mut diff_comment(
+        CommentTarget::constraint(&t.schema_name, &t.table_name, &t.name),
+        &get_default_description(),
+        &t.comment,
+    ));
+    return changes;
+}
+
+fn diff_exclusion_update(s: &Exclusion, t: &Exclusion) -> Changeset {
+    let mut changes = Changeset::new();
+
+    if s.method != t.method
+        || !same_exclusion_elements(&s.elements, &t.elements)
+        || !same_optional_expression(&s.predicate, &t.predicate)
+    {
+        changes.append(&mut diff_exclusion_drop(s));
+        changes.append(&mut diff_exclusion_create(t));
+        return changes;
+    }
+    if s.deferrable != t.deferrable || s.initially_deferred != t.initially_deferred {
+        changes.push(AlterExclusionChange::new(t));
+    }
+    changes.append(&mut diff_comment(
+        CommentTarget::constraint(&t.schema_name, &t.table_name, &t.name),
+        &s.comment,
+        &t.comment,
+    ));
+
+    return changes;
+}
+
+fn same_exclusion_elements(s: &[ExclusionElement], t: &[ExclusionElement]) -> bool {
+    s.len() == t.len()
+        && s.iter().zip(t).all(|(s, t)| {
+            same_expression(&s.expression, &t.expression) && s.operator == t.operator
+        })
+}
+
+fn diff_exclusion_drop(s: &Exclusion) -> Changeset {
+    let mut changes = Changeset::new();
+    changes.push(DropExclusionChange::new(s));
+    return changes;
+}
+
 fn diff_view_create(t: &View) -> Changeset {
     let mut changes = Changeset::new();
     changes.push(CreateViewChange::new(t));
+    changes.append(&mut diff_owner(OwnerTarget::view(t), &None, &t.owner));
+    changes.append(&mut diff_comment(
+        CommentTarget::view(t),
+        &get_default_description(),
+        &t.comment,
+    ));
     return changes;
 }
 
 fn diff_view_update(s: &View, t: &View) -> Changeset {
     let mut changes = Changeset::new();
 
-    if s.query != t.query {
-        changes.push(DropViewChange::new(s));
-        changes.push(CreateViewChange::new(t));
+    if view_needs_recreate(s, t) {
+        changes.append(&mut diff_view_drop(s));
+        changes.append(&mut diff_view_create(t));
+        return changes;
+    }
+    // views depending on this one stay in place when it can be replaced
+    if !same_expression(&s.query, &t.query) {
+        changes.push(ReplaceViewChange::new(t));
     }
 
+    changes.append(&mut diff_owner(OwnerTarget::view(t), &s.owner, &t.owner));
+    changes.append(&mut diff_comment(
+        CommentTarget::view(t),
+        &s.comment,
+        &t.comment,
+    ));
+
     return changes;
 }
 
+fn view_needs_recreate(s: &View, t: &View) -> bool {
+    !same_expression(&s.query, &t.query) && !keeps_output_columns(&s.query, &t.query)
+}
+
 fn diff_view_drop(s: &View) -> Changeset {
     let mut changes = Changeset::new();
     changes.push(DropViewChange::new(s));
     return changes;
 }
 
+fn diff_materialized_view_create(t: &MaterializedView) -> Changeset {
+    let mut changes = Changeset::new();
+    changes.push(CreateMaterializedViewChange::new(t));
+    changes.append(&mut diff_owner(
+        OwnerTarget::materialized_view(t),
+        &None,
+        &t.owner,
+    ));
+    changes.append(&mut diff_comment(
+        CommentTarget::materialized_view(t),
+        &get_default_description(),
+        &t.comment,
+    ));
+    return changes;
+}
+
+fn diff_materialized_view_update(s: &MaterializedView, t: &MaterializedView) -> Changeset {
+    let mut changes = Changeset::new();
+
+    if materialized_view_needs_recreate(s, t) {
+        changes.append(&mut diff_materialized_view_drop(s));
+        changes.append(&mut diff_materialized_view_create(t));
+        return changes;
+    }
+    if s.with_data != t.with_data {
+        changes.push(RefreshMaterializedViewChange::new(t));
+    }
+    changes.append(&mut diff_owner(
+        OwnerTarget::materialized_view(t),
+        &s.owner,
+        &t.owner,
+    ));
+    changes.append(&mut diff_comment(
+        CommentTarget::materialized_view(t),
+        &s.comment,
+        &t.comment,
+    ));
+
+    return changes;
+}
+
+fn diff_materialized_view_drop(s: &MaterializedView) -> Changeset {
+    let mut changes = Changeset::new();
+    changes.push(DropMaterializedViewChange::new(s));
+    return changes;
+}
+
+fn materialized_view_needs_recreate(s: &MaterializedView, t: &MaterializedView) -> bool {
+    !same_expression(&s.query, &t.query) || s.storage_parameters != t.storage_parameters
+}
+
+// Materialized views whose definition changed and which have to be dropped and
+// created again.
+fn collect_recreated_materialized_views<'a>(s: &'a Schema, t: &Schema) -> Vec<&'a str> {
+    let mut names = vec![];
+    for si in s.iter_relations() {
+        if let (Relation::MaterializedView(sv), Ok(Relation::MaterializedView(tv))) =
+            (si, t.get_relation(si.get_name()))
+        {
+            if materialized_view_needs_recreate(sv, tv) {
+                names.push(si.get_name());
+            }
+        }
+    }
+    names
+}
+
+// Indexes built on any of the given relations, in either schema.
+fn collect_indexes_on<'a>(s: &'a Schema, t: &'a Schema, tables: &[&str]) -> Vec<&'a str> {
+    let mut names: Vec<&str> = vec![];
+    for relation in s.iter_relations().chain(t.iter_relations()) {
+        if let Relation::Index(index) = relation {
+            if tables.contains(&index.table_name.as_str()) && !names.contains(&relation.get_name())
+            {
+                names.push(relation.get_name());
+            }
+        }
+    }
+    names
+}
+
+// Views reading a column whose type changes or which is dropped, along with
+// the views reading them, as `(schema, name)` pairs. PostgreSQL refuses to
+// touch such a column, so they are dropped before the change and created
+// again after it. Views which cannot be replaced in place are dropped and
+// created again the same way, along with the views reading them.
+fn collect_cascaded_views(s: &Database, t: &Database) -> Vec<(String, String)> {
+    let mut columns: Vec<(&str, &str, &str)> = vec![];
+    let mut views: Vec<(&str, &str)> = vec![];
+    for ss in s.iter_schemas() {
+        for si in ss.iter_relations() {
+            let ti = t.get_schema(&ss.name).and_then(|ts| ts.get_relation(si.get_name()));
+            let (st, tt) = match (si, ti) {
+                (Relation::Table(st), Ok(Relation::Table(tt))) => (st, tt),
+                (Relation::View(sv), Ok(Relation::View(tv))) => {
+                    if view_needs_recreate(sv, tv) {
+                        views.push((&ss.name, &sv.name));
+                    }
+                    continue;
+                }
+                (Relation::MaterializedView(sv), Ok(Relation::MaterializedView(tv))) => {
+                    if materialized_view_needs_recreate(sv, tv) {
+                        views.push((&ss.name, &sv.name));
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+            for sc in st.iter_columns() {
+                let changed = match tt.get_column(&sc.name) {
+                    Ok(tc) => {
+                        !same_type(&sc.data_type, &tc.data_type) || sc.collation != tc.collation
+                    }
+                    Err(_) => true,
+                };
+                if changed {
+                    columns.push((&ss.name, &st.name, &sc.name));
+                }
+            }
+        }
+    }
+
+    let mut cascaded: Vec<(String, String)> = views
+        .iter()
+        .map(|(schema, name)| (schema.to_string(), name.to_string()))
+        .collect();
+    for view in find_dependent_views(s, &columns, &views) {
+        if !cascaded.contains(&view) {
+            cascaded.push(view);
+        }
+    }
+
+    // views gone from the target are simply dropped
+    cascaded
+        .into_iter()
+        .filter(|(schema, name)| {
+            matches!(
+                (
+                    s.get_schema(schema).and_then(|ss| ss.get_relation(name)),
+                    t.get_schema(schema).and_then(|ts| ts.get_relation(name)),
+                ),
+                (Ok(Relation::View(_)), Ok(Relation::View(_)))
+                    | (Ok(Relation::MaterializedView(_)), Ok(Relation::MaterializedView(_)))
+            )
+        })
+        .collect()
+}
+
+// Dropping a view or a materialized view drops its indexes too, so they are
+// dropped before the view and created again after it.
+fn diff_view_recreate(
+    s: &Schema,
+    t: &Schema,
+    recreated: &[&str],
+    indexes: &[&str],
+) -> Changeset {
+    let mut changes = Changeset::new();
+
+    for name in indexes {
+        if let Ok(si) = s.get_relation(name) {
+            changes.append(&mut diff_relation_drop(si));
+        }
+    }
+    for name in recreated {
+        changes.append(&mut diff_relation_drop(s.get_relation(name).unwrap()));
+        changes.append(&mut diff_relation_create(t.get_relation(name).unwrap()));
+    }
+    for name in indexes {
+        if let Ok(ti) = t.get_relation(name) {
+            changes.append(&mut diff_relation_create(ti));
+        }
+    }
+
+    return changes;
+}
+
+// Refresh hooks run once a materialized view and its indexes exist.
+fn diff_materialized_view_refresh(s: Option<&Schema>, t: &Schema) -> Changeset {
+    let mut changes = Changeset::new();
+
+    for ti in t.iter_relations() {
+        if let Relation::MaterializedView(tv) = ti {
+            if !tv.refresh || !tv.with_data {
+                continue;
+            }
+            let created = match s.map(|s| s.get_relation(ti.get_name())) {
+                Some(Ok(Relation::MaterializedView(sv))) => {
+                    materialized_view_needs_recreate(sv, tv)
+                }
+                _ => true,
+            };
+            if created {
+                changes.push(RefreshMaterializedViewChange::new(tv));
+            }
+        }
+    }
+
+    return changes;
+}
+
+// Comments are set once the object they describe exists, `s` being the
+// comment it has at that point.
+fn diff_comment(target: CommentTarget, s: &str, t: &str) -> Changeset {
+    let mut changes = Changeset::new();
+    if s != t {
+        changes.push(CommentChange::new(target, t));
+    }
+    return changes;
+}
+
+// Objects without an owner are left to whoever they belong to.
+fn diff_owner(target: OwnerTarget, s: &Option<String>, t: &Option<String>) -> Changeset {
+    let mut changes = Changeset::new();
+    if t.is_some() && s != t {
+        changes.push(OwnerChange::new(target, t.as_ref()));
+    }
+    return changes;
+}
+
+// Privileges are granted once the object they apply to exists, `s` being the
+// privileges it holds at that point. Revokes come first, one statement per
+// grantee either way. The privileges an object starts out with are revoked,
+// and given back, the same way.
+fn diff_grants(
+    target: GrantTarget,
+    s: &[Grant],
+    s_revokes: &[Grant],
+    t: &[Grant],
+    t_revokes: &[Grant],
+) -> Changeset {
+    let mut changes = Changeset::new();
+    let revokes = merge_missing_grants(
+        collect_missing_grants(s, t),
+        collect_missing_grants(t_revokes, s_revokes),
+    );
+    for (grantee, privileges) in revokes {
+        changes.push(RevokeChange::new(target.clone(), grantee, privileges));
+    }
+    let grants = merge_missing_grants(
+        collect_missing_grants(t, s),
+        collect_missing_grants(s_revokes, t_revokes),
+    );
+    for (grantee, privileges) in grants {
+        changes.push(GrantChange::new(target.clone(), grantee, privileges));
+    }
+    return changes;
+}
+
+fn merge_missing_grants<'a>(
+    mut a: BTreeMap<&'a str, Vec<String>>,
+    b: BTreeMap<&'a str, Vec<String>>,
+) -> BTreeMap<&'a str, Vec<String>> {
+    for (grantee, privileges) in b {
+        let merged = a.entry(grantee).or_default();
+        merged.extend(privileges);
+        merged.sort();
+    }
+    a
+}
+
+// Privileges in `a` but not in `b`, by grantee.
+fn collect_missing_grants<'a>(a: &'a [Grant], b: &[Grant]) -> BTreeMap<&'a str, Vec<String>> {
+    let mut missing: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for grant in a {
+        if !b.contains(grant) {
+            missing
+                .entry(&grant.grantee)
+                .or_default()
+                .push(grant.privilege.clone());
+        }
+    }
+    missing
+}
+
+fn diff_default_grants(schema: &str, s: &[DefaultGrant], t: &[DefaultGrant]) -> Changeset {
+    let mut changes = Changeset::new();
+    for ((object_type, grantee), privileges) in collect_missing_default_grants(s, t) {
+        changes.push(RevokeDefaultPrivilegesChange::new(
+            schema,
+            object_type,
+            grantee,
+            privileges,
+        ));
+    }
+    for ((object_type, grantee), privileges) in collect_missing_default_grants(t, s) {
+        changes.push(GrantDefaultPrivilegesChange::new(
+            schema,
+            object_type,
+            grantee,
+            privileges,
+        ));
+    }
+    return changes;
+}
+
+fn collect_missing_default_grants<'a>(
+    a: &'a [DefaultGrant],
+    b: &[DefaultGrant],
+) -> BTreeMap<(&'a str, &'a str), Vec<String>> {
+    let mut missing: BTreeMap<(&str, &str), Vec<String>> = BTreeMap::new();
+    for grant in a {
+        if !b.contains(grant) {
+            missing
+                .entry((&grant.object_type, &grant.grantee))
+                .or_default()
+                .push(grant.privilege.clone());
+        }
+    }
+    missing
+}
+
 fn diff_function_create(t: &Function) -> Changeset {
     let mut changes = Changeset::new();
     changes.push(CreateFunctionChange::new(t));
+    changes.append(&mut diff_owner(OwnerTarget::function(t), &None, &t.owner));
+    changes.append(&mut diff_comment(
+        CommentTarget::function(t),
+        &get_default_description(),
+        &t.comment,
+    ));
+    changes.append(&mut diff_grants(
+        GrantTarget::function(t),
+        &[],
+        &[],
+        &t.grants,
+        &t.revokes,
+    ));
     return changes;
 }
 
 fn diff_function_update(s: &Function, t: &Function) -> Changeset {
     let mut changes = Changeset::new();
 
-    if s.body != t.body
-        || s.language != t.language
-        || s.returns != t.returns
-        || s.volatility != t.volatility
-    {
-        changes.push(DropFunctionChange::new(s));
-        changes.push(CreateFunctionChange::new(t));
+    // the return type is the only thing a replacement cannot change
+    if !same_type(&s.returns, &t.returns) {
+        changes.append(&mut diff_function_drop(s));
+        changes.append(&mut diff_function_create(t));
+        return changes;
+    }
+    if s.body != t.body || s.language != t.language || s.volatility != t.volatility {
+        changes.push(ReplaceFunctionChange::new(t));
     }
 
+    changes.append(&mut diff_owner(OwnerTarget::function(t), &s.owner, &t.owner));
+    changes.append(&mut diff_comment(
+        CommentTarget::function(t),
+        &s.comment,
+        &t.comment,
+    ));
+    changes.append(&mut diff_grants(
+        GrantTarget::function(t),
+        &s.grants,
+        &s.revokes,
+        &t.grants,
+        &t.revokes,
+    ));
+
     return changes;
 }
 
@@ -439,20 +1632,120 @@ fn diff_function_drop(s: &Function) -> Changeset {
     return changes;
 }
 
+fn diff_procedure_create(t: &Procedure) -> Changeset {
+    let mut changes = Changeset::new();
+    changes.push(CreateProcedureChange::new(t));
+    changes.append(&mut diff_owner(OwnerTarget::procedure(t), &None, &t.owner));
+    return changes;
+}
+
+fn diff_procedure_update(s: &Procedure, t: &Procedure) -> Changeset {
+    let mut changes = Changeset::new();
+
+    if !same_arguments(&s.arguments, &t.arguments) {
+        changes.append(&mut diff_procedure_drop(s));
+        changes.append(&mut diff_procedure_create(t));
+        return changes;
+    }
+    if s.body != t.body || s.language != t.language {
+        changes.push(ReplaceProcedureChange::new(t));
+    }
+
+    changes.append(&mut diff_owner(OwnerTarget::procedure(t), &s.owner, &t.owner));
+
+    return changes;
+}
+
+fn diff_procedure_drop(s: &Procedure) -> Changeset {
+    let mut changes = Changeset::new();
+    changes.push(DropProcedureChange::new(s));
+    return changes;
+}
+
 fn diff_index_create(t: &Index) -> Changeset {
     let mut changes = Changeset::new();
     changes.push(CreateIndexChange::new(t));
+    changes.append(&mut diff_comment(
+        CommentTarget::index(t),
+        &get_default_description(),
+        &t.comment,
+    ));
     return changes;
 }
 
+// Indexes only going by another name in the target, paired with that name.
+fn collect_renamed_indexes<'a>(
+    s: &'a Schema,
+    t: &'a Schema,
+    sandwiched: &[&str],
+) -> Vec<(&'a Index, &'a str)> {
+    let mut renamed: Vec<(&Index, &str)> = vec![];
+    let created: Vec<&Index> = t
+        .iter_relations()
+        .filter_map(|r| r.as_index().ok())
+        .filter(|ti| !s.has_relation(&ti.name) && !sandwiched.contains(&ti.name.as_str()))
+        .collect();
+    let dropped = s
+        .iter_relations()
+        .filter_map(|r| r.as_index().ok())
+        .filter(|si| !t.has_relation(&si.name) && !sandwiched.contains(&si.name.as_str()));
+
+    for si in dropped {
+        let found = created.iter().copied().find(|ti| {
+            !renamed.iter().any(|(_, to)| *to == ti.name)
+                && Index {
+                    name: ti.name.clone(),
+                    ..si.clone()
+                } == **ti
+        });
+        if let Some(ti) = found {
+            renamed.push((si, &ti.name));
+        }
+    }
+    renamed
+}
+
+fn same_index_keys(s: &[IndexKey], t: &[IndexKey]) -> bool {
+    s.len() == t.len()
+        && s.iter().zip(t).all(|(s, t)| {
+            same_expression(&s.expression, &t.expression)
+                && s.collation == t.collation
+                && s.opclass == t.opclass
+                && s.order == t.order
+                && s.nulls == t.nulls
+        })
+}
+
 fn diff_index_update(s: &Index, t: &Index) -> Changeset {
     let mut changes = Changeset::new();
 
-    if s.key_expressions != t.key_expressions {
-        changes.push(DropIndexChange::new(s));
-        changes.push(CreateIndexChange::new(t));
+    if s.table_name != t.table_name
+        || s.unique != t.unique
+        || s.nulls_not_distinct != t.nulls_not_distinct
+        || s.method != t.method
+        || !same_index_keys(&s.keys, &t.keys)
+        || s.include != t.include
+        || !same_optional_expression(&s.predicate, &t.predicate)
+    {
+        changes.append(&mut diff_index_drop(s));
+        changes.append(&mut diff_index_create(t));
+        return changes;
+    }
+
+    if s.tablespace != t.tablespace {
+        changes.push(AlterIndexSetTablespaceChange::new(t));
     }
 
+    let parameters = AlterIndexSetStorageParametersChange::new(s, t);
+    if !parameters.is_empty() {
+        changes.push(parameters);
+    }
+    changes.append(&mut diff_comment(
+        CommentTarget::index(t),
+        &s.comment,
+        &t.comment,
+    ));
+
     return changes;
 }
 
@@ -465,25 +1758,51 @@ fn diff_index_drop(s: &Index) -> Changeset {
 fn diff_sequence_create(t: &Sequence) -> Changeset {
     let mut changes = Changeset::new();
     changes.push(CreateSequenceChange::new(t));
+    // the owning column may only come afterwards
+    if t.owned_by_table.is_some() {
+        changes.push(AlterSequenceChange::owned_by(t));
+    }
+    changes.append(&mut diff_owner(OwnerTarget::sequence(t), &None, &t.owner));
+    changes.append(&mut diff_comment(
+        CommentTarget::sequence(t),
+        &get_default_description(),
+        &t.comment,
+    ));
+    changes.append(&mut diff_grants(
+        GrantTarget::sequence(t),
+        &[],
+        &[],
+        &t.grants,
+        &t.revokes,
+    ));
     return changes;
 }
 
 fn diff_sequence_update(s: &Sequence, t: &Sequence) -> Changeset {
     let mut changes = Changeset::new();
 
-    if s.data_type != t.data_type
-        || s.increment != t.increment
-        || s.min_value != t.min_value
-        || s.max_value != t.max_value
-        || s.start != t.start
-        || s.cache != t.cache
-        || s.cycle != t.cycle
-        || s.owned_by_table != t.owned_by_table
-        || s.owned_by_column != t.owned_by_column
-    {
-        changes.push(DropSequenceChange::new(s));
-        changes.push(CreateSequenceChange::new(t));
+    // altered in place, so that the sequence keeps counting from where it is
+    let mut alter = AlterSequenceChange::new(s, t);
+    if same_type(&s.data_type, &t.data_type) {
+        alter.data_type = None;
     }
+    if !alter.is_empty() {
+        changes.push(alter);
+    }
+
+    changes.append(&mut diff_owner(OwnerTarget::sequence(t), &s.owner, &t.owner));
+    changes.append(&mut diff_comment(
+        CommentTarget::sequence(t),
+        &s.comment,
+        &t.comment,
+    ));
+    changes.append(&mut diff_grants(
+        GrantTarget::sequence(t),
+        &s.grants,
+        &s.revokes,
+        &t.grants,
+        &t.revokes,
+    ));
 
     return changes;
 }
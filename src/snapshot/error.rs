@@ -13,6 +13,10 @@ pub enum SnapshotError {
         expected: &'static str,
         actual: &'static str,
     },
+    ChangeNotReversible {
+        path: Vec<String>,
+        change: &'static str,
+    },
 }
 
 impl SnapshotError {
@@ -30,6 +34,36 @@ impl SnapshotError {
         Self::object_already_exists(vec![schema.to_string()], "schema")
     }
 
+    pub fn extension_not_found(extension: &str) -> Self {
+        Self::object_not_found(vec![extension.to_string()], "extension")
+    }
+    pub fn extension_already_exists(extension: &str) -> Self {
+        Self::object_already_exists(vec![extension.to_string()], "extension")
+    }
+
+    pub fn publication_not_found(publication: &str) -> Self {
+        Self::object_not_found(vec![publication.to_string()], "publication")
+    }
+    pub fn publication_already_exists(publication: &str) -> Self {
+        Self::object_already_exists(vec![publication.to_string()], "publication")
+    }
+    pub fn publication_table_not_found(publication: &str, schema: &str, table: &str) -> Self {
+        let path = vec![
+            publication.to_string(),
+            schema.to_string(),
+            table.to_string(),
+        ];
+        Self::object_not_found(path, "publication table")
+    }
+    pub fn publication_table_already_exists(publication: &str, schema: &str, table: &str) -> Self {
+        let path = vec![
+            publication.to_string(),
+            schema.to_string(),
+            table.to_string(),
+        ];
+        Self::object_already_exists(path, "publication table")
+    }
+
     pub fn relation_not_found(schema: &str, relation: &str) -> Self {
         Self::object_not_found(vec![schema.to_string(), relation.to_string()], "relation")
     }
@@ -44,6 +78,13 @@ impl SnapshotError {
         Self::object_already_exists(vec![schema.to_string(), function.to_string()], "function")
     }
 
+    pub fn procedure_not_found(schema: &str, procedure: &str) -> Self {
+        Self::object_not_found(vec![schema.to_string(), procedure.to_string()], "procedure")
+    }
+    pub fn procedure_already_exists(schema: &str, procedure: &str) -> Self {
+        Self::object_already_exists(vec![schema.to_string(), procedure.to_string()], "procedure")
+    }
+
     pub fn relation_has_unexpected_type(
         schema: &str,
         relation: &str,
@@ -83,6 +124,71 @@ impl SnapshotError {
         Self::object_already_exists(path, "constraint")
     }
 
+    pub fn partition_not_found(schema: &str, relation: &str, partition: &str) -> Self {
+        let path = vec![
+            schema.to_string(),
+            relation.to_string(),
+            partition.to_string(),
+        ];
+        Self::object_not_found(path, "partition")
+    }
+    pub fn partition_already_exists(schema: &str, relation: &str, partition: &str) -> Self {
+        let path = vec![
+            schema.to_string(),
+            relation.to_string(),
+            partition.to_string(),
+        ];
+        Self::object_already_exists(path, "partition")
+    }
+
+    pub fn inheritance_not_found(schema: &str, relation: &str, parent: &str) -> Self {
+        let path = vec![schema.to_string(), relation.to_string(), parent.to_string()];
+        Self::object_not_found(path, "inheritance")
+    }
+    pub fn inheritance_already_exists(schema: &str, relation: &str, parent: &str) -> Self {
+        let path = vec![schema.to_string(), relation.to_string(), parent.to_string()];
+        Self::object_already_exists(path, "inheritance")
+    }
+
+    pub fn policy_not_found(schema: &str, relation: &str, policy: &str) -> Self {
+        let path = vec![schema.to_string(), relation.to_string(), policy.to_string()];
+        Self::object_not_found(path, "policy")
+    }
+    pub fn policy_already_exists(schema: &str, relation: &str, policy: &str) -> Self {
+        let path = vec![schema.to_string(), relation.to_string(), policy.to_string()];
+        Self::object_already_exists(path, "policy")
+    }
+
+    pub fn statistics_not_found(schema: &str, relation: &str, statistics: &str) -> Self {
+        let path = vec![
+            schema.to_string(),
+            relation.to_string(),
+            statistics.to_string(),
+        ];
+        Self::object_not_found(path, "statistics")
+    }
+    pub fn statistics_already_exists(schema: &str, relation: &str, statistics: &str) -> Self {
+        let path = vec![
+            schema.to_string(),
+            relation.to_string(),
+            statistics.to_string(),
+        ];
+        Self::object_already_exists(path, "statistics")
+    }
+
+    pub fn privilege_not_granted(object: &[String], grantee: &str, privilege: &str) -> Self {
+        let mut path = object.to_vec();
+        path.push(grantee.to_string());
+        path.push(privilege.to_string());
+        Self::object_not_found(path, "privilege")
+    }
+    pub fn privilege_already_granted(object: &[String], grantee: &str, privilege: &str) -> Self {
+        let mut path = object.to_vec();
+        path.push(grantee.to_string());
+        path.push(privilege.to_string());
+        Self::object_already_exists(path, "privilege")
+    }
+
     pub fn constraint_has_unexpected_type(
         schema: &str,
         table: &str,
@@ -100,6 +206,13 @@ impl SnapshotError {
             actual: actual,
         }
     }
+
+    pub fn column_expression_not_restorable(schema: &str, table: &str, column: &str) -> Self {
+        Self::ChangeNotReversible {
+            path: vec![schema.to_string(), table.to_string(), column.to_string()],
+            change: "drop expression",
+        }
+    }
 }
 
 impl std::fmt::Display for SnapshotError {
@@ -1,9 +1,13 @@
 pub mod changes;
+mod dependency;
 mod diff;
 mod error;
+mod normalize;
+mod similarity;
 mod types;
 pub use error::SnapshotError;
 pub use types::*;
 pub mod defaults;
 pub use diff::*;
+pub use similarity::*;
 mod tests;
@@ -0,0 +1,456 @@
+//! Canonical forms of what definitions and the catalog spell differently, so
+//! that `integer` and `int4`, or `'x'` and `'x'::text`, compare as equal.
+//! Derived offline from the way the catalog renders them back.
+
+/// Whether two data types name the same type.
+pub fn same_type(a: &str, b: &str) -> bool {
+    a == b || normalize_type(a) == normalize_type(b)
+}
+
+/// Whether two expressions, such as defaults or checks, read the same.
+pub fn same_expression(a: &str, b: &str) -> bool {
+    a == b || normalize_expression(a) == normalize_expression(b)
+}
+
+/// Whether two lists of expressions read the same, item by item.
+pub fn same_expressions(a: &[String], b: &[String]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| same_expression(a, b))
+}
+
+pub fn same_optional_expression(a: &Option<String>, b: &Option<String>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => same_expression(a, b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Whether two argument lists of a routine declare the same arguments.
+pub fn same_arguments(a: &str, b: &str) -> bool {
+    a == b || normalize_arguments(a) == normalize_arguments(b)
+}
+
+/// Rewrites an argument list the way `pg_get_function_arguments` reads it,
+/// less the `IN` mode arguments have unless told otherwise, so that `a int, b
+/// text = 'x'` and `IN a integer, IN b text DEFAULT 'x'::text` compare as
+/// equal. Type modifiers are dropped, as the catalog does not keep them.
+pub fn normalize_arguments(arguments: &str) -> String {
+    let mut items: Vec<Vec<String>> = vec![vec![]];
+    let mut depth = 0;
+    for token in tokenize(arguments) {
+        match token.as_str() {
+            "(" => depth += 1,
+            ")" => depth -= 1,
+            "," if depth == 0 => {
+                items.push(vec![]);
+                continue;
+            }
+            _ => {}
+        }
+        items.last_mut().unwrap().push(token);
+    }
+
+    items
+        .iter()
+        .filter(|item| !item.is_empty())
+        .map(|item| normalize_argument(item))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn normalize_argument(tokens: &[String]) -> String {
+    let (mut declaration, default) = match tokens.iter().position(|t| t == "default" || t == "=") {
+        Some(i) => (&tokens[..i], Some(&tokens[i + 1..])),
+        None => (tokens, None),
+    };
+
+    let mut words: Vec<String> = vec![];
+    if declaration.len() > 1
+        && matches!(declaration[0].as_str(), "in" | "out" | "inout" | "variadic")
+    {
+        if declaration[0] != "in" {
+            words.push(declaration[0].clone());
+        }
+        declaration = &declaration[1..];
+    }
+
+    let mut depth = 0;
+    let mut written: Vec<&str> = vec![];
+    for token in declaration {
+        match token.as_str() {
+            "(" => depth += 1,
+            ")" => depth -= 1,
+            _ if depth == 0 => written.push(token),
+            _ => {}
+        }
+    }
+    // a name comes first unless the whole declaration is a type
+    let written = written.join(" ");
+    let data_type = normalize_type(&written);
+    match written.split_once(' ') {
+        Some((name, rest)) if data_type.contains(' ') => {
+            words.push(name.to_string());
+            words.push(normalize_type(rest));
+        }
+        _ => words.push(data_type),
+    }
+
+    if let Some(default) = default {
+        words.push("default".to_string());
+        words.push(normalize_expression(&default.join(" ")));
+    }
+    words.join(" ")
+}
+
+/// Rewrites a data type the way `pg_catalog` names it, e.g. `character
+/// varying(20)` into `varchar(20)` and `integer[]` into `int4[]`.
+pub fn normalize_type(data_type: &str) -> String {
+    let data_type = data_type.trim();
+    // functions returning sets name the type of their rows
+    if let Some(row_type) = data_type
+        .get(..6)
+        .filter(|prefix| prefix.eq_ignore_ascii_case("setof "))
+        .map(|_| &data_type[6..])
+    {
+        return format!("setof {}", normalize_type(row_type));
+    }
+    // quoted names are taken as they are
+    if data_type.contains('"') {
+        return data_type.to_string();
+    }
+
+    let mut rest = data_type.to_lowercase();
+    let mut dimensions = 0;
+    while let Some(stripped) = rest.trim_end().strip_suffix(']') {
+        let Some(open) = stripped.rfind('[') else {
+            break;
+        };
+        rest = stripped[..open].to_string();
+        dimensions += 1;
+    }
+
+    let mut modifier: Option<String> = None;
+    if let (Some(open), Some(close)) = (rest.find('('), rest.find(')')) {
+        if open < close {
+            modifier = Some(rest[open + 1..close].replace(' ', ""));
+            rest = format!("{} {}", &rest[..open], &rest[close + 1..]);
+        }
+    }
+
+    let words: Vec<&str> = rest.split_whitespace().collect();
+    let name = words.join(" ");
+    let name = name.strip_prefix("pg_catalog.").unwrap_or(&name);
+    let base = match name {
+        "int" | "integer" => "int4",
+        "smallint" => "int2",
+        "bigint" => "int8",
+        "real" => "float4",
+        "double precision" => "float8",
+        "float" => match modifier.take().and_then(|p| p.parse::<u32>().ok()) {
+            Some(p) if p <= 24 => "float4",
+            _ => "float8",
+        },
+        "boolean" => "bool",
+        "decimal" => "numeric",
+        "character varying" => "varchar",
+        "character" | "char" => {
+            modifier.get_or_insert_with(|| "1".to_string());
+            "bpchar"
+        }
+        "bit" => {
+            modifier.get_or_insert_with(|| "1".to_string());
+            "bit"
+        }
+        "bit varying" => "varbit",
+        "timestamp with time zone" => "timestamptz",
+        "timestamp without time zone" => "timestamp",
+        "time with time zone" => "timetz",
+        "time without time zone" => "time",
+        _ => name,
+    };
+
+    format!(
+        "{}{}{}",
+        base,
+        match modifier {
+            Some(modifier) => format!("({})", modifier),
+            None => "".to_string(),
+        },
+        "[]".repeat(dimensions),
+    )
+}
+
+/// Rewrites an expression or a query into a canonical token stream. Spacing,
+/// letter case outside of quotes, needless identifier quotes, enclosing
+/// parentheses, a trailing semicolon and the casts the catalog adds to
+/// literals make no difference.
+pub fn normalize_expression(expression: &str) -> String {
+    let mut tokens = tokenize(expression);
+
+    while tokens.last().map(String::as_str) == Some(";") {
+        tokens.pop();
+    }
+    strip_literal_casts(&mut tokens);
+    while encloses(&tokens) {
+        tokens.remove(0);
+        tokens.pop();
+    }
+
+    tokens.join(" ")
+}
+
+fn tokenize(s: &str) -> Vec<String> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut tokens: Vec<String> = vec![];
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        if c == '\'' || c == '"' {
+            // quotes within are doubled
+            i += 1;
+            while i < chars.len() {
+                if chars[i] == c {
+                    if chars.get(i + 1) == Some(&c) {
+                        i += 2;
+                        continue;
+                    }
+                    break;
+                }
+                i += 1;
+            }
+            i = (i + 1).min(chars.len());
+            let token: String = chars[start..i].iter().collect();
+            tokens.push(if c == '"' { unquote(token) } else { token });
+            continue;
+        }
+
+        if c == '$' {
+            let mut j = i + 1;
+            while j < chars.len() && (chars[j].is_alphanumeric() || chars[j] == '_') {
+                j += 1;
+            }
+            // `$1` is a parameter, `$$` and `$tag$` open a dollar quote
+            let parameter = chars.get(i + 1).is_some_and(|c| c.is_ascii_digit());
+            if !parameter && chars.get(j) == Some(&'$') {
+                let tag: String = chars[i..=j].iter().collect();
+                let body: String = chars[j + 1..].iter().collect();
+                let end = match body.find(&tag) {
+                    Some(end) => j + 1 + body[..end].chars().count() + tag.chars().count(),
+                    None => chars.len(),
+                };
+                tokens.push(chars[start..end].iter().collect());
+                i = end;
+                continue;
+            }
+        }
+
+        if c.is_alphanumeric() || c == '_' || c == '$' {
+            let number = c.is_ascii_digit();
+            while i < chars.len()
+                && (chars[i].is_alphanumeric()
+                    || chars[i] == '_'
+                    || chars[i] == '$'
+                    || number && chars[i] == '.')
+            {
+                i += 1;
+            }
+            tokens.push(chars[start..i].iter().collect::<String>().to_lowercase());
+            continue;
+        }
+
+        if c == ':' {
+            while i < chars.len() && chars[i] == ':' {
+                i += 1;
+            }
+        } else if "+-*/<>=~!@#%^&|`?".contains(c) {
+            while i < chars.len() && "+-*/<>=~!@#%^&|`?".contains(chars[i]) {
+                i += 1;
+            }
+        } else {
+            i += 1;
+        }
+        tokens.push(chars[start..i].iter().collect());
+    }
+
+    tokens
+}
+
+/// Drops the quotes of identifiers that read the same without them.
+fn unquote(token: String) -> String {
+    if token.len() < 3 || !token.ends_with('"') {
+        return token;
+    }
+    let name = &token[1..token.len() - 1];
+    let plain = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase() || c == '_')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if plain {
+        name.to_string()
+    } else {
+        token
+    }
+}
+
+/// Drops the casts the catalog puts on literals of the column type, as in
+/// `'x'::text` or `'-1'::integer`.
+fn strip_literal_casts(tokens: &mut Vec<String>) {
+    let mut i = 0;
+    while i + 2 < tokens.len() {
+        let literal = &tokens[i];
+        if literal.len() < 2
+            || !literal.starts_with('\'')
+            || !literal.ends_with('\'')
+            || tokens[i + 1] != "::"
+        {
+            i += 1;
+            continue;
+        }
+        let literal = tokens[i][1..tokens[i].len() - 1].to_string();
+        let cast = &tokens[i + 2..];
+
+        let length = if cast.len() > 1 && cast[0] == "character" && cast[1] == "varying" {
+            2
+        } else if matches!(cast[0].as_str(), "text" | "varchar" | "bpchar") {
+            1
+        } else {
+            0
+        };
+        if length > 0 {
+            tokens.drain(i + 1..i + 1 + 1 + length);
+            i += 1;
+            continue;
+        }
+
+        let numeric = matches!(
+            cast[0].as_str(),
+            "integer" | "int4" | "bigint" | "int8" | "smallint" | "int2" | "numeric"
+        );
+        if numeric && literal.parse::<f64>().is_ok() {
+            let mut number = tokenize(&literal);
+            let count = number.len();
+            tokens.splice(i..i + 3, number.drain(..));
+            i += count;
+            continue;
+        }
+        i += 1;
+    }
+}
+
+/// Whether the first and the last token are parentheses matching each other.
+fn encloses(tokens: &[String]) -> bool {
+    if tokens.len() < 2 || tokens[0] != "(" || tokens[tokens.len() - 1] != ")" {
+        return false;
+    }
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        match token.as_str() {
+            "(" => depth += 1,
+            ")" => depth -= 1,
+            _ => {}
+        }
+        if depth == 0 && i < tokens.len() - 1 {
+            return false;
+        }
+    }
+    true
+}
+
+/// Whether `t` lists the same output columns as `s` first, which PostgreSQL
+/// requires for `CREATE OR REPLACE VIEW`. The select lists are compared as
+/// written and the rows have to come from the same place, only the `WHERE`
+/// clause may change, so a doubt leaves the view to be recreated. Views
+/// reading a column whose type changes are recreated anyway.
+pub fn keeps_output_columns(s: &str, t: &str) -> bool {
+    match (select_list(s), select_list(t)) {
+        (Some(sl), Some(tl)) => {
+            tl.starts_with(&sl)
+                && row_sources(s).is_some_and(|sources| row_sources(t) == Some(sources))
+        }
+        _ => false,
+    }
+}
+
+/// The top level tokens of a query but its select list and its `WHERE`
+/// clause, or `None` when it combines several queries.
+fn row_sources(query: &str) -> Option<Vec<String>> {
+    let mut tokens: Vec<String> = vec![];
+    let mut depth = 0;
+    let mut skipping = false;
+
+    for token in tokenize(query) {
+        let top = depth == 0;
+        match token.as_str() {
+            "(" => depth += 1,
+            ")" => depth -= 1,
+            _ => {}
+        }
+        if top {
+            match token.as_str() {
+                "union" | "intersect" | "except" => return None,
+                "select" | "where" => skipping = true,
+                "from" | "group" | "having" | "window" | "order" | "limit" | "offset" | "fetch"
+                | "for" => skipping = false,
+                ";" => continue,
+                _ => {}
+            }
+        }
+        if !skipping {
+            tokens.push(token);
+        }
+    }
+    Some(tokens)
+}
+
+/// The top level select list of a query, one canonical item per column, or
+/// `None` when it cannot tell the columns, as with `*`.
+fn select_list(query: &str) -> Option<Vec<String>> {
+    let mut items: Vec<Vec<String>> = vec![];
+    let mut item: Vec<String> = vec![];
+    let mut depth = 0;
+    let mut listing = false;
+
+    for token in tokenize(query) {
+        let top = depth == 0;
+        match token.as_str() {
+            "(" => depth += 1,
+            ")" => depth -= 1,
+            _ => {}
+        }
+        if !listing {
+            listing = top && token == "select";
+            continue;
+        }
+        if top && matches!(token.as_str(), "from" | "where" | "union" | ";") {
+            break;
+        }
+        if top && token == "," {
+            items.push(std::mem::take(&mut item));
+            continue;
+        }
+        item.push(token);
+    }
+    items.push(item);
+
+    let star = |item: &Vec<String>| item.last().is_some_and(|t| t == "*");
+    if !listing || items.iter().any(|item| item.is_empty() || star(item)) {
+        return None;
+    }
+    Some(
+        items
+            .iter()
+            .map(|item| normalize_expression(&item.join(" ")))
+            .collect(),
+    )
+}
@@ -0,0 +1,135 @@
+use super::types::*;
+
+/// Pairs up objects the target no longer has with look-alikes that are new in
+/// the target, proposing to rename the former into the latter. Objects are
+/// alike when they only differ by their name, except for tables, which only
+/// need to hold the same columns. These are guesses to confirm, not hints.
+pub fn propose_renames(s: &Database, t: &Database) -> Vec<Rename> {
+    let mut renames: Vec<Rename> = vec![];
+
+    let created: Vec<(&str, &Schema)> = t
+        .iter_schemas()
+        .filter(|ti| !s.has_schema(&ti.name))
+        .map(|ti| (ti.name.as_str(), ti))
+        .collect();
+    let mut taken = vec![];
+    let dropped = s.iter_schemas().filter(|si| !t.has_schema(&si.name));
+    for si in dropped {
+        if let Some(to) = find_alike(&created, &mut taken, |ti| schemas_alike(si, ti)) {
+            renames.push(Rename::schema(&si.name, to));
+        }
+    }
+
+    for ss in s.iter_schemas() {
+        let Ok(ts) = t.get_schema(&ss.name) else {
+            continue;
+        };
+
+        let created: Vec<(&str, &Relation)> = ts
+            .iter_relations()
+            .filter(|ti| !ss.has_relation(ti.get_name()))
+            .map(|ti| (ti.get_name(), ti))
+            .collect();
+        let mut taken = vec![];
+        let dropped = ss
+            .iter_relations()
+            .filter(|si| !ts.has_relation(si.get_name()));
+        for si in dropped {
+            if let Some(to) = find_alike(&created, &mut taken, |ti| relations_alike(si, ti)) {
+                renames.push(Rename::relation(&ss.name, si.get_name(), to));
+            }
+        }
+
+        for st in ss.iter_relations().filter_map(|r| r.as_table().ok()) {
+            let Some(tt) = ts.get_relation(&st.name).and_then(|r| r.as_table()).ok() else {
+                continue;
+            };
+
+            let created: Vec<(&str, &Column)> = tt
+                .iter_columns()
+                .filter(|ti| !st.has_column(&ti.name))
+                .map(|ti| (ti.name.as_str(), ti))
+                .collect();
+            let mut taken = vec![];
+            let dropped = st.iter_columns().filter(|si| !tt.has_column(&si.name));
+            for si in dropped {
+                if let Some(to) = find_alike(&created, &mut taken, |ti| columns_alike(si, ti)) {
+                    renames.push(Rename::column(&ss.name, &st.name, &si.name, to));
+                }
+            }
+
+            let created: Vec<(&str, &Constraint)> = tt
+                .iter_constraints()
+                .filter(|ti| !st.has_constraint(ti.get_name()))
+                .map(|ti| (ti.get_name(), ti))
+                .collect();
+            let mut taken = vec![];
+            let dropped = st
+                .iter_constraints()
+                .filter(|si| !tt.has_constraint(si.get_name()));
+            for si in dropped {
+                if let Some(to) = find_alike(&created, &mut taken, |ti| constraints_alike(si, ti)) {
+                    renames.push(Rename::constraint(&ss.name, &st.name, si.get_name(), to));
+                }
+            }
+        }
+    }
+
+    // the definitions may already tell about some of them
+    renames.retain(|r| {
+        !t.iter_renames()
+            .any(|h| h.target == r.target && (h.from == r.from || h.to == r.to))
+    });
+    renames
+}
+
+/// Finds the first candidate alike and not taken yet, taking it.
+fn find_alike<'a, T>(
+    candidates: &[(&'a str, &'a T)],
+    taken: &mut Vec<&'a str>,
+    alike: impl Fn(&T) -> bool,
+) -> Option<&'a str> {
+    let (name, _) = candidates
+        .iter()
+        .find(|(name, c)| !taken.contains(name) && alike(c))?;
+    taken.push(name);
+    Some(name)
+}
+
+fn schemas_alike(s: &Schema, t: &Schema) -> bool {
+    let mut renamed = s.clone();
+    renamed.relocate(&t.name);
+    &renamed == t
+}
+
+fn relations_alike(s: &Relation, t: &Relation) -> bool {
+    match (s, t) {
+        (Relation::Table(s), Relation::Table(t)) => {
+            let names = |table: &Table| -> Vec<String> {
+                table.iter_columns().map(|c| c.name.clone()).collect()
+            };
+            names(s) == names(t)
+        }
+        _ => {
+            let mut renamed = s.clone();
+            renamed.relocate(t.get_schema_name(), t.get_name());
+            &renamed == t
+        }
+    }
+}
+
+fn columns_alike(s: &Column, t: &Column) -> bool {
+    let mut renamed = s.clone();
+    renamed.name = t.name.clone();
+    // conversions only tell how to get there
+    renamed.using = t.using.clone();
+    renamed.using_from = t.using_from.clone();
+    renamed.revert_using = t.revert_using.clone();
+    &renamed == t
+}
+
+fn constraints_alike(s: &Constraint, t: &Constraint) -> bool {
+    let mut renamed = s.clone();
+    renamed.relocate(t.get_schema_name(), t.get_table_name(), t.get_name());
+    &renamed == t
+}
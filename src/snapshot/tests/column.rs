@@ -3,9 +3,14 @@ use crate::{
     definition::load_yaml_string,
     snapshot::{
         changes::{
-            AddColumnChange, AlterColumnSetDataTypeChange, AlterColumnSetDefaultChange,
-            AlterColumnSetNotNullChange, Change, DropColumnChange,
+            AddColumnChange, AlterColumnAddIdentityChange, AlterColumnDropExpressionChange,
+            AlterColumnDropIdentityChange, AlterColumnSetCompressionChange,
+            AlterColumnSetDataTypeChange, AlterColumnSetDefaultChange,
+            AlterColumnSetIdentityChange, AlterColumnSetNotNullChange,
+            AlterColumnSetStatisticsChange, AlterColumnSetStorageChange, Change, ColumnIdentity,
+            DropColumnChange,
         },
+        compare_diff,
         tests::utils::run_snapshot_diffing_test,
     },
 };
@@ -36,13 +41,47 @@ fn test_add_column_change() {
         table: "user".to_string(),
         column: "email".to_string(),
         data_type: "text".to_string(),
+        collation: None,
+        compression: None,
         default: None,
         not_null: false,
+        generated: None,
+        identity: None,
     })];
 
     run_snapshot_diffing_test(source, target, expected);
 }
 
+#[test]
+fn test_add_column_renders_nullability() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text!
+                    column nickname: text",
+    )
+    .unwrap();
+
+    let changes = compare_diff(&source, &target);
+    assert_eq!(
+        changes.iter().map(|c| c.render_sql()).collect::<Vec<_>>(),
+        vec![
+            "ALTER TABLE \"public\".\"user\" ADD COLUMN \"email\" text NOT NULL;",
+            "ALTER TABLE \"public\".\"user\" ADD COLUMN \"nickname\" text;",
+        ]
+    );
+}
+
 #[test]
 fn test_alter_column_set_data_type_change() {
     let source = load_yaml_string(
@@ -69,6 +108,95 @@ fn test_alter_column_set_data_type_change() {
             table: "user".to_string(),
             column: "username".to_string(),
             data_type: "varchar".to_string(),
+            collation: None,
+            using: None,
+            revert_using: None,
+        },
+    )];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_column_set_data_type_using_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column age: text",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column age:
+                        type: int4
+                        using text: age::int4
+                        revert using: age::text",
+    )
+    .unwrap();
+
+    let expected = vec![Change::AlterColumnSetDataTypeChange(
+        AlterColumnSetDataTypeChange {
+            schema: "public".to_string(),
+            table: "user".to_string(),
+            column: "age".to_string(),
+            data_type: "int4".to_string(),
+            collation: None,
+            using: Some("age::int4".to_string()),
+            revert_using: Some("age::text".to_string()),
+        },
+    )];
+
+    let changes = compare_diff(&source, &target);
+    assert_eq!(
+        changes[0].render_sql(),
+        "ALTER TABLE \"public\".\"user\" ALTER COLUMN \"age\" SET DATA TYPE int4 USING (age::int4);"
+    );
+    assert_eq!(
+        changes[0].revert(&source).unwrap().render_sql(),
+        "ALTER TABLE \"public\".\"user\" ALTER COLUMN \"age\" SET DATA TYPE text USING (age::text);"
+    );
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_column_set_data_type_ignores_stale_using() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column age: int4",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column age:
+                        type: int8
+                        using text: age::int4
+                        revert using: age::text",
+    )
+    .unwrap();
+
+    let expected = vec![Change::AlterColumnSetDataTypeChange(
+        AlterColumnSetDataTypeChange {
+            schema: "public".to_string(),
+            table: "user".to_string(),
+            column: "age".to_string(),
+            data_type: "int8".to_string(),
+            collation: None,
+            using: None,
+            revert_using: None,
         },
     )];
 
@@ -198,3 +326,368 @@ fn test_drop_column_change() {
 
     run_snapshot_diffing_test(source, target, expected);
 }
+
+#[test]
+fn test_add_generated_column_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table item:
+                    column price: numeric",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table item:
+                    column price: numeric
+                    column total:
+                        type: numeric
+                        generated: price * 2",
+    )
+    .unwrap();
+
+    let expected = vec![Change::AddColumnChange(AddColumnChange {
+        schema: "public".to_string(),
+        table: "item".to_string(),
+        column: "total".to_string(),
+        data_type: "numeric".to_string(),
+        collation: None,
+        compression: None,
+        default: None,
+        not_null: false,
+        generated: Some("price * 2".to_string()),
+        identity: None,
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_recreate_column_on_generated_expression_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table item:
+                    column price: numeric
+                    column total:
+                        type: numeric
+                        generated: price * 2",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table item:
+                    column price: numeric
+                    column total:
+                        type: numeric
+                        generated: price * 3",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::DropColumnChange(DropColumnChange {
+            schema: "public".to_string(),
+            table: "item".to_string(),
+            column: "total".to_string(),
+        }),
+        Change::AddColumnChange(AddColumnChange {
+            schema: "public".to_string(),
+            table: "item".to_string(),
+            column: "total".to_string(),
+            data_type: "numeric".to_string(),
+            collation: None,
+            compression: None,
+            default: None,
+            not_null: false,
+            generated: Some("price * 3".to_string()),
+            identity: None,
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_column_drop_expression_change() {
+    let mut source = load_yaml_string(
+        "
+            schema public:
+                table item:
+                    column price: numeric
+                    column total:
+                        type: numeric
+                        generated: price * 2",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table item:
+                    column price: numeric
+                    column total: numeric",
+    )
+    .unwrap();
+
+    let changes = compare_diff(&source, &target);
+    assert_eq!(
+        changes,
+        vec![Change::AlterColumnDropExpressionChange(
+            AlterColumnDropExpressionChange {
+                schema: "public".to_string(),
+                table: "item".to_string(),
+                column: "total".to_string(),
+            }
+        )]
+    );
+
+    // the expression is gone for good once dropped
+    assert!(changes[0].revert(&source).is_err());
+    changes[0].apply(&mut source).unwrap();
+    assert_eq!(compare_diff(&source, &target), vec![]);
+}
+
+#[test]
+fn test_alter_column_add_identity_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: int4!",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id:
+                        type: int4
+                        identity: always",
+    )
+    .unwrap();
+
+    let expected = vec![Change::AlterColumnAddIdentityChange(
+        AlterColumnAddIdentityChange {
+            schema: "public".to_string(),
+            table: "user".to_string(),
+            column: "id".to_string(),
+            identity: ColumnIdentity {
+                always: true,
+                increment: 1,
+                min_value: 1,
+                max_value: 2147483647,
+                start: 1,
+                cache: 1,
+                cycle: false,
+            },
+        },
+    )];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_column_set_identity_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id:
+                        type: int8
+                        identity: always",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id:
+                        type: int8
+                        identity:
+                            generated: by default
+                            start: 1000",
+    )
+    .unwrap();
+
+    let expected = vec![Change::AlterColumnSetIdentityChange(
+        AlterColumnSetIdentityChange {
+            schema: "public".to_string(),
+            table: "user".to_string(),
+            column: "id".to_string(),
+            identity: ColumnIdentity {
+                always: false,
+                increment: 1,
+                min_value: 1,
+                max_value: 9223372036854775807,
+                start: 1000,
+                cache: 1,
+                cycle: false,
+            },
+        },
+    )];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_column_drop_identity_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id:
+                        type: int8
+                        identity: by default",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: int8!",
+    )
+    .unwrap();
+
+    let expected = vec![Change::AlterColumnDropIdentityChange(
+        AlterColumnDropIdentityChange {
+            schema: "public".to_string(),
+            table: "user".to_string(),
+            column: "id".to_string(),
+        },
+    )];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_column_set_collation_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column email: text",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column email: text COLLATE \"und-x-icu\"",
+    )
+    .unwrap();
+
+    let expected = vec![Change::AlterColumnSetDataTypeChange(
+        AlterColumnSetDataTypeChange {
+            schema: "public".to_string(),
+            table: "user".to_string(),
+            column: "email".to_string(),
+            data_type: "text".to_string(),
+            collation: Some("und-x-icu".to_string()),
+            using: None,
+            revert_using: None,
+        },
+    )];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_column_storage_settings_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table document:
+                    column body:
+                        type: text
+                        compression: pglz",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table document:
+                    column body:
+                        type: text
+                        storage: external
+                        statistics: 500",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::AlterColumnSetStorageChange(AlterColumnSetStorageChange {
+            schema: "public".to_string(),
+            table: "document".to_string(),
+            column: "body".to_string(),
+            storage: Some("external".to_string()),
+        }),
+        Change::AlterColumnSetCompressionChange(AlterColumnSetCompressionChange {
+            schema: "public".to_string(),
+            table: "document".to_string(),
+            column: "body".to_string(),
+            compression: None,
+        }),
+        Change::AlterColumnSetStatisticsChange(AlterColumnSetStatisticsChange {
+            schema: "public".to_string(),
+            table: "document".to_string(),
+            column: "body".to_string(),
+            statistics: Some(500),
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_add_column_with_storage_settings_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table document:
+                    column id: int4",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table document:
+                    column id: int4
+                    column body:
+                        type: text COLLATE \"C\"
+                        compression: lz4
+                        storage: main",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::AddColumnChange(AddColumnChange {
+            schema: "public".to_string(),
+            table: "document".to_string(),
+            column: "body".to_string(),
+            data_type: "text".to_string(),
+            collation: Some("C".to_string()),
+            compression: Some("lz4".to_string()),
+            default: None,
+            not_null: false,
+            generated: None,
+            identity: None,
+        }),
+        Change::AlterColumnSetStorageChange(AlterColumnSetStorageChange {
+            schema: "public".to_string(),
+            table: "document".to_string(),
+            column: "body".to_string(),
+            storage: Some("main".to_string()),
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
@@ -0,0 +1,132 @@
+#[cfg(test)]
+use crate::{
+    definition::load_yaml_string,
+    snapshot::{
+        changes::{Change, CommentChange, CommentTarget},
+        tests::utils::run_snapshot_diffing_test,
+    },
+};
+
+#[test]
+fn test_add_table_comment_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: integer",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    comment: Registered accounts
+                    column id: integer",
+    )
+    .unwrap();
+
+    let expected = vec![Change::CommentChange(CommentChange {
+        target: CommentTarget::Table {
+            schema: "public".to_string(),
+            table: "user".to_string(),
+        },
+        comment: "Registered accounts".to_string(),
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_remove_schema_comment_change() {
+    let source = load_yaml_string(
+        "
+            schema app:
+                comment: Application data",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema app:",
+    )
+    .unwrap();
+
+    let expected = vec![Change::CommentChange(CommentChange {
+        target: CommentTarget::Schema {
+            schema: "app".to_string(),
+        },
+        comment: "".to_string(),
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_column_comment_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column email:
+                        type: text
+                        comment: Email",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column email:
+                        type: text
+                        comment: Login address",
+    )
+    .unwrap();
+
+    let expected = vec![Change::CommentChange(CommentChange {
+        target: CommentTarget::Column {
+            schema: "public".to_string(),
+            table: "user".to_string(),
+            column: "email".to_string(),
+        },
+        comment: "Login address".to_string(),
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_add_constraint_comment_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column age: integer
+                    constraint age_positive:
+                        check: age > 0",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column age: integer
+                    constraint age_positive:
+                        check: age > 0
+                        comment: Ages are counted from birth",
+    )
+    .unwrap();
+
+    let expected = vec![Change::CommentChange(CommentChange {
+        target: CommentTarget::Constraint {
+            schema: "public".to_string(),
+            table: "user".to_string(),
+            constraint: "age_positive".to_string(),
+        },
+        comment: "Ages are counted from birth".to_string(),
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
@@ -0,0 +1,253 @@
+#[cfg(test)]
+use crate::{
+    definition::load_yaml_string,
+    snapshot::{changes::Change, compare_diff},
+};
+
+#[cfg(test)]
+fn position(changes: &[Change], name: &str, sql: &str) -> usize {
+    changes
+        .iter()
+        .position(|c| c.display_name() == name && c.render_sql().contains(sql))
+        .unwrap()
+}
+
+#[test]
+fn test_foreign_keys_wait_for_every_table() {
+    let source = load_yaml_string(
+        "
+            schema public:",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table author:
+                    column id: uuid
+                    column favorite_book_id: uuid
+                    constraint author_pkey:
+                        primary key: id
+                    constraint author_favorite_book_fkey:
+                        foreign key: favorite_book_id
+                        references book: id
+                table book:
+                    column id: uuid
+                    column author_id: uuid
+                    constraint book_pkey:
+                        primary key: id
+                    constraint book_author_fkey:
+                        foreign key: author_id
+                        references author: id",
+    )
+    .unwrap();
+
+    let changes = compare_diff(&source, &target);
+
+    let author = position(&changes, "CreateTableChange", "\"author\"");
+    let book = position(&changes, "CreateTableChange", "\"book\"");
+    let author_pkey = position(&changes, "AddPrimaryKeyChange", "author_pkey");
+    let book_pkey = position(&changes, "AddPrimaryKeyChange", "book_pkey");
+    let author_fkey = position(&changes, "AddForeignKeyChange", "author_favorite_book_fkey");
+    let book_fkey = position(&changes, "AddForeignKeyChange", "book_author_fkey");
+    assert!(author_fkey > author && author_fkey > book && author_fkey > book_pkey);
+    assert!(book_fkey > author && book_fkey > book && book_fkey > author_pkey);
+}
+
+#[test]
+fn test_dependents_are_dropped_first() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column nickname: text
+                table session:
+                    column user_id: uuid
+                    constraint session_user_fkey:
+                        foreign key: user_id
+                        references user: id
+
+                index user_nickname_idx:
+                    on user: nickname
+                materialized view user_nicknames:
+                    as: SELECT nickname FROM public.user",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid",
+    )
+    .unwrap();
+
+    let changes = compare_diff(&source, &target);
+
+    let view = position(&changes, "DropMaterializedViewChange", "user_nicknames");
+    let index = position(&changes, "DropIndexChange", "user_nickname_idx");
+    let column = position(&changes, "DropColumnChange", "nickname");
+    assert!(view < column);
+    assert!(index < column);
+}
+
+#[test]
+fn test_extensions_follow_their_schema_and_outlive_their_users() {
+    let without = load_yaml_string(
+        "
+            schema public:",
+    )
+    .unwrap();
+
+    let with = load_yaml_string(
+        "
+            extension citext:
+                schema: ext
+
+            schema app:
+                table user:
+                    column email: citext
+
+            schema ext:
+
+            schema public:",
+    )
+    .unwrap();
+
+    let changes = compare_diff(&without, &with);
+
+    let ext = position(&changes, "CreateSchemaChange", "ext");
+    let citext = position(&changes, "CreateExtensionChange", "citext");
+    let user = position(&changes, "CreateTableChange", "\"user\"");
+    assert!(ext < citext);
+    assert!(citext < user);
+
+    let changes = compare_diff(&with, &without);
+
+    let user = position(&changes, "DropTableChange", "\"user\"");
+    let citext = position(&changes, "DropExtensionChange", "citext");
+    let ext = position(&changes, "DropSchemaChange", "ext");
+    assert!(user < citext);
+    assert!(citext < ext);
+}
+
+#[test]
+fn test_views_follow_what_they_select_from() {
+    let source = load_yaml_string(
+        "
+            schema public:",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column nickname: text
+                materialized view user_nicknames:
+                    as: SELECT nickname FROM public.user
+                materialized view nickname_count:
+                    as: SELECT count(*) FROM user_nicknames",
+    )
+    .unwrap();
+
+    let changes = compare_diff(&source, &target);
+
+    let user = position(&changes, "CreateTableChange", "\"user\"");
+    let nicknames = position(&changes, "CreateMaterializedViewChange", "user_nicknames");
+    let count = position(&changes, "CreateMaterializedViewChange", "nickname_count");
+    assert!(user < nicknames);
+    assert!(nicknames < count);
+}
+
+#[test]
+fn test_views_are_recreated_around_column_type_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column nickname: text
+                table session:
+                    column id: uuid
+                view user_nicknames:
+                    as: SELECT id, nickname FROM public.user
+                view session_ids:
+                    as: SELECT id FROM public.session
+            schema report:
+                view nickname_count:
+                    as: SELECT count(nickname) FROM public.user_nicknames",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column nickname: varchar(64)
+                table session:
+                    column id: uuid
+                view user_nicknames:
+                    as: SELECT id, nickname FROM public.user
+                view session_ids:
+                    as: SELECT id FROM public.session
+            schema report:
+                view nickname_count:
+                    as: SELECT count(nickname) FROM public.user_nicknames",
+    )
+    .unwrap();
+
+    let changes = compare_diff(&source, &target);
+
+    let column = position(&changes, "AlterColumnSetDataTypeChange", "nickname");
+    let drop_nicknames = position(&changes, "DropViewChange", "user_nicknames");
+    let drop_count = position(&changes, "DropViewChange", "nickname_count");
+    let create_nicknames = position(&changes, "CreateViewChange", "user_nicknames");
+    let create_count = position(&changes, "CreateViewChange", "nickname_count");
+    assert!(drop_count < drop_nicknames && drop_nicknames < column);
+    assert!(column < create_nicknames && create_nicknames < create_count);
+    assert!(!changes
+        .iter()
+        .any(|c| c.render_sql().contains("session_ids")));
+}
+
+#[test]
+fn test_materialized_views_are_recreated_around_dropped_column() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column nickname: text
+                materialized view user_ids:
+                    as: SELECT * FROM public.user
+                index user_ids_idx:
+                    on user_ids: id",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                materialized view user_ids:
+                    as: SELECT * FROM public.user
+                index user_ids_idx:
+                    on user_ids: id",
+    )
+    .unwrap();
+
+    let changes = compare_diff(&source, &target);
+
+    let column = position(&changes, "DropColumnChange", "nickname");
+    let drop_index = position(&changes, "DropIndexChange", "user_ids_idx");
+    let drop_view = position(&changes, "DropMaterializedViewChange", "user_ids");
+    let create_view = position(&changes, "CreateMaterializedViewChange", "user_ids");
+    let create_index = position(&changes, "CreateIndexChange", "user_ids_idx");
+    assert!(drop_index < drop_view && drop_view < column);
+    assert!(column < create_view && create_view < create_index);
+}
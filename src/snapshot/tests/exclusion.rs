@@ -0,0 +1,144 @@
+#[cfg(test)]
+use crate::{
+    definition::load_yaml_string,
+    snapshot::{
+        changes::{
+            AddExclusionChange, AddExclusionChangeElement, AlterExclusionChange, Change,
+            DropExclusionChange,
+        },
+        tests::utils::run_snapshot_diffing_test,
+    },
+};
+
+#[test]
+fn test_add_exclusion_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table booking:
+                    column room: int
+                    column during: tsrange",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table booking:
+                    column room: int
+                    column during: tsrange
+
+                    constraint booking_no_overlap:
+                        exclude: [room WITH =, during WITH &&]",
+    )
+    .unwrap();
+
+    let expected = vec![Change::AddExclusionChange(AddExclusionChange {
+        schema: "public".to_string(),
+        table: "booking".to_string(),
+        constraint: "booking_no_overlap".to_string(),
+        method: "gist".to_string(),
+        elements: vec![
+            AddExclusionChangeElement {
+                expression: "room".to_string(),
+                operator: "=".to_string(),
+            },
+            AddExclusionChangeElement {
+                expression: "during".to_string(),
+                operator: "&&".to_string(),
+            },
+        ],
+        predicate: None,
+        deferrable: false,
+        initially_deferred: false,
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_exclusion_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table booking:
+                    column during: tsrange
+
+                    constraint booking_no_overlap:
+                        exclude: during WITH &&",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table booking:
+                    column during: tsrange
+
+                    constraint booking_no_overlap:
+                        exclude: during WITH &&
+                        deferrable: true",
+    )
+    .unwrap();
+
+    let expected = vec![Change::AlterExclusionChange(AlterExclusionChange {
+        schema: "public".to_string(),
+        table: "booking".to_string(),
+        constraint: "booking_no_overlap".to_string(),
+        deferrable: true,
+        initially_deferred: false,
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_recreate_exclusion_on_predicate_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table booking:
+                    column during: tsrange
+                    column cancelled: boolean
+
+                    constraint booking_no_overlap:
+                        exclude: during WITH &&",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table booking:
+                    column during: tsrange
+                    column cancelled: boolean
+
+                    constraint booking_no_overlap:
+                        exclude: during WITH &&
+                        where: NOT cancelled",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::DropExclusionChange(DropExclusionChange {
+            schema: "public".to_string(),
+            table: "booking".to_string(),
+            constraint: "booking_no_overlap".to_string(),
+        }),
+        Change::AddExclusionChange(AddExclusionChange {
+            schema: "public".to_string(),
+            table: "booking".to_string(),
+            constraint: "booking_no_overlap".to_string(),
+            method: "gist".to_string(),
+            elements: vec![AddExclusionChangeElement {
+                expression: "during".to_string(),
+                operator: "&&".to_string(),
+            }],
+            predicate: Some("NOT cancelled".to_string()),
+            deferrable: false,
+            initially_deferred: false,
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
@@ -0,0 +1,244 @@
+#[cfg(test)]
+use crate::{
+    definition::load_yaml_string,
+    snapshot::{
+        changes::{
+            AlterExtensionSetSchemaChange, AlterExtensionUpdateChange, Change,
+            CreateExtensionChange, CreateSchemaChange, CreateTableChange, CreateTableChangeColumn,
+            DropExtensionChange, DropSchemaChange, DropTableChange,
+        },
+        tests::utils::run_snapshot_diffing_test,
+    },
+};
+
+#[test]
+fn test_create_extension_change() {
+    let source = load_yaml_string(
+        "
+            schema public:",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            extension pgcrypto:
+                version: '1.3'
+
+            schema public:",
+    )
+    .unwrap();
+
+    let expected = vec![Change::CreateExtensionChange(CreateExtensionChange {
+        extension: "pgcrypto".to_string(),
+        schema: "public".to_string(),
+        version: Some("1.3".to_string()),
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_create_extension_before_schema_change() {
+    let source = load_yaml_string("{}").unwrap();
+
+    let target = load_yaml_string(
+        "
+            extension citext:
+
+            schema app:
+                table user:
+                    column email: citext",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::CreateExtensionChange(CreateExtensionChange {
+            extension: "citext".to_string(),
+            schema: "public".to_string(),
+            version: None,
+        }),
+        Change::CreateSchemaChange(CreateSchemaChange {
+            schema_name: "app".to_string(),
+        }),
+        Change::CreateTableChange(CreateTableChange {
+            schema: "app".to_string(),
+            table: "user".to_string(),
+            columns: vec![CreateTableChangeColumn {
+                name: "email".to_string(),
+                data_type: "citext".to_string(),
+                collation: None,
+                compression: None,
+                default: None,
+                not_null: false,
+                generated: None,
+                identity: None,
+            }],
+            partition_by: None,
+            unlogged: false,
+            storage_parameters: vec![],
+            tablespace: None,
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_create_extension_in_new_schema_change() {
+    let source = load_yaml_string("{}").unwrap();
+
+    let target = load_yaml_string(
+        "
+            extension citext:
+                schema: ext
+
+            schema app:
+                table user:
+                    column email: citext
+
+            schema ext:",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::CreateSchemaChange(CreateSchemaChange {
+            schema_name: "app".to_string(),
+        }),
+        Change::CreateSchemaChange(CreateSchemaChange {
+            schema_name: "ext".to_string(),
+        }),
+        Change::CreateExtensionChange(CreateExtensionChange {
+            extension: "citext".to_string(),
+            schema: "ext".to_string(),
+            version: None,
+        }),
+        Change::CreateTableChange(CreateTableChange {
+            schema: "app".to_string(),
+            table: "user".to_string(),
+            columns: vec![CreateTableChangeColumn {
+                name: "email".to_string(),
+                data_type: "citext".to_string(),
+                collation: None,
+                compression: None,
+                default: None,
+                not_null: false,
+                generated: None,
+                identity: None,
+            }],
+            partition_by: None,
+            unlogged: false,
+            storage_parameters: vec![],
+            tablespace: None,
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_drop_extension_after_its_users_change() {
+    let source = load_yaml_string(
+        "
+            extension citext:
+                schema: ext
+
+            schema app:
+                table user:
+                    column email: citext
+
+            schema ext:",
+    )
+    .unwrap();
+
+    let target = load_yaml_string("{}").unwrap();
+
+    let expected = vec![
+        Change::DropTableChange(DropTableChange {
+            schema: "app".to_string(),
+            table: "user".to_string(),
+        }),
+        Change::DropSchemaChange(DropSchemaChange {
+            schema_name: "app".to_string(),
+        }),
+        Change::DropExtensionChange(DropExtensionChange {
+            extension: "citext".to_string(),
+        }),
+        Change::DropSchemaChange(DropSchemaChange {
+            schema_name: "ext".to_string(),
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_extension_set_schema_change() {
+    let source = load_yaml_string(
+        "
+            extension citext:
+
+            schema ext:",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            extension citext:
+                schema: ext
+
+            schema ext:",
+    )
+    .unwrap();
+
+    let expected = vec![Change::AlterExtensionSetSchemaChange(
+        AlterExtensionSetSchemaChange {
+            extension: "citext".to_string(),
+            schema: "ext".to_string(),
+        },
+    )];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_extension_update_change() {
+    let source = load_yaml_string(
+        "
+            extension citext:
+                version: '1.5'",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            extension citext:
+                version: '1.6'",
+    )
+    .unwrap();
+
+    let expected = vec![Change::AlterExtensionUpdateChange(
+        AlterExtensionUpdateChange {
+            extension: "citext".to_string(),
+            version: Some("1.6".to_string()),
+        },
+    )];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_drop_extension_change() {
+    let source = load_yaml_string(
+        "
+            extension citext:",
+    )
+    .unwrap();
+
+    let target = load_yaml_string("{}").unwrap();
+
+    let expected = vec![Change::DropExtensionChange(DropExtensionChange {
+        extension: "citext".to_string(),
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
@@ -2,7 +2,7 @@
 use crate::{
     definition::load_yaml_string,
     snapshot::{
-        changes::{Change, CreateFunctionChange, DropFunctionChange},
+        changes::{Change, CreateFunctionChange, DropFunctionChange, ReplaceFunctionChange},
         tests::utils::run_snapshot_diffing_test,
     },
 };
@@ -62,3 +62,77 @@ fn test_drop_function_change() {
 
     run_snapshot_diffing_test(source, target, expected);
 }
+
+#[test]
+fn test_replace_function_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                function hello_world():
+                    returns: text
+                    language: sql
+                    as: select 'Hello, World!';",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                function hello_world():
+                    returns: text
+                    language: sql
+                    as: select 'Hello, Podo!';",
+    )
+    .unwrap();
+
+    let expected = vec![Change::ReplaceFunctionChange(ReplaceFunctionChange {
+        schema: "public".to_string(),
+        function: "hello_world()".to_string(),
+        returns: "text".to_string(),
+        language: "sql".to_string(),
+        body: "select 'Hello, Podo!';".to_string(),
+        volatility: "VOLATILE".to_string(),
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_recreate_function_on_return_type_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                function answer():
+                    returns: int4
+                    language: sql
+                    as: select 42;",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                function answer():
+                    returns: int8
+                    language: sql
+                    as: select 42;",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::DropFunctionChange(DropFunctionChange {
+            schema: "public".to_string(),
+            function: "answer()".to_string(),
+        }),
+        Change::CreateFunctionChange(CreateFunctionChange {
+            schema: "public".to_string(),
+            function: "answer()".to_string(),
+            returns: "int8".to_string(),
+            language: "sql".to_string(),
+            body: "select 42;".to_string(),
+            volatility: "VOLATILE".to_string(),
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
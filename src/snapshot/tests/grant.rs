@@ -0,0 +1,295 @@
+#[cfg(test)]
+use crate::{
+    definition::load_yaml_string,
+    snapshot::{
+        changes::{
+            Change, CreateFunctionChange, CreateTableChange, CreateTableChangeColumn, GrantChange,
+            GrantDefaultPrivilegesChange, GrantTarget, RevokeChange,
+            RevokeDefaultPrivilegesChange,
+        },
+        tests::utils::run_snapshot_diffing_test,
+    },
+};
+
+#[test]
+fn test_grant_on_created_table_change() {
+    let source = load_yaml_string(
+        "
+            schema public:",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: integer
+                    grant select: [app_ro]",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::CreateTableChange(CreateTableChange {
+            schema: "public".to_string(),
+            table: "user".to_string(),
+            columns: vec![CreateTableChangeColumn {
+                name: "id".to_string(),
+                data_type: "integer".to_string(),
+                collation: None,
+                compression: None,
+                default: None,
+                not_null: false,
+                generated: None,
+                identity: None,
+            }],
+            partition_by: None,
+            unlogged: false,
+            storage_parameters: vec![],
+            tablespace: None,
+        }),
+        Change::GrantChange(GrantChange {
+            target: GrantTarget::Table {
+                schema: "public".to_string(),
+                table: "user".to_string(),
+            },
+            grantee: "app_ro".to_string(),
+            privileges: vec!["select".to_string()],
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_grant_and_revoke_table_privileges_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: integer
+                    grant select: [app_ro, app_rw]",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: integer
+                    grant select, insert: [app_rw]",
+    )
+    .unwrap();
+
+    let target_table = GrantTarget::Table {
+        schema: "public".to_string(),
+        table: "user".to_string(),
+    };
+    let expected = vec![
+        Change::RevokeChange(RevokeChange {
+            target: target_table.clone(),
+            grantee: "app_ro".to_string(),
+            privileges: vec!["select".to_string()],
+        }),
+        Change::GrantChange(GrantChange {
+            target: target_table,
+            grantee: "app_rw".to_string(),
+            privileges: vec!["insert".to_string()],
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_grant_column_privileges_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column email: text",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column email:
+                        type: text
+                        grant select, update: [public]",
+    )
+    .unwrap();
+
+    let expected = vec![Change::GrantChange(GrantChange {
+        target: GrantTarget::Column {
+            schema: "public".to_string(),
+            table: "user".to_string(),
+            column: "email".to_string(),
+        },
+        grantee: "public".to_string(),
+        privileges: vec!["select".to_string(), "update".to_string()],
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_default_privileges_change() {
+    let source = load_yaml_string(
+        "
+            schema app:
+                grant usage: [app_ro]
+                default privileges on sequences:
+                    grant usage: [app_ro]",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema app:
+                grant usage: [app_ro]
+                default privileges on tables:
+                    grant select: [app_ro]",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::RevokeDefaultPrivilegesChange(RevokeDefaultPrivilegesChange {
+            schema: "app".to_string(),
+            object_type: "sequences".to_string(),
+            grantee: "app_ro".to_string(),
+            privileges: vec!["usage".to_string()],
+        }),
+        Change::GrantDefaultPrivilegesChange(GrantDefaultPrivilegesChange {
+            schema: "app".to_string(),
+            object_type: "tables".to_string(),
+            grantee: "app_ro".to_string(),
+            privileges: vec!["select".to_string()],
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_render_grant_sql() {
+    let change = GrantChange {
+        target: GrantTarget::Column {
+            schema: "public".to_string(),
+            table: "user".to_string(),
+            column: "email".to_string(),
+        },
+        grantee: "app_rw".to_string(),
+        privileges: vec!["select".to_string(), "update".to_string()],
+    };
+    assert_eq!(
+        change.render_sql(),
+        r#"GRANT SELECT ("email"), UPDATE ("email") ON TABLE "public"."user" TO "app_rw";"#
+    );
+
+    let change = RevokeDefaultPrivilegesChange {
+        schema: "app".to_string(),
+        object_type: "tables".to_string(),
+        grantee: "public".to_string(),
+        privileges: vec!["select".to_string()],
+    };
+    assert_eq!(
+        change.render_sql(),
+        r#"ALTER DEFAULT PRIVILEGES IN SCHEMA "app" REVOKE SELECT ON TABLES FROM PUBLIC;"#
+    );
+}
+
+#[test]
+fn test_revoke_on_created_function_change() {
+    let source = load_yaml_string(
+        "
+            schema public:",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                function one():
+                    returns: integer
+                    as: SELECT 1
+                    revoke execute: [public]",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::CreateFunctionChange(CreateFunctionChange {
+            schema: "public".to_string(),
+            function: "one()".to_string(),
+            returns: "integer".to_string(),
+            language: "SQL".to_string(),
+            body: "SELECT 1".to_string(),
+            volatility: "VOLATILE".to_string(),
+        }),
+        Change::RevokeChange(RevokeChange {
+            target: GrantTarget::Function {
+                schema: "public".to_string(),
+                function: "one()".to_string(),
+            },
+            grantee: "public".to_string(),
+            privileges: vec!["execute".to_string()],
+        }),
+    ];
+
+    assert_eq!(
+        expected[1].render_sql(),
+        "REVOKE EXECUTE ON FUNCTION \"public\".\"one()\" FROM PUBLIC;"
+    );
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_restore_revoked_privileges_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                revoke create, usage: [app_owner]
+                table user:
+                    column id: integer
+                    grant select: [app_ro]
+                    revoke truncate: [app_owner]",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                revoke create: [app_owner]
+                table user:
+                    column id: integer",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::GrantChange(GrantChange {
+            target: GrantTarget::Schema {
+                schema: "public".to_string(),
+            },
+            grantee: "app_owner".to_string(),
+            privileges: vec!["usage".to_string()],
+        }),
+        Change::RevokeChange(RevokeChange {
+            target: GrantTarget::Table {
+                schema: "public".to_string(),
+                table: "user".to_string(),
+            },
+            grantee: "app_ro".to_string(),
+            privileges: vec!["select".to_string()],
+        }),
+        Change::GrantChange(GrantChange {
+            target: GrantTarget::Table {
+                schema: "public".to_string(),
+                table: "user".to_string(),
+            },
+            grantee: "app_owner".to_string(),
+            privileges: vec!["truncate".to_string()],
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
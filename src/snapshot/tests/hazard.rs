@@ -0,0 +1,128 @@
+#[cfg(test)]
+use crate::{
+    definition::load_yaml_string,
+    snapshot::{changes::Hazard, compare_diff},
+};
+
+#[cfg(test)]
+fn hazards_of(source: &str, target: &str) -> Vec<(String, Vec<Hazard>)> {
+    let source = load_yaml_string(source).unwrap();
+    let target = load_yaml_string(target).unwrap();
+    compare_diff(&source, &target)
+        .iter()
+        .map(|c| (c.display_name().to_string(), c.hazards()))
+        .collect()
+}
+
+#[test]
+fn test_dropping_data_is_irreversible() {
+    let hazards = hazards_of(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column nickname: text
+                table session:
+                    column id: uuid",
+        "
+            schema public:
+                table user:
+                    column id: uuid",
+    );
+
+    assert_eq!(
+        hazards,
+        vec![
+            (
+                "DropColumnChange".to_string(),
+                vec![Hazard::Irreversible, Hazard::DataLosing]
+            ),
+            (
+                "DropTableChange".to_string(),
+                vec![Hazard::Irreversible, Hazard::DataLosing]
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_rewriting_a_table_is_lock_heavy() {
+    let hazards = hazards_of(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column age: int4",
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column age: int8
+                    column nickname: text",
+    );
+
+    assert_eq!(
+        hazards,
+        vec![
+            (
+                "AlterColumnSetDataTypeChange".to_string(),
+                vec![Hazard::DataLosing, Hazard::LockHeavy]
+            ),
+            ("AddColumnChange".to_string(), vec![]),
+        ]
+    );
+}
+
+#[test]
+fn test_repartitioning_a_table_is_irreversible() {
+    let hazards = hazards_of(
+        "
+            schema public:
+                table event:
+                    column id: int
+                    column created_at: date
+                    partition by list: id",
+        "
+            schema public:
+                table event:
+                    column id: int
+                    column created_at: date
+                    partition by range: created_at",
+    );
+
+    assert_eq!(
+        hazards,
+        vec![
+            (
+                "DropTableChange".to_string(),
+                vec![Hazard::Irreversible, Hazard::DataLosing]
+            ),
+            ("CreateTableChange".to_string(), vec![]),
+        ]
+    );
+}
+
+#[test]
+fn test_dropping_a_schema_or_materialized_view_loses_data() {
+    let hazards = hazards_of(
+        "
+            schema archive:
+
+            schema public:
+                materialized view answer:
+                    as: SELECT 42 AS answer",
+        "
+            schema public:",
+    );
+
+    assert_eq!(
+        hazards,
+        vec![
+            ("DropSchemaChange".to_string(), vec![Hazard::DataLosing]),
+            (
+                "DropMaterializedViewChange".to_string(),
+                vec![Hazard::DataLosing]
+            ),
+        ]
+    );
+}
@@ -2,7 +2,10 @@
 use crate::{
     definition::load_yaml_string,
     snapshot::{
-        changes::{Change, CreateIndexChange, DropIndexChange},
+        changes::{
+            AlterIndexSetStorageParametersChange, AlterIndexSetTablespaceChange, Change,
+            CreateIndexChange, CreateIndexChangeKey, DropIndexChange, RenameIndexChange,
+        },
         tests::utils::run_snapshot_diffing_test,
     },
 };
@@ -34,9 +37,20 @@ fn test_create_index_change() {
         schema: "public".to_string(),
         table: "user".to_string(),
         index: "user_username_idx".to_string(),
-        key_expressions: vec!["username".to_string()],
-        method: "btree".to_string(),
         unique: false,
+        nulls_not_distinct: false,
+        method: "btree".to_string(),
+        keys: vec![CreateIndexChangeKey {
+            expression: "username".to_string(),
+            collation: None,
+            opclass: None,
+            order: "ASC".to_string(),
+            nulls: "LAST".to_string(),
+        }],
+        include: vec![],
+        predicate: None,
+        storage_parameters: vec![],
+        tablespace: None,
     })];
 
     run_snapshot_diffing_test(source, target, expected);
@@ -72,3 +86,594 @@ fn test_drop_index_change() {
 
     run_snapshot_diffing_test(source, target, expected);
 }
+
+#[test]
+fn test_create_complete_index_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                    column deleted_at: timestamptz",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                    column deleted_at: timestamptz
+
+                index user_email_idx:
+                    unique: true
+                    nulls not distinct: true
+                    on user: [lower(email) text_pattern_ops DESC, email COLLATE \"C\"]
+                    include: id
+                    where: deleted_at IS NULL
+                    with: fillfactor=70",
+    )
+    .unwrap();
+
+    let expected = vec![Change::CreateIndexChange(CreateIndexChange {
+        schema: "public".to_string(),
+        table: "user".to_string(),
+        index: "user_email_idx".to_string(),
+        unique: true,
+        nulls_not_distinct: true,
+        method: "btree".to_string(),
+        keys: vec![
+            CreateIndexChangeKey {
+                expression: "lower(email)".to_string(),
+                collation: None,
+                opclass: Some("text_pattern_ops".to_string()),
+                order: "DESC".to_string(),
+                nulls: "FIRST".to_string(),
+            },
+            CreateIndexChangeKey {
+                expression: "email".to_string(),
+                collation: Some("C".to_string()),
+                opclass: None,
+                order: "ASC".to_string(),
+                nulls: "LAST".to_string(),
+            },
+        ],
+        include: vec!["id".to_string()],
+        predicate: Some("deleted_at IS NULL".to_string()),
+        storage_parameters: vec!["fillfactor=70".to_string()],
+        tablespace: None,
+    })];
+
+    assert_eq!(
+        expected[0].render_sql(),
+        "CREATE UNIQUE INDEX \"user_email_idx\" ON \"public\".\"user\" USING btree \
+         (lower(email) text_pattern_ops DESC, email COLLATE \"C\") INCLUDE (\"id\") \
+         NULLS NOT DISTINCT WITH (fillfactor=70) WHERE (deleted_at IS NULL);"
+    );
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_index_set_storage_parameters_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column email: text
+                index user_email_idx:
+                    on user: email
+                    with: [fillfactor=70, deduplicate_items=off]",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column email: text
+                index user_email_idx:
+                    on user: email
+                    with: fillfactor=90",
+    )
+    .unwrap();
+
+    let expected = vec![Change::AlterIndexSetStorageParametersChange(
+        AlterIndexSetStorageParametersChange {
+            schema: "public".to_string(),
+            index: "user_email_idx".to_string(),
+            set: vec!["fillfactor=90".to_string()],
+            reset: vec!["deduplicate_items".to_string()],
+        },
+    )];
+
+    assert_eq!(
+        expected[0].render_sql(),
+        "ALTER INDEX \"public\".\"user_email_idx\" SET (fillfactor=90), RESET (deduplicate_items);"
+    );
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_recreate_index_on_unique_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                index user_email_idx:
+                    on user: email",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                index user_email_idx:
+                    unique: true
+                    on user: email",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::DropIndexChange(DropIndexChange {
+            schema: "public".to_string(),
+            index: "user_email_idx".to_string(),
+        }),
+        Change::CreateIndexChange(CreateIndexChange {
+            schema: "public".to_string(),
+            table: "user".to_string(),
+            index: "user_email_idx".to_string(),
+            unique: true,
+            nulls_not_distinct: false,
+            method: "btree".to_string(),
+            keys: vec![CreateIndexChangeKey {
+                expression: "email".to_string(),
+                collation: None,
+                opclass: None,
+                order: "ASC".to_string(),
+                nulls: "LAST".to_string(),
+            }],
+            include: vec![],
+            predicate: None,
+            storage_parameters: vec![],
+            tablespace: None,
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_recreate_index_on_nulls_not_distinct_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                index user_email_idx:
+                    unique: true
+                    on user: email",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                index user_email_idx:
+                    unique: true
+                    nulls not distinct: true
+                    on user: email",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::DropIndexChange(DropIndexChange {
+            schema: "public".to_string(),
+            index: "user_email_idx".to_string(),
+        }),
+        Change::CreateIndexChange(CreateIndexChange {
+            schema: "public".to_string(),
+            table: "user".to_string(),
+            index: "user_email_idx".to_string(),
+            unique: true,
+            nulls_not_distinct: true,
+            method: "btree".to_string(),
+            keys: vec![CreateIndexChangeKey {
+                expression: "email".to_string(),
+                collation: None,
+                opclass: None,
+                order: "ASC".to_string(),
+                nulls: "LAST".to_string(),
+            }],
+            include: vec![],
+            predicate: None,
+            storage_parameters: vec![],
+            tablespace: None,
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_recreate_index_on_method_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                index user_email_idx:
+                    on user: email",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                index user_email_idx:
+                    using: hash
+                    on user: email",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::DropIndexChange(DropIndexChange {
+            schema: "public".to_string(),
+            index: "user_email_idx".to_string(),
+        }),
+        Change::CreateIndexChange(CreateIndexChange {
+            schema: "public".to_string(),
+            table: "user".to_string(),
+            index: "user_email_idx".to_string(),
+            unique: false,
+            nulls_not_distinct: false,
+            method: "hash".to_string(),
+            keys: vec![CreateIndexChangeKey {
+                expression: "email".to_string(),
+                collation: None,
+                opclass: None,
+                order: "ASC".to_string(),
+                nulls: "LAST".to_string(),
+            }],
+            include: vec![],
+            predicate: None,
+            storage_parameters: vec![],
+            tablespace: None,
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_recreate_index_on_keys_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                index user_email_idx:
+                    on user: email",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                index user_email_idx:
+                    on user: [email DESC]",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::DropIndexChange(DropIndexChange {
+            schema: "public".to_string(),
+            index: "user_email_idx".to_string(),
+        }),
+        Change::CreateIndexChange(CreateIndexChange {
+            schema: "public".to_string(),
+            table: "user".to_string(),
+            index: "user_email_idx".to_string(),
+            unique: false,
+            nulls_not_distinct: false,
+            method: "btree".to_string(),
+            keys: vec![CreateIndexChangeKey {
+                expression: "email".to_string(),
+                collation: None,
+                opclass: None,
+                order: "DESC".to_string(),
+                nulls: "FIRST".to_string(),
+            }],
+            include: vec![],
+            predicate: None,
+            storage_parameters: vec![],
+            tablespace: None,
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_recreate_index_on_include_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                index user_email_idx:
+                    on user: email",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                index user_email_idx:
+                    on user: email
+                    include: id",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::DropIndexChange(DropIndexChange {
+            schema: "public".to_string(),
+            index: "user_email_idx".to_string(),
+        }),
+        Change::CreateIndexChange(CreateIndexChange {
+            schema: "public".to_string(),
+            table: "user".to_string(),
+            index: "user_email_idx".to_string(),
+            unique: false,
+            nulls_not_distinct: false,
+            method: "btree".to_string(),
+            keys: vec![CreateIndexChangeKey {
+                expression: "email".to_string(),
+                collation: None,
+                opclass: None,
+                order: "ASC".to_string(),
+                nulls: "LAST".to_string(),
+            }],
+            include: vec!["id".to_string()],
+            predicate: None,
+            storage_parameters: vec![],
+            tablespace: None,
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_recreate_index_on_predicate_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                index user_email_idx:
+                    on user: email",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                index user_email_idx:
+                    on user: email
+                    where: id IS NOT NULL",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::DropIndexChange(DropIndexChange {
+            schema: "public".to_string(),
+            index: "user_email_idx".to_string(),
+        }),
+        Change::CreateIndexChange(CreateIndexChange {
+            schema: "public".to_string(),
+            table: "user".to_string(),
+            index: "user_email_idx".to_string(),
+            unique: false,
+            nulls_not_distinct: false,
+            method: "btree".to_string(),
+            keys: vec![CreateIndexChangeKey {
+                expression: "email".to_string(),
+                collation: None,
+                opclass: None,
+                order: "ASC".to_string(),
+                nulls: "LAST".to_string(),
+            }],
+            include: vec![],
+            predicate: Some("id IS NOT NULL".to_string()),
+            storage_parameters: vec![],
+            tablespace: None,
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_index_set_tablespace_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                index user_email_idx:
+                    on user: email",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                index user_email_idx:
+                    on user: email
+                    tablespace: fast",
+    )
+    .unwrap();
+
+    let expected = vec![Change::AlterIndexSetTablespaceChange(
+        AlterIndexSetTablespaceChange {
+            schema: "public".to_string(),
+            index: "user_email_idx".to_string(),
+            tablespace: Some("fast".to_string()),
+        },
+    )];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_recreate_index_on_table_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                table member:
+                    column id: uuid
+                    column email: text
+                index user_email_idx:
+                    on user: email",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                table member:
+                    column id: uuid
+                    column email: text
+                index user_email_idx:
+                    on member: email",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::DropIndexChange(DropIndexChange {
+            schema: "public".to_string(),
+            index: "user_email_idx".to_string(),
+        }),
+        Change::CreateIndexChange(CreateIndexChange {
+            schema: "public".to_string(),
+            table: "member".to_string(),
+            index: "user_email_idx".to_string(),
+            unique: false,
+            nulls_not_distinct: false,
+            method: "btree".to_string(),
+            keys: vec![CreateIndexChangeKey {
+                expression: "email".to_string(),
+                collation: None,
+                opclass: None,
+                order: "ASC".to_string(),
+                nulls: "LAST".to_string(),
+            }],
+            include: vec![],
+            predicate: None,
+            storage_parameters: vec![],
+            tablespace: None,
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_rename_index_when_only_the_name_differs() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column email: text
+                index user_idx:
+                    unique: true
+                    on user: email
+                    where: email IS NOT NULL",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column email: text
+                index user_email_key:
+                    unique: true
+                    on user: email
+                    where: email IS NOT NULL",
+    )
+    .unwrap();
+
+    let expected = vec![Change::RenameIndexChange(RenameIndexChange {
+        schema: "public".to_string(),
+        from_index: "user_idx".to_string(),
+        to_index: "user_email_key".to_string(),
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_unchanged_index_predicate_is_kept() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column email: text
+                index user_email_idx:
+                    on user: email
+                    where: (email IS NOT NULL)",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column email: text
+                index user_email_idx:
+                    on user: email
+                    where: email IS NOT NULL",
+    )
+    .unwrap();
+
+    run_snapshot_diffing_test(source, target, vec![]);
+}
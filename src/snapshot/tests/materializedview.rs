@@ -0,0 +1,175 @@
+#[cfg(test)]
+use crate::{
+    definition::load_yaml_string,
+    snapshot::{
+        changes::{
+            Change, CreateIndexChange, CreateIndexChangeKey, CreateMaterializedViewChange,
+            DropIndexChange, DropMaterializedViewChange, RefreshMaterializedViewChange,
+        },
+        tests::utils::run_snapshot_diffing_test,
+    },
+};
+
+#[test]
+fn test_create_materialized_view_change() {
+    let source = load_yaml_string(
+        "
+            schema public:",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                materialized view user_count:
+                    as: SELECT count(*) AS total FROM users
+                    with data: false
+                    with: fillfactor=70",
+    )
+    .unwrap();
+
+    let expected = vec![Change::CreateMaterializedViewChange(
+        CreateMaterializedViewChange {
+            schema: "public".to_string(),
+            view: "user_count".to_string(),
+            query: "SELECT count(*) AS total FROM users".to_string(),
+            with_data: false,
+            storage_parameters: vec!["fillfactor=70".to_string()],
+            refresh: false,
+        },
+    )];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_drop_materialized_view_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                materialized view user_count:
+                    as: SELECT count(*) AS total FROM users",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:",
+    )
+    .unwrap();
+
+    let expected = vec![Change::DropMaterializedViewChange(
+        DropMaterializedViewChange {
+            schema: "public".to_string(),
+            view: "user_count".to_string(),
+        },
+    )];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_recreate_materialized_view_with_indexes() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                materialized view user_names:
+                    as: SELECT id, username FROM users
+
+                index user_names_username_idx:
+                    on user_names: username",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                materialized view user_names:
+                    as: SELECT id, lower(username) AS username FROM users
+
+                index user_names_username_idx:
+                    on user_names: username",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::DropIndexChange(DropIndexChange {
+            schema: "public".to_string(),
+            index: "user_names_username_idx".to_string(),
+        }),
+        Change::DropMaterializedViewChange(DropMaterializedViewChange {
+            schema: "public".to_string(),
+            view: "user_names".to_string(),
+        }),
+        Change::CreateMaterializedViewChange(CreateMaterializedViewChange {
+            schema: "public".to_string(),
+            view: "user_names".to_string(),
+            query: "SELECT id, lower(username) AS username FROM users".to_string(),
+            with_data: true,
+            storage_parameters: vec![],
+            refresh: false,
+        }),
+        Change::CreateIndexChange(CreateIndexChange {
+            schema: "public".to_string(),
+            table: "user_names".to_string(),
+            index: "user_names_username_idx".to_string(),
+            unique: false,
+            nulls_not_distinct: false,
+            method: "btree".to_string(),
+            keys: vec![CreateIndexChangeKey {
+                expression: "username".to_string(),
+                collation: None,
+                opclass: None,
+                order: "ASC".to_string(),
+                nulls: "LAST".to_string(),
+            }],
+            include: vec![],
+            predicate: None,
+            storage_parameters: vec![],
+            tablespace: None,
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_refresh_materialized_view_after_create() {
+    let source = load_yaml_string(
+        "
+            schema public:",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                materialized view user_names:
+                    as: SELECT id, username FROM users
+                    refresh: true",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::CreateMaterializedViewChange(CreateMaterializedViewChange {
+            schema: "public".to_string(),
+            view: "user_names".to_string(),
+            query: "SELECT id, username FROM users".to_string(),
+            with_data: true,
+            storage_parameters: vec![],
+            refresh: true,
+        }),
+        Change::RefreshMaterializedViewChange(RefreshMaterializedViewChange {
+            schema: "public".to_string(),
+            view: "user_names".to_string(),
+            with_data: true,
+        }),
+    ];
+
+    assert_eq!(
+        expected[0].render_sql(),
+        "CREATE MATERIALIZED VIEW \"public\".\"user_names\" AS SELECT id, username FROM users WITH NO DATA;"
+    );
+
+    run_snapshot_diffing_test(source, target, expected);
+}
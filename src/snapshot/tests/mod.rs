@@ -1,11 +1,28 @@
 mod check;
 mod column;
+mod comment;
+mod dependency;
+mod exclusion;
+mod extension;
 mod foreignkey;
 mod function;
+mod grant;
+mod hazard;
 mod index;
+mod materializedview;
+mod normalize;
+mod ordering;
+mod owner;
+mod partition;
+mod policy;
 mod primarykey;
+mod procedure;
+mod publication;
+mod rename;
 mod schema;
 mod sequence;
+mod statistics;
 mod table;
 mod unique;
-mod utils;
\ No newline at end of file
+mod utils;
+mod view;
\ No newline at end of file
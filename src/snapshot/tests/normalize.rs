@@ -0,0 +1,316 @@
+#[cfg(test)]
+use crate::{
+    definition::load_yaml_string,
+    snapshot::{
+        changes::{AlterColumnSetDataTypeChange, AlterColumnSetDefaultChange, Change},
+        tests::utils::run_snapshot_diffing_test,
+    },
+};
+
+#[test]
+fn test_type_aliases_compare_equal() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: int4
+                    column name: varchar(20)
+                    column score: float8
+                    column active: bool
+                    column joined_at: timestamptz(3)
+                    column tags: int8[]",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: integer
+                    column name: character varying(20)
+                    column score: double precision
+                    column active: boolean
+                    column joined_at: timestamp(3) with time zone
+                    column tags: bigint[]",
+    )
+    .unwrap();
+
+    run_snapshot_diffing_test(source, target, vec![]);
+}
+
+#[test]
+fn test_type_alias_change_is_kept() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column name: varchar(20)",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column name: character varying(40)",
+    )
+    .unwrap();
+
+    let expected = vec![Change::AlterColumnSetDataTypeChange(
+        AlterColumnSetDataTypeChange {
+            schema: "public".to_string(),
+            table: "user".to_string(),
+            column: "name".to_string(),
+            data_type: "character varying(40)".to_string(),
+            collation: None,
+            using: None,
+            revert_using: None,
+        },
+    )];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_default_casts_compare_equal() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column rank: int4 = '-1'::integer
+                    column name: varchar = 'x'::character varying
+                    column bio: text = 'y'::text
+                    column joined_at: timestamptz = now()",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column rank: integer = -1
+                    column name: character varying = 'x'
+                    column bio: text = 'y'
+                    column joined_at: timestamptz = NOW()",
+    )
+    .unwrap();
+
+    run_snapshot_diffing_test(source, target, vec![]);
+}
+
+#[test]
+fn test_default_change_is_kept() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column bio: text = 'y'::text",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column bio: text = 'Y'",
+    )
+    .unwrap();
+
+    let expected = vec![Change::AlterColumnSetDefaultChange(
+        AlterColumnSetDefaultChange {
+            schema: "public".to_string(),
+            table: "user".to_string(),
+            column: "bio".to_string(),
+            default: Some("'Y'".to_string()),
+        },
+    )];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_check_expressions_compare_equal() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column age: int4
+                    constraint user_age_check:
+                        check: ((\"age\" > 0))",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column age: int4
+                    constraint user_age_check:
+                        check: age>0",
+    )
+    .unwrap();
+
+    run_snapshot_diffing_test(source, target, vec![]);
+}
+
+#[test]
+fn test_view_queries_compare_equal() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: int4
+                view adult:
+                    as: \" SELECT id\n   FROM \\\"user\\\"\n  WHERE id > 0;\"",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: int4
+                view adult:
+                    as: select id from \"user\" where id > 0",
+    )
+    .unwrap();
+
+    run_snapshot_diffing_test(source, target, vec![]);
+}
+
+#[test]
+fn test_function_return_types_compare_equal() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                function one():
+                    returns: integer
+                    as: SELECT 1
+                function ones():
+                    returns: setof integer
+                    as: SELECT 1",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                function one():
+                    returns: int4
+                    as: SELECT 1
+                function ones():
+                    returns: SETOF int
+                    as: SELECT 1",
+    )
+    .unwrap();
+
+    run_snapshot_diffing_test(source, target, vec![]);
+}
+
+#[test]
+fn test_exclusion_expressions_compare_equal() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table booking:
+                    column room: int4
+                    column during: tsrange
+                    constraint booking_no_overlap:
+                        exclude: [during WITH &&]
+                        where: ((room > 0))",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table booking:
+                    column room: int4
+                    column during: tsrange
+                    constraint booking_no_overlap:
+                        exclude: [DURING WITH &&]
+                        where: room>0",
+    )
+    .unwrap();
+
+    run_snapshot_diffing_test(source, target, vec![]);
+}
+
+#[test]
+fn test_policy_expressions_compare_equal() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column owner: text
+                    policy own_rows:
+                        using: (owner = CURRENT_USER)
+                        with check: (owner <> 'nobody'::text)",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column owner: text
+                    policy own_rows:
+                        using: owner = current_user
+                        with check: owner <> 'nobody'",
+    )
+    .unwrap();
+
+    run_snapshot_diffing_test(source, target, vec![]);
+}
+
+#[test]
+fn test_index_expressions_compare_equal() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column email: text
+                index user_email_idx:
+                    on user: [lower(email)]",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column email: text
+                index user_email_idx:
+                    on user: [LOWER(\"email\")]",
+    )
+    .unwrap();
+
+    run_snapshot_diffing_test(source, target, vec![]);
+}
+
+#[test]
+fn test_statistics_expressions_compare_equal() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column a: int4
+                    column b: int4
+                    statistics user_a_b:
+                        on: [a, (a + b)]",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column a: int4
+                    column b: int4
+                    statistics user_a_b:
+                        on: [\"a\", (a+b)]",
+    )
+    .unwrap();
+
+    run_snapshot_diffing_test(source, target, vec![]);
+}
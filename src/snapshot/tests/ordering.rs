@@ -0,0 +1,78 @@
+#[cfg(test)]
+use crate::{
+    definition::load_yaml_string,
+    snapshot::{changes::Change, compare_diff},
+};
+
+#[cfg(test)]
+fn rendered(changes: &[Change]) -> Vec<String> {
+    changes.iter().map(|c| c.render_sql()).collect()
+}
+
+#[test]
+fn test_changes_follow_definition_order() {
+    let source = load_yaml_string(
+        "
+            schema public:",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table zebra:
+                    column id: uuid
+                table antelope:
+                    column id: uuid
+                table moose:
+                    column id: uuid",
+    )
+    .unwrap();
+
+    let changes = compare_diff(&source, &target);
+    assert_eq!(
+        rendered(&changes),
+        vec![
+            "CREATE TABLE \"public\".\"zebra\" (\"id\" uuid NULL);",
+            "CREATE TABLE \"public\".\"antelope\" (\"id\" uuid NULL);",
+            "CREATE TABLE \"public\".\"moose\" (\"id\" uuid NULL);",
+        ]
+    );
+
+    let changes = compare_diff(&target, &source);
+    assert_eq!(
+        rendered(&changes),
+        vec![
+            "DROP TABLE \"public\".\"zebra\";",
+            "DROP TABLE \"public\".\"antelope\";",
+            "DROP TABLE \"public\".\"moose\";",
+        ]
+    );
+}
+
+#[test]
+fn test_diff_is_reproducible() {
+    let definition = "
+        schema public:
+            table book:
+                column id: uuid
+                column title: text
+                constraint book_pkey:
+                    primary key: id
+                constraint book_title_check:
+                    check: (title <> '')
+            index book_title_idx:
+                on book: title
+            view book_titles:
+                as: SELECT title FROM public.book
+        schema archive:
+            table book:
+                column id: uuid";
+
+    let source = load_yaml_string("").unwrap();
+    let first = compare_diff(&source, &load_yaml_string(definition).unwrap());
+    for _ in 0..5 {
+        let again = compare_diff(&source, &load_yaml_string(definition).unwrap());
+        assert_eq!(first, again);
+    }
+}
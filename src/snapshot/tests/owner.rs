@@ -0,0 +1,278 @@
+#[cfg(test)]
+use crate::{
+    definition::load_yaml_string,
+    snapshot::{
+        changes::{Change, CreateProcedureChange, CreateSequenceChange, OwnerChange, OwnerTarget},
+        compare_diff,
+        tests::utils::run_snapshot_diffing_test,
+    },
+};
+
+#[test]
+fn test_create_sequence_with_owner_change() {
+    let source = load_yaml_string(
+        "
+            schema public:",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                sequence counter:
+                    owner: app_owner",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::CreateSequenceChange(CreateSequenceChange {
+            schema: "public".to_string(),
+            sequence: "counter".to_string(),
+            data_type: "int8".to_string(),
+            increment: 1,
+            min_value: 1,
+            max_value: 9223372036854775807,
+            start: 1,
+            cache: 1,
+            cycle: false,
+        }),
+        Change::OwnerChange(OwnerChange {
+            target: OwnerTarget::Sequence {
+                schema: "public".to_string(),
+                sequence: "counter".to_string(),
+            },
+            owner: Some("app_owner".to_string()),
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_table_owner_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    owner: postgres
+                    column id: integer",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    owner: app_owner
+                    column id: integer",
+    )
+    .unwrap();
+
+    let expected = vec![Change::OwnerChange(OwnerChange {
+        target: OwnerTarget::Table {
+            schema: "public".to_string(),
+            table: "user".to_string(),
+        },
+        owner: Some("app_owner".to_string()),
+    })];
+
+    assert_eq!(
+        expected[0].render_sql(),
+        "ALTER TABLE \"public\".\"user\" OWNER TO \"app_owner\";"
+    );
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_materialized_view_owner_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                materialized view order_totals:
+                    as: SELECT 1 AS total",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                materialized view order_totals:
+                    owner: app_owner
+                    as: SELECT 1 AS total",
+    )
+    .unwrap();
+
+    let expected = vec![Change::OwnerChange(OwnerChange {
+        target: OwnerTarget::MaterializedView {
+            schema: "public".to_string(),
+            view: "order_totals".to_string(),
+        },
+        owner: Some("app_owner".to_string()),
+    })];
+
+    assert_eq!(
+        expected[0].render_sql(),
+        "ALTER MATERIALIZED VIEW \"public\".\"order_totals\" OWNER TO \"app_owner\";"
+    );
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_create_procedure_with_owner_change() {
+    let source = load_yaml_string(
+        "
+            schema public:",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                procedure touch_user:
+                    owner: app_owner
+                    as: SELECT 1;",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::CreateProcedureChange(CreateProcedureChange {
+            schema: "public".to_string(),
+            procedure: "touch_user".to_string(),
+            arguments: "".to_string(),
+            body: "SELECT 1;".to_string(),
+            language: "sql".to_string(),
+        }),
+        Change::OwnerChange(OwnerChange {
+            target: OwnerTarget::Procedure {
+                schema: "public".to_string(),
+                procedure: "touch_user".to_string(),
+            },
+            owner: Some("app_owner".to_string()),
+        }),
+    ];
+
+    assert_eq!(
+        expected[1].render_sql(),
+        "ALTER PROCEDURE \"public\".\"touch_user\" OWNER TO \"app_owner\";"
+    );
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_unmanaged_owner_is_left_alone() {
+    let source = load_yaml_string(
+        "
+            schema app:
+                owner: app_owner",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema app:",
+    )
+    .unwrap();
+
+    assert_eq!(compare_diff(&source, &target), vec![]);
+}
+
+#[test]
+fn test_default_owner_applies_to_new_objects() {
+    let source = load_yaml_string(
+        "
+            schema app:
+                table user:
+                    column id: integer",
+    )
+    .unwrap();
+
+    let mut target = load_yaml_string(
+        "
+            schema app:
+                table user:
+                    column id: integer
+                view active_user:
+                    as: SELECT * FROM app.user
+                materialized view user_count:
+                    as: SELECT count(*) FROM app.user
+                function now_utc():
+                    owner: postgres
+                    as: SELECT now()
+                procedure touch_user:
+                    as: SELECT 1;",
+    )
+    .unwrap();
+    target.set_default_owner(&source, "app_owner");
+
+    let schema = target.get_schema("app").unwrap();
+    assert_eq!(schema.owner, None);
+    assert_eq!(
+        schema
+            .get_relation("user")
+            .unwrap()
+            .as_table()
+            .unwrap()
+            .owner,
+        None
+    );
+    assert_eq!(
+        schema
+            .get_relation("active_user")
+            .unwrap()
+            .as_view()
+            .unwrap()
+            .owner,
+        Some("app_owner".to_string())
+    );
+    assert_eq!(
+        schema
+            .get_relation("user_count")
+            .unwrap()
+            .as_materialized_view()
+            .unwrap()
+            .owner,
+        Some("app_owner".to_string())
+    );
+    assert_eq!(
+        schema.get_function("now_utc()").unwrap().owner,
+        Some("postgres".to_string())
+    );
+    assert_eq!(
+        schema.get_procedure("touch_user").unwrap().owner,
+        Some("app_owner".to_string())
+    );
+
+    target.clear_owners();
+    let schema = target.get_schema("app").unwrap();
+    assert_eq!(
+        schema
+            .get_relation("active_user")
+            .unwrap()
+            .as_view()
+            .unwrap()
+            .owner,
+        None
+    );
+    assert_eq!(schema.get_function("now_utc()").unwrap().owner, None);
+    assert_eq!(schema.get_procedure("touch_user").unwrap().owner, None);
+}
+
+#[test]
+fn test_ignored_ownership_clears_both_sides() {
+    let mut source = load_yaml_string(
+        "
+            schema app:
+                owner: postgres",
+    )
+    .unwrap();
+
+    let mut target = load_yaml_string(
+        "
+            schema app:
+                owner: app_owner",
+    )
+    .unwrap();
+    target.apply_ownership(&mut source, true, Some("app_owner"));
+
+    assert_eq!(compare_diff(&source, &target), vec![]);
+}
@@ -0,0 +1,324 @@
+#[cfg(test)]
+use crate::{
+    definition::load_yaml_string,
+    snapshot::{
+        changes::{
+            AttachPartitionChange, Change, CreateTableChange, CreateTableChangeColumn,
+            CreateSchemaChange, CreateTableChangePartitionKey, DetachPartitionChange, DropTableChange,
+        },
+        tests::utils::run_snapshot_diffing_test,
+    },
+};
+
+#[test]
+fn test_create_partitioned_table_change() {
+    let source = load_yaml_string(
+        "
+            schema public:",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table event:
+                    column id: int
+                    column created_at: date
+                    partition by range: created_at",
+    )
+    .unwrap();
+
+    let expected = vec![Change::CreateTableChange(CreateTableChange {
+        schema: "public".to_string(),
+        table: "event".to_string(),
+        columns: vec![
+            CreateTableChangeColumn {
+                name: "id".to_string(),
+                data_type: "int".to_string(),
+                collation: None,
+                compression: None,
+                default: None,
+                not_null: false,
+                generated: None,
+                identity: None,
+            },
+            CreateTableChangeColumn {
+                name: "created_at".to_string(),
+                data_type: "date".to_string(),
+                collation: None,
+                compression: None,
+                default: None,
+                not_null: false,
+                generated: None,
+                identity: None,
+            },
+        ],
+        partition_by: Some(CreateTableChangePartitionKey {
+            strategy: "range".to_string(),
+            key_expressions: vec!["created_at".to_string()],
+        }),
+        unlogged: false,
+        storage_parameters: vec![],
+        tablespace: None,
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_create_partition_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table event:
+                    column id: int
+                    partition by list: id",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table event:
+                    column id: int
+                    partition by list: id
+
+                table event_1:
+                    column id: int
+                    partition of event: FOR VALUES IN (1)",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::CreateTableChange(CreateTableChange {
+            schema: "public".to_string(),
+            table: "event_1".to_string(),
+            columns: vec![CreateTableChangeColumn {
+                name: "id".to_string(),
+                data_type: "int".to_string(),
+                collation: None,
+                compression: None,
+                default: None,
+                not_null: false,
+                generated: None,
+                identity: None,
+            }],
+            partition_by: None,
+            unlogged: false,
+            storage_parameters: vec![],
+            tablespace: None,
+        }),
+        Change::AttachPartitionChange(AttachPartitionChange {
+            schema: "public".to_string(),
+            table: "event".to_string(),
+            partition_schema: "public".to_string(),
+            partition: "event_1".to_string(),
+            bound: "FOR VALUES IN (1)".to_string(),
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_change_partition_bound() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table event:
+                    column id: int
+                    partition by list: id
+
+                table event_1:
+                    column id: int
+                    partition of event: FOR VALUES IN (1)",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table event:
+                    column id: int
+                    partition by list: id
+
+                table event_1:
+                    column id: int
+                    partition of event: FOR VALUES IN (1, 2)",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::DetachPartitionChange(DetachPartitionChange {
+            schema: "public".to_string(),
+            table: "event".to_string(),
+            partition_schema: "public".to_string(),
+            partition: "event_1".to_string(),
+        }),
+        Change::AttachPartitionChange(AttachPartitionChange {
+            schema: "public".to_string(),
+            table: "event".to_string(),
+            partition_schema: "public".to_string(),
+            partition: "event_1".to_string(),
+            bound: "FOR VALUES IN (1, 2)".to_string(),
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_drop_partition_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table event:
+                    column id: int
+                    partition by list: id
+
+                table event_1:
+                    column id: int
+                    partition of event: FOR VALUES IN (1)",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table event:
+                    column id: int
+                    partition by list: id",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::DetachPartitionChange(DetachPartitionChange {
+            schema: "public".to_string(),
+            table: "event".to_string(),
+            partition_schema: "public".to_string(),
+            partition: "event_1".to_string(),
+        }),
+        Change::DropTableChange(DropTableChange {
+            schema: "public".to_string(),
+            table: "event_1".to_string(),
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_create_partition_in_another_schema_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table event:
+                    column id: int
+                    partition by list: id",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema archive:
+                table event_1:
+                    column id: int
+                    partition of public.event: FOR VALUES IN (1)
+
+            schema public:
+                table event:
+                    column id: int
+                    partition by list: id",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::CreateSchemaChange(CreateSchemaChange {
+            schema_name: "archive".to_string(),
+        }),
+        Change::CreateTableChange(CreateTableChange {
+            schema: "archive".to_string(),
+            table: "event_1".to_string(),
+            columns: vec![CreateTableChangeColumn {
+                name: "id".to_string(),
+                data_type: "int".to_string(),
+                collation: None,
+                compression: None,
+                default: None,
+                not_null: false,
+                generated: None,
+                identity: None,
+            }],
+            partition_by: None,
+            unlogged: false,
+            storage_parameters: vec![],
+            tablespace: None,
+        }),
+        Change::AttachPartitionChange(AttachPartitionChange {
+            schema: "public".to_string(),
+            table: "event".to_string(),
+            partition_schema: "archive".to_string(),
+            partition: "event_1".to_string(),
+            bound: "FOR VALUES IN (1)".to_string(),
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_change_partition_parent_schema() {
+    let source = load_yaml_string(
+        "
+            schema archive:
+                table event:
+                    column id: int
+                    partition by list: id
+
+            schema public:
+                table event:
+                    column id: int
+                    partition by list: id
+
+                table event_1:
+                    column id: int
+                    partition of event: FOR VALUES IN (1)",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema archive:
+                table event:
+                    column id: int
+                    partition by list: id
+
+            schema public:
+                table event:
+                    column id: int
+                    partition by list: id
+
+                table event_1:
+                    column id: int
+                    partition of archive.event: FOR VALUES IN (1)",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::DetachPartitionChange(DetachPartitionChange {
+            schema: "public".to_string(),
+            table: "event".to_string(),
+            partition_schema: "public".to_string(),
+            partition: "event_1".to_string(),
+        }),
+        Change::AttachPartitionChange(AttachPartitionChange {
+            schema: "archive".to_string(),
+            table: "event".to_string(),
+            partition_schema: "public".to_string(),
+            partition: "event_1".to_string(),
+            bound: "FOR VALUES IN (1)".to_string(),
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
@@ -0,0 +1,176 @@
+#[cfg(test)]
+use crate::{
+    definition::load_yaml_string,
+    snapshot::{
+        changes::{
+            AlterPolicyChange, AlterTableRowLevelSecurityChange, Change, CreatePolicyChange,
+            DropPolicyChange,
+        },
+        tests::utils::run_snapshot_diffing_test,
+    },
+};
+
+#[test]
+fn test_enable_row_level_security_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table account:
+                    column tenant_id: integer",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table account:
+                    column tenant_id: integer
+                    row level security: true
+                    policy tenant_isolation:
+                        to: [app_rw]
+                        using: tenant_id = 1",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::AlterTableRowLevelSecurityChange(AlterTableRowLevelSecurityChange {
+            schema: "public".to_string(),
+            table: "account".to_string(),
+            enabled: true,
+            forced: false,
+        }),
+        Change::CreatePolicyChange(CreatePolicyChange {
+            schema: "public".to_string(),
+            table: "account".to_string(),
+            policy: "tenant_isolation".to_string(),
+            command: "ALL".to_string(),
+            permissive: true,
+            roles: vec!["app_rw".to_string()],
+            using: Some("tenant_id = 1".to_string()),
+            with_check: None,
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_policy_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table account:
+                    column tenant_id: integer
+                    row level security: true
+                    policy tenant_isolation:
+                        using: tenant_id = 1",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table account:
+                    column tenant_id: integer
+                    row level security: true
+                    policy tenant_isolation:
+                        to: [app_ro, app_rw]
+                        using: tenant_id = 2",
+    )
+    .unwrap();
+
+    let expected = vec![Change::AlterPolicyChange(AlterPolicyChange {
+        schema: "public".to_string(),
+        table: "account".to_string(),
+        policy: "tenant_isolation".to_string(),
+        roles: vec!["app_ro".to_string(), "app_rw".to_string()],
+        using: Some("tenant_id = 2".to_string()),
+        with_check: None,
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_recreate_policy_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table account:
+                    column tenant_id: integer
+                    policy tenant_isolation:
+                        using: tenant_id = 1",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table account:
+                    column tenant_id: integer
+                    policy tenant_isolation:
+                        for: select
+                        as: restrictive
+                        using: tenant_id = 1",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::DropPolicyChange(DropPolicyChange {
+            schema: "public".to_string(),
+            table: "account".to_string(),
+            policy: "tenant_isolation".to_string(),
+        }),
+        Change::CreatePolicyChange(CreatePolicyChange {
+            schema: "public".to_string(),
+            table: "account".to_string(),
+            policy: "tenant_isolation".to_string(),
+            command: "SELECT".to_string(),
+            permissive: false,
+            roles: vec!["public".to_string()],
+            using: Some("tenant_id = 1".to_string()),
+            with_check: None,
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_drop_policy_and_disable_row_level_security_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table account:
+                    column tenant_id: integer
+                    row level security: true
+                    force row level security: true
+                    policy tenant_isolation:
+                        with check: tenant_id > 0",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table account:
+                    column tenant_id: integer",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::DropPolicyChange(DropPolicyChange {
+            schema: "public".to_string(),
+            table: "account".to_string(),
+            policy: "tenant_isolation".to_string(),
+        }),
+        Change::AlterTableRowLevelSecurityChange(AlterTableRowLevelSecurityChange {
+            schema: "public".to_string(),
+            table: "account".to_string(),
+            enabled: false,
+            forced: false,
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
@@ -0,0 +1,141 @@
+#[cfg(test)]
+use crate::{
+    definition::load_yaml_string,
+    introspection::introspect,
+    snapshot::{
+        changes::{Change, CreateProcedureChange, DropProcedureChange, ReplaceProcedureChange},
+        compare_diff,
+        tests::utils::run_snapshot_diffing_test,
+        Database,
+    },
+    util::test::get_test_connection,
+};
+
+#[test]
+fn test_create_procedure_change() {
+    let source = load_yaml_string(
+        "
+            schema public:",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                procedure touch_user:
+                    arguments: user_id uuid
+                    as: UPDATE users SET updated_at = now() WHERE id = user_id;",
+    )
+    .unwrap();
+
+    let expected = vec![Change::CreateProcedureChange(CreateProcedureChange {
+        schema: "public".to_string(),
+        procedure: "touch_user".to_string(),
+        arguments: "user_id uuid".to_string(),
+        body: "UPDATE users SET updated_at = now() WHERE id = user_id;".to_string(),
+        language: "sql".to_string(),
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_replace_procedure_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                procedure touch_user:
+                    arguments: user_id uuid
+                    as: UPDATE users SET updated_at = now() WHERE id = user_id;",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                procedure touch_user:
+                    arguments: user_id uuid
+                    as: UPDATE users SET touched_at = now() WHERE id = user_id;",
+    )
+    .unwrap();
+
+    let expected = vec![Change::ReplaceProcedureChange(ReplaceProcedureChange {
+        schema: "public".to_string(),
+        procedure: "touch_user".to_string(),
+        arguments: "user_id uuid".to_string(),
+        body: "UPDATE users SET touched_at = now() WHERE id = user_id;".to_string(),
+        language: "sql".to_string(),
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_drop_procedure_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                procedure touch_user:
+                    arguments: user_id uuid
+                    as: UPDATE users SET updated_at = now() WHERE id = user_id;",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:",
+    )
+    .unwrap();
+
+    let expected = vec![Change::DropProcedureChange(DropProcedureChange {
+        schema: "public".to_string(),
+        procedure: "touch_user".to_string(),
+        arguments: "user_id uuid".to_string(),
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_procedure_arguments_compare_equal() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                procedure archive_orders:
+                    arguments: IN before date, IN keep integer DEFAULT 10, INOUT total numeric
+                    as: SELECT 1;",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                procedure archive_orders:
+                    arguments: before date, keep int = 10, inout total numeric(10, 2)
+                    as: SELECT 1;",
+    )
+    .unwrap();
+
+    run_snapshot_diffing_test(source, target, vec![]);
+}
+
+#[test]
+fn test_staged_procedure_diffs_empty() {
+    let target = load_yaml_string(
+        "
+            schema test_procedure_stage:
+                procedure archive_orders:
+                    arguments: before date, keep int = 10
+                    as: SELECT 1;",
+    )
+    .unwrap();
+
+    let mut conn = get_test_connection();
+    let mut tx = conn.transaction().unwrap();
+    for change in compare_diff(&Database::new(), &target) {
+        tx.batch_execute(&change.render_sql()).unwrap();
+    }
+    let staged = introspect(&mut tx, &["test_procedure_stage"]).unwrap();
+
+    assert_eq!(compare_diff(&staged, &target), vec![]);
+}
@@ -0,0 +1,129 @@
+#[cfg(test)]
+use crate::{
+    definition::load_yaml_string,
+    snapshot::{
+        changes::{
+            AlterPublicationAddTableChange, AlterPublicationDropTableChange, Change,
+            CreatePublicationChange, DropPublicationChange, PublicationTableItem,
+        },
+        tests::utils::run_snapshot_diffing_test,
+    },
+};
+
+#[test]
+fn test_create_publication_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table order:
+                    column id: uuid
+                    column total: int",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table order:
+                    column id: uuid
+                    column total: int
+
+            publication warehouse:
+                table public.order:
+                    columns: [id, total]
+                    where: (total > 0)",
+    )
+    .unwrap();
+
+    let expected = vec![Change::CreatePublicationChange(CreatePublicationChange {
+        publication: "warehouse".to_string(),
+        tables: vec![PublicationTableItem {
+            schema: "public".to_string(),
+            table: "order".to_string(),
+            columns: vec!["id".to_string(), "total".to_string()],
+            row_filter: Some("(total > 0)".to_string()),
+        }],
+    })];
+
+    assert_eq!(
+        expected[0].render_sql(),
+        "CREATE PUBLICATION \"warehouse\" FOR \
+         TABLE \"public\".\"order\" (\"id\", \"total\") WHERE ((total > 0));"
+    );
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_drop_publication_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table order:
+                    column id: uuid
+
+            publication warehouse:
+                table public.order:",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table order:
+                    column id: uuid",
+    )
+    .unwrap();
+
+    let expected = vec![Change::DropPublicationChange(DropPublicationChange {
+        publication: "warehouse".to_string(),
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_publication_tables_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table order:
+                    column id: uuid
+                    column total: int
+
+            publication warehouse:
+                table public.order:",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table order:
+                    column id: uuid
+                    column total: int
+
+            publication warehouse:
+                table public.order:
+                    where: (total > 0)",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::AlterPublicationDropTableChange(AlterPublicationDropTableChange {
+            publication: "warehouse".to_string(),
+            schema: "public".to_string(),
+            table: "order".to_string(),
+        }),
+        Change::AlterPublicationAddTableChange(AlterPublicationAddTableChange {
+            publication: "warehouse".to_string(),
+            table: PublicationTableItem {
+                schema: "public".to_string(),
+                table: "order".to_string(),
+                columns: vec![],
+                row_filter: Some("(total > 0)".to_string()),
+            },
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
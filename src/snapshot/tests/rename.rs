@@ -0,0 +1,393 @@
+#[cfg(test)]
+use crate::{
+    definition::load_yaml_string,
+    snapshot::{
+        changes::{
+            Change, CreateTableChange, DropTableChange, RenameColumnChange, RenameConstraintChange,
+            RenameIndexChange, RenameSchemaChange, RenameSequenceChange, RenameTableChange,
+            RenameViewChange,
+        },
+        compare_diff, propose_renames,
+        tests::utils::run_snapshot_diffing_test,
+        Rename,
+    },
+};
+
+#[test]
+fn test_rename_schema_change() {
+    let source = load_yaml_string(
+        "
+            schema legacy:
+                table user:
+                    column id: uuid",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema app:
+                renamed from: legacy
+                table user:
+                    column id: uuid",
+    )
+    .unwrap();
+
+    let expected = vec![Change::RenameSchemaChange(RenameSchemaChange {
+        from_schema: "legacy".to_string(),
+        to_schema: "app".to_string(),
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_rename_table_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    constraint user_pkey:
+                        primary key: id
+                table session:
+                    column user_id: uuid
+                    constraint session_user_fkey:
+                        foreign key: user_id
+                        references user: id
+                index user_id_idx:
+                    on user: id",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table account:
+                    renamed from: user
+                    column id: uuid
+                    constraint user_pkey:
+                        primary key: id
+                table session:
+                    column user_id: uuid
+                    constraint session_user_fkey:
+                        foreign key: user_id
+                        references account: id
+                index user_id_idx:
+                    on account: id",
+    )
+    .unwrap();
+
+    let expected = vec![Change::RenameTableChange(RenameTableChange {
+        schema: "public".to_string(),
+        from_table: "user".to_string(),
+        to_table: "account".to_string(),
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_rename_column_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column mail: text
+                    constraint user_mail_key:
+                        unique: mail
+                index user_mail_idx:
+                    on user: mail",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email:
+                        type: text
+                        renamed from: mail
+                    constraint user_mail_key:
+                        unique: email
+                index user_mail_idx:
+                    on user: email",
+    )
+    .unwrap();
+
+    let expected = vec![Change::RenameColumnChange(RenameColumnChange {
+        from_schema: "public".to_string(),
+        from_table: "user".to_string(),
+        from_column: "mail".to_string(),
+        to_column: "email".to_string(),
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_rename_inline_hint_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column mail: text",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table account (was user):
+                    column id: uuid
+                    column email (was mail): text",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::RenameTableChange(RenameTableChange {
+            schema: "public".to_string(),
+            from_table: "user".to_string(),
+            to_table: "account".to_string(),
+        }),
+        Change::RenameColumnChange(RenameColumnChange {
+            from_schema: "public".to_string(),
+            from_table: "account".to_string(),
+            from_column: "mail".to_string(),
+            to_column: "email".to_string(),
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_rename_constraint_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    constraint user_pk:
+                        primary key: id",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    constraint user_pkey:
+                        renamed from: user_pk
+                        primary key: id",
+    )
+    .unwrap();
+
+    let expected = vec![Change::RenameConstraintChange(RenameConstraintChange {
+        schema: "public".to_string(),
+        table: "user".to_string(),
+        from_constraint: "user_pk".to_string(),
+        to_constraint: "user_pkey".to_string(),
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_rename_index_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                index user_idx:
+                    on user: id",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                index user_id_idx:
+                    renamed from: user_idx
+                    on user: id",
+    )
+    .unwrap();
+
+    let expected = vec![Change::RenameIndexChange(RenameIndexChange {
+        schema: "public".to_string(),
+        from_index: "user_idx".to_string(),
+        to_index: "user_id_idx".to_string(),
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_rename_sequence_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                sequence user_seq:
+                    as: int8",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                sequence user_id_seq:
+                    renamed from: user_seq
+                    as: int8",
+    )
+    .unwrap();
+
+    let expected = vec![Change::RenameSequenceChange(RenameSequenceChange {
+        schema: "public".to_string(),
+        from_sequence: "user_seq".to_string(),
+        to_sequence: "user_id_seq".to_string(),
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_rename_view_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                view one:
+                    as: SELECT 1",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                view single:
+                    renamed from: one
+                    as: SELECT 1",
+    )
+    .unwrap();
+
+    let expected = vec![Change::RenameViewChange(RenameViewChange {
+        schema: "public".to_string(),
+        from_view: "one".to_string(),
+        to_view: "single".to_string(),
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_rename_is_followed_by_other_changes() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column nickname: text",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table account:
+                    renamed from: user
+                    column uid:
+                        type: uuid
+                        renamed from: id",
+    )
+    .unwrap();
+
+    let changes = compare_diff(&source, &target);
+    let rendered: Vec<String> = changes.iter().map(|c| c.render_sql()).collect();
+    assert_eq!(
+        rendered,
+        vec![
+            "ALTER TABLE \"public\".\"user\" RENAME TO \"account\";",
+            "ALTER TABLE \"public\".\"account\" RENAME COLUMN \"id\" TO \"uid\";",
+            "ALTER TABLE \"public\".\"account\" DROP COLUMN \"nickname\";",
+        ]
+    );
+}
+
+#[test]
+fn test_stale_rename_hint_is_ignored() {
+    // the old name is gone from the source, so the table is created
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table account:
+                    renamed from: member
+                    column id: uuid",
+    )
+    .unwrap();
+
+    let changes = compare_diff(&source, &target);
+    assert!(changes.iter().any(|c| matches!(c, Change::CreateTableChange(CreateTableChange { table, .. }) if table == "account")));
+    assert!(changes.iter().any(
+        |c| matches!(c, Change::DropTableChange(DropTableChange { table, .. }) if table == "user")
+    ));
+}
+
+#[test]
+fn test_propose_renames() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                table post:
+                    column id: uuid
+                    column title: text
+                    column body: text
+                    constraint post_pk:
+                        primary key: id
+                view recent_post:
+                    as: SELECT 1",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table account:
+                    column id: uuid
+                table post:
+                    column id: uuid
+                    column headline: text
+                    column content:
+                        type: text
+                        renamed from: body
+                    column created_at: timestamptz
+                    constraint post_pkey:
+                        primary key: id
+                view latest_post:
+                    as: SELECT 2",
+    )
+    .unwrap();
+
+    assert_eq!(
+        propose_renames(&source, &target),
+        vec![
+            Rename::relation("public", "user", "account"),
+            Rename::column("public", "post", "title", "headline"),
+            Rename::constraint("public", "post", "post_pk", "post_pkey"),
+        ]
+    );
+}
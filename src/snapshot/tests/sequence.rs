@@ -2,7 +2,10 @@
 use crate::{
     definition::load_yaml_string,
     snapshot::{
-        changes::{Change, CreateSequenceChange, DropSequenceChange},
+        changes::{
+            AlterSequenceChange, Change, CreateSequenceChange, DropSequenceChange, SequenceOwnedBy,
+        },
+        compare_diff,
         tests::utils::run_snapshot_diffing_test,
     },
 };
@@ -61,3 +64,143 @@ fn test_drop_sequence_change() {
 
     run_snapshot_diffing_test(source, target, expected);
 }
+
+#[test]
+fn test_alter_sequence_owned_by_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: int8
+                sequence user_id_seq:
+                    as: int8",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: int8
+                sequence user_id_seq:
+                    as: int8
+                    owned by user: id",
+    )
+    .unwrap();
+
+    let expected = vec![Change::AlterSequenceChange(AlterSequenceChange {
+        schema: "public".to_string(),
+        sequence: "user_id_seq".to_string(),
+        data_type: None,
+        increment: None,
+        min_value: None,
+        max_value: None,
+        start: None,
+        cache: None,
+        cycle: None,
+        owned_by: Some(SequenceOwnedBy::Column {
+            table: "user".to_string(),
+            column: "id".to_string(),
+        }),
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_sequence_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                sequence ticket_seq:
+                    as: int4",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                sequence ticket_seq:
+                    as: int4
+                    increment: 10
+                    maxvalue: 100000
+                    cache: 20
+                    cycle: true",
+    )
+    .unwrap();
+
+    let expected = vec![Change::AlterSequenceChange(AlterSequenceChange {
+        schema: "public".to_string(),
+        sequence: "ticket_seq".to_string(),
+        data_type: None,
+        increment: Some(10),
+        min_value: None,
+        max_value: Some(100000),
+        start: None,
+        cache: Some(20),
+        cycle: Some(true),
+        owned_by: None,
+    })];
+
+    let changes = compare_diff(&source, &target);
+    assert_eq!(
+        changes[0].render_sql(),
+        "ALTER SEQUENCE \"public\".\"ticket_seq\" INCREMENT BY 10 MAXVALUE 100000 CACHE 20 CYCLE;"
+    );
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_serial_column_matches_owned_sequence() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: int8! = nextval('user_id_seq'::regclass)
+                sequence user_id_seq:
+                    as: int8
+                    owned by user: id",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: bigserial",
+    )
+    .unwrap();
+
+    run_snapshot_diffing_test(source, target, vec![]);
+}
+
+#[test]
+fn test_create_serial_column() {
+    let source = load_yaml_string(
+        "
+            schema public:",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: serial",
+    )
+    .unwrap();
+
+    let changes = compare_diff(&source, &target);
+    let rendered: Vec<String> = changes.iter().map(|c| c.render_sql()).collect();
+    assert_eq!(
+        rendered,
+        vec![
+            "CREATE SEQUENCE \"public\".\"user_id_seq\" AS int4 INCREMENT BY 1 MINVALUE 1 MAXVALUE 2147483647 START WITH 1 CACHE 1 NO CYCLE;",
+            "CREATE TABLE \"public\".\"user\" (\"id\" int4 NOT NULL DEFAULT nextval('user_id_seq'::regclass));",
+            "ALTER SEQUENCE \"public\".\"user_id_seq\" OWNED BY \"public\".\"user\".\"id\";",
+        ]
+    );
+
+    run_snapshot_diffing_test(source, target, changes);
+}
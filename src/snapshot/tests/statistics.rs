@@ -0,0 +1,134 @@
+#[cfg(test)]
+use crate::{
+    definition::load_yaml_string,
+    snapshot::{
+        changes::{AlterStatisticsChange, Change, CreateStatisticsChange, DropStatisticsChange},
+        tests::utils::run_snapshot_diffing_test,
+    },
+};
+
+#[test]
+fn test_create_statistics_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table address:
+                    column city: text
+                    column zip: text",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table address:
+                    column city: text
+                    column zip: text
+                    statistics address_city_zip:
+                        kinds: [ndistinct, dependencies]
+                        on: [city, zip]
+                        target: 1000",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::CreateStatisticsChange(CreateStatisticsChange {
+            schema: "public".to_string(),
+            table: "address".to_string(),
+            statistics: "address_city_zip".to_string(),
+            kinds: vec!["ndistinct".to_string(), "dependencies".to_string()],
+            expressions: vec!["city".to_string(), "zip".to_string()],
+        }),
+        Change::AlterStatisticsChange(AlterStatisticsChange {
+            schema: "public".to_string(),
+            table: "address".to_string(),
+            statistics: "address_city_zip".to_string(),
+            target: Some(1000),
+        }),
+    ];
+
+    assert_eq!(
+        expected[0].render_sql(),
+        "CREATE STATISTICS \"public\".\"address_city_zip\" (ndistinct, dependencies) \
+         ON city, zip FROM \"public\".\"address\";"
+    );
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_statistics_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table address:
+                    column city: text
+                    column zip: text
+                    statistics address_city_zip:
+                        on: [city, zip]
+                        target: 1000",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table address:
+                    column city: text
+                    column zip: text
+                    statistics address_city_zip:
+                        on: [city, zip]",
+    )
+    .unwrap();
+
+    let expected = vec![Change::AlterStatisticsChange(AlterStatisticsChange {
+        schema: "public".to_string(),
+        table: "address".to_string(),
+        statistics: "address_city_zip".to_string(),
+        target: None,
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_recreate_statistics_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table address:
+                    column city: text
+                    column zip: text
+                    statistics address_city_zip:
+                        on: [city, zip]",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table address:
+                    column city: text
+                    column zip: text
+                    statistics address_city_zip:
+                        kinds: [mcv]
+                        on: [city, (lower(zip))]",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::DropStatisticsChange(DropStatisticsChange {
+            schema: "public".to_string(),
+            table: "address".to_string(),
+            statistics: "address_city_zip".to_string(),
+        }),
+        Change::CreateStatisticsChange(CreateStatisticsChange {
+            schema: "public".to_string(),
+            table: "address".to_string(),
+            statistics: "address_city_zip".to_string(),
+            kinds: vec!["mcv".to_string()],
+            expressions: vec!["city".to_string(), "(lower(zip))".to_string()],
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
@@ -2,7 +2,13 @@
 use crate::{
     definition::load_yaml_string,
     snapshot::{
-        changes::{Change, CreateTableChange, CreateTableChangeColumn, DropTableChange},
+        changes::{
+            AlterTableInheritChange, AlterTableNoInheritChange, AlterTableReplicaIdentityChange,
+            AlterTableSetLoggedChange,
+            AlterTableSetStorageParametersChange, AlterTableSetTablespaceChange, Change,
+            CreateTableChange, CreateTableChangeColumn, DropTableChange,
+        },
+        compare_diff,
         tests::utils::run_snapshot_diffing_test,
     },
 };
@@ -31,21 +37,57 @@ fn test_create_table_change() {
             CreateTableChangeColumn {
                 name: "id".to_string(),
                 data_type: "uuid".to_string(),
+                collation: None,
+                compression: None,
                 default: None,
                 not_null: false,
+                generated: None,
+                identity: None,
             },
             CreateTableChangeColumn {
                 name: "username".to_string(),
                 data_type: "text".to_string(),
+                collation: None,
+                compression: None,
                 default: None,
                 not_null: false,
+                generated: None,
+                identity: None,
             },
         ],
+        partition_by: None,
+        unlogged: false,
+        storage_parameters: vec![],
+        tablespace: None,
     })];
 
     run_snapshot_diffing_test(source, target, expected);
 }
 
+#[test]
+fn test_create_table_renders_nullability() {
+    let source = load_yaml_string(
+        "
+            schema public:",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid!
+                    column nickname: text",
+    )
+    .unwrap();
+
+    let changes = compare_diff(&source, &target);
+    assert_eq!(
+        changes[0].render_sql(),
+        "CREATE TABLE \"public\".\"user\" (\"id\" uuid NOT NULL, \"nickname\" text NULL);"
+    );
+}
+
 #[test]
 fn test_drop_table_change() {
     let source = load_yaml_string(
@@ -70,3 +112,230 @@ fn test_drop_table_change() {
 
     run_snapshot_diffing_test(source, target, expected);
 }
+
+#[test]
+fn test_create_table_with_options_change() {
+    let source = load_yaml_string(
+        "
+            schema public:",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table staging:
+                    column id: uuid
+                    unlogged: true
+                    with: [fillfactor=70, autovacuum_enabled=false]
+                    tablespace: fast",
+    )
+    .unwrap();
+
+    let expected = vec![Change::CreateTableChange(CreateTableChange {
+        schema: "public".to_string(),
+        table: "staging".to_string(),
+        columns: vec![CreateTableChangeColumn {
+            name: "id".to_string(),
+            data_type: "uuid".to_string(),
+            collation: None,
+            compression: None,
+            not_null: false,
+            default: None,
+            generated: None,
+            identity: None,
+        }],
+        partition_by: None,
+        unlogged: true,
+        storage_parameters: vec![
+            "fillfactor=70".to_string(),
+            "autovacuum_enabled=false".to_string(),
+        ],
+        tablespace: Some("fast".to_string()),
+    })];
+
+    assert_eq!(
+        expected[0].render_sql(),
+        "CREATE UNLOGGED TABLE \"public\".\"staging\" (\"id\" uuid NULL) \
+         WITH (fillfactor=70, autovacuum_enabled=false) TABLESPACE \"fast\";"
+    );
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_table_options_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table event:
+                    column id: uuid
+                    unlogged: true
+                    with: [fillfactor=70, autovacuum_enabled=false]",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table event:
+                    column id: uuid
+                    with: [fillfactor=90, autovacuum_vacuum_scale_factor=0.05]
+                    tablespace: fast",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::AlterTableSetLoggedChange(AlterTableSetLoggedChange {
+            schema: "public".to_string(),
+            table: "event".to_string(),
+            unlogged: false,
+        }),
+        Change::AlterTableSetStorageParametersChange(AlterTableSetStorageParametersChange {
+            schema: "public".to_string(),
+            table: "event".to_string(),
+            set: vec![
+                "fillfactor=90".to_string(),
+                "autovacuum_vacuum_scale_factor=0.05".to_string(),
+            ],
+            reset: vec!["autovacuum_enabled".to_string()],
+        }),
+        Change::AlterTableSetTablespaceChange(AlterTableSetTablespaceChange {
+            schema: "public".to_string(),
+            table: "event".to_string(),
+            tablespace: Some("fast".to_string()),
+        }),
+    ];
+
+    assert_eq!(
+        expected[1].render_sql(),
+        "ALTER TABLE \"public\".\"event\" \
+         SET (fillfactor=90, autovacuum_vacuum_scale_factor=0.05), RESET (autovacuum_enabled);"
+    );
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_table_inherit_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table base:
+                    column id: uuid
+                table archive:
+                    column id: uuid
+                table legacy:
+                    column id: uuid
+                    inherits: base",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table base:
+                    column id: uuid
+                table archive:
+                    column id: uuid
+                    inherits: base
+                table legacy:
+                    column id: uuid",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::AlterTableNoInheritChange(AlterTableNoInheritChange {
+            schema: "public".to_string(),
+            table: "legacy".to_string(),
+            parent_schema: "public".to_string(),
+            parent: "base".to_string(),
+        }),
+        Change::AlterTableInheritChange(AlterTableInheritChange {
+            schema: "public".to_string(),
+            table: "archive".to_string(),
+            parent_schema: "public".to_string(),
+            parent: "base".to_string(),
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_table_inherit_from_another_schema_change() {
+    let source = load_yaml_string(
+        "
+            schema audit:
+                table entry:
+                    column at: timestamptz
+
+            schema public:
+                table order:
+                    column at: timestamptz",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema audit:
+                table entry:
+                    column at: timestamptz
+
+            schema public:
+                table order:
+                    column at: timestamptz
+                    inherits: audit.entry",
+    )
+    .unwrap();
+
+    let expected = vec![Change::AlterTableInheritChange(AlterTableInheritChange {
+        schema: "public".to_string(),
+        table: "order".to_string(),
+        parent_schema: "audit".to_string(),
+        parent: "entry".to_string(),
+    })];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_alter_table_replica_identity_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table order:
+                    column id: uuid!
+
+                index order_id_idx:
+                    on order: id
+                    unique: true",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table order:
+                    column id: uuid!
+                    replica identity: using index order_id_idx
+
+                index order_id_idx:
+                    on order: id
+                    unique: true",
+    )
+    .unwrap();
+
+    let expected = vec![Change::AlterTableReplicaIdentityChange(
+        AlterTableReplicaIdentityChange {
+            schema: "public".to_string(),
+            table: "order".to_string(),
+            replica_identity: "index".to_string(),
+            index: Some("order_id_idx".to_string()),
+        },
+    )];
+
+    assert_eq!(
+        expected[0].render_sql(),
+        "ALTER TABLE \"public\".\"order\" REPLICA IDENTITY USING INDEX \"order_id_idx\";"
+    );
+    run_snapshot_diffing_test(source, target, expected);
+}
@@ -0,0 +1,181 @@
+#[cfg(test)]
+use crate::{
+    definition::load_yaml_string,
+    snapshot::{
+        changes::{
+            AlterColumnSetDataTypeChange, Change, CreateViewChange, DropViewChange,
+            ReplaceViewChange,
+        },
+        tests::utils::run_snapshot_diffing_test,
+    },
+};
+
+#[test]
+fn test_replace_view_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                view user_email:
+                    as: SELECT id FROM \"user\"",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                view user_email:
+                    as: SELECT id, email FROM \"user\"",
+    )
+    .unwrap();
+
+    let expected = vec![Change::ReplaceViewChange(ReplaceViewChange {
+        schema: "public".to_string(),
+        view: "user_email".to_string(),
+        query: "SELECT id, email FROM \"user\"".to_string(),
+    })];
+
+    assert_eq!(
+        expected[0].render_sql(),
+        "CREATE OR REPLACE VIEW \"public\".\"user_email\" AS SELECT id, email FROM \"user\";"
+    );
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_recreate_view_on_removed_column() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                view user_email:
+                    as: SELECT id, email FROM \"user\"",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                view user_email:
+                    as: SELECT email FROM \"user\"",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::DropViewChange(DropViewChange {
+            schema: "public".to_string(),
+            view: "user_email".to_string(),
+        }),
+        Change::CreateViewChange(CreateViewChange {
+            schema: "public".to_string(),
+            view: "user_email".to_string(),
+            query: "SELECT email FROM \"user\"".to_string(),
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_recreate_view_on_column_type_change() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                view user_email:
+                    as: SELECT id, email FROM \"user\"",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: varchar(255)
+                view user_email:
+                    as: SELECT id, email FROM \"user\"",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::DropViewChange(DropViewChange {
+            schema: "public".to_string(),
+            view: "user_email".to_string(),
+        }),
+        Change::AlterColumnSetDataTypeChange(AlterColumnSetDataTypeChange {
+            schema: "public".to_string(),
+            table: "user".to_string(),
+            column: "email".to_string(),
+            data_type: "varchar(255)".to_string(),
+            collation: None,
+            using: None,
+            revert_using: None,
+        }),
+        Change::CreateViewChange(CreateViewChange {
+            schema: "public".to_string(),
+            view: "user_email".to_string(),
+            query: "SELECT id, email FROM \"user\"".to_string(),
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
+
+#[test]
+fn test_recreate_view_on_changed_from() {
+    let source = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                table account:
+                    column id: uuid
+                    column email: varchar(255)
+                view user_email:
+                    as: SELECT id, email FROM \"user\"",
+    )
+    .unwrap();
+
+    let target = load_yaml_string(
+        "
+            schema public:
+                table user:
+                    column id: uuid
+                    column email: text
+                table account:
+                    column id: uuid
+                    column email: varchar(255)
+                view user_email:
+                    as: SELECT id, email FROM account",
+    )
+    .unwrap();
+
+    let expected = vec![
+        Change::DropViewChange(DropViewChange {
+            schema: "public".to_string(),
+            view: "user_email".to_string(),
+        }),
+        Change::CreateViewChange(CreateViewChange {
+            schema: "public".to_string(),
+            view: "user_email".to_string(),
+            query: "SELECT id, email FROM account".to_string(),
+        }),
+    ];
+
+    run_snapshot_diffing_test(source, target, expected);
+}
@@ -1,4 +1,4 @@
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Check {
     pub schema_name: String,
     pub table_name: String,
@@ -6,4 +6,6 @@ pub struct Check {
     pub expression: String,
     pub deferrable: bool,
     pub initially_deferred: bool,
+
+    pub comment: String,
 }
@@ -1,12 +1,41 @@
-#[derive(PartialEq, Debug)]
+use super::Grant;
+
+#[derive(PartialEq, Debug, Clone)]
 pub struct Column {
     pub schema_name: String,
     pub table_name: String,
     pub name: String,
 
     pub data_type: String,
+    /// Collation other than the data type's default, `schema.name` when not
+    /// in `pg_catalog`.
+    pub collation: Option<String>,
     pub not_null: bool,
     pub default: Option<String>,
+    /// Expression of a `GENERATED ALWAYS AS (...) STORED` column.
+    pub generated: Option<String>,
+    pub identity: Option<Identity>,
+
+    /// `plain`, `external`, `extended` or `main`, when not the data type's
+    /// default
+    pub storage: Option<String>,
+    /// `pglz` or `lz4`, when not the server's default
+    pub compression: Option<String>,
+    /// Statistics target, when not the server's default
+    pub statistics: Option<i64>,
+
+    pub comment: String,
+    pub grants: Vec<Grant>,
+    pub revokes: Vec<Grant>,
+
+    /// Expression computing the new value from the old one when the data
+    /// type changes, as in `USING (...)`. Defaults to an assignment cast.
+    pub using: Option<String>,
+    /// Data type `using` converts from. The expression is only used for a
+    /// change away from that type, so a stale one is never applied again.
+    pub using_from: Option<String>,
+    /// Expression bringing the value back when the type change is reverted.
+    pub revert_using: Option<String>,
 }
 
 impl Column {
@@ -14,3 +43,16 @@ impl Column {
         &self.name
     }
 }
+
+/// `GENERATED { ALWAYS | BY DEFAULT } AS IDENTITY` along with the options of
+/// the sequence backing it.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Identity {
+    pub always: bool,
+    pub increment: i64,
+    pub min_value: i64,
+    pub max_value: i64,
+    pub start: i64,
+    pub cache: i64,
+    pub cycle: bool,
+}
@@ -1,13 +1,14 @@
 use crate::snapshot::SnapshotError;
 
-use super::{Check, ForeignKey, PrimaryKey, Unique};
+use super::{rename_in, Check, Exclusion, ForeignKey, PrimaryKey, Unique};
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 pub enum Constraint {
     PrimaryKey(PrimaryKey),
     ForeignKey(ForeignKey),
     Unique(Unique),
     Check(Check),
+    Exclusion(Exclusion),
 }
 
 impl Constraint {
@@ -17,6 +18,7 @@ impl Constraint {
             Constraint::ForeignKey(fk) => &fk.schema_name,
             Constraint::Unique(u) => &u.schema_name,
             Constraint::Check(c) => &c.schema_name,
+            Constraint::Exclusion(x) => &x.schema_name,
         }
     }
 
@@ -26,6 +28,7 @@ impl Constraint {
             Constraint::ForeignKey(fk) => &fk.table_name,
             Constraint::Unique(u) => &u.table_name,
             Constraint::Check(c) => &c.table_name,
+            Constraint::Exclusion(x) => &x.table_name,
         }
     }
 
@@ -35,6 +38,26 @@ impl Constraint {
             Constraint::ForeignKey(fk) => &fk.name,
             Constraint::Unique(u) => &u.name,
             Constraint::Check(c) => &c.name,
+            Constraint::Exclusion(x) => &x.name,
+        }
+    }
+
+    pub fn get_comment(&self) -> &str {
+        match self {
+            Constraint::PrimaryKey(pk) => &pk.comment,
+            Constraint::ForeignKey(fk) => &fk.comment,
+            Constraint::Unique(u) => &u.comment,
+            Constraint::Check(c) => &c.comment,
+            Constraint::Exclusion(x) => &x.comment,
+        }
+    }
+    pub fn get_comment_mut(&mut self) -> &mut String {
+        match self {
+            Constraint::PrimaryKey(pk) => &mut pk.comment,
+            Constraint::ForeignKey(fk) => &mut fk.comment,
+            Constraint::Unique(u) => &mut u.comment,
+            Constraint::Check(c) => &mut c.comment,
+            Constraint::Exclusion(x) => &mut x.comment,
         }
     }
 
@@ -90,12 +113,56 @@ impl Constraint {
         }
     }
 
+    pub fn as_exclusion(&self) -> Result<&Exclusion, SnapshotError> {
+        match self {
+            Constraint::Exclusion(x) => Ok(x),
+            _ => Err(self.format_unexpected_type_error("exclusion")),
+        }
+    }
+    pub fn as_exclusion_mut(&mut self) -> Result<&mut Exclusion, SnapshotError> {
+        match self {
+            Constraint::Exclusion(x) => Ok(x),
+            _ => Err(self.format_unexpected_type_error("exclusion")),
+        }
+    }
+
+    /// Gives the constraint a new name or moves it along with its table.
+    pub fn relocate(&mut self, schema_name: &str, table_name: &str, name: &str) {
+        let (s, t, n) = match self {
+            Constraint::PrimaryKey(pk) => (&mut pk.schema_name, &mut pk.table_name, &mut pk.name),
+            Constraint::ForeignKey(fk) => (&mut fk.schema_name, &mut fk.table_name, &mut fk.name),
+            Constraint::Unique(u) => (&mut u.schema_name, &mut u.table_name, &mut u.name),
+            Constraint::Check(c) => (&mut c.schema_name, &mut c.table_name, &mut c.name),
+            Constraint::Exclusion(x) => (&mut x.schema_name, &mut x.table_name, &mut x.name),
+        };
+        *s = schema_name.to_string();
+        *t = table_name.to_string();
+        *n = name.to_string();
+    }
+
+    /// Follows a column of its own table being renamed. Check expressions are
+    /// left as they are.
+    pub fn rename_column(&mut self, column: &str, name: &str) {
+        match self {
+            Constraint::PrimaryKey(pk) => rename_in(&mut pk.columns, column, name),
+            Constraint::ForeignKey(fk) => rename_in(&mut fk.columns, column, name),
+            Constraint::Unique(u) => rename_in(&mut u.columns, column, name),
+            Constraint::Check(_) => {}
+            Constraint::Exclusion(x) => {
+                for element in x.elements.iter_mut().filter(|e| e.expression == column) {
+                    element.expression = name.to_string();
+                }
+            }
+        }
+    }
+
     fn get_type(&self) -> &'static str {
         match self {
             Constraint::PrimaryKey(_) => "primary key",
             Constraint::ForeignKey(_) => "foreign key",
             Constraint::Unique(_) => "unique",
             Constraint::Check(_) => "check",
+            Constraint::Exclusion(_) => "exclusion",
         }
     }
 
@@ -130,3 +197,8 @@ impl Into<Constraint> for Check {
         Constraint::Check(self)
     }
 }
+impl Into<Constraint> for Exclusion {
+    fn into(self) -> Constraint {
+        Constraint::Exclusion(self)
+    }
+}
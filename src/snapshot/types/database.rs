@@ -1,18 +1,28 @@
-use std::collections::HashMap;
+use indexmap::IndexMap;
 
 use crate::snapshot::error::SnapshotError;
 
-use super::Schema;
+use super::{
+    rename_entry, rename_in, Constraint, Extension, ForeignKey, Inheritance, PartitionBound, Publication,
+    Relation, Rename, Schema,
+};
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 pub struct Database {
-    pub schemas: HashMap<String, Schema>,
+    pub schemas: IndexMap<String, Schema>,
+    pub extensions: IndexMap<String, Extension>,
+    pub publications: IndexMap<String, Publication>,
+    /// Rename hints, in the order they were defined.
+    pub renames: Vec<Rename>,
 }
 
 impl Database {
     pub fn new() -> Self {
         Database {
-            schemas: HashMap::new(),
+            schemas: IndexMap::new(),
+            extensions: IndexMap::new(),
+            publications: IndexMap::new(),
+            renames: Vec::new(),
         }
     }
 
@@ -41,11 +51,86 @@ impl Database {
     }
     pub fn remove_schema(&mut self, schema: &str) -> Result<Schema, SnapshotError> {
         self.schemas
-            .remove(schema)
+            .shift_remove(schema)
             .ok_or(SnapshotError::schema_not_found(schema))
     }
 
+    pub fn iter_extensions(&self) -> impl Iterator<Item = &Extension> {
+        self.extensions.values()
+    }
+    pub fn get_extension(&self, extension: &str) -> Result<&Extension, SnapshotError> {
+        self.extensions
+            .get(extension)
+            .ok_or(SnapshotError::extension_not_found(extension))
+    }
+    pub fn get_extension_mut(&mut self, extension: &str) -> Result<&mut Extension, SnapshotError> {
+        self.extensions
+            .get_mut(extension)
+            .ok_or(SnapshotError::extension_not_found(extension))
+    }
+    pub fn has_extension(&self, extension: &str) -> bool {
+        self.extensions.contains_key(extension)
+    }
+    pub fn add_extension(&mut self, extension: Extension) -> Result<(), SnapshotError> {
+        if self.extensions.contains_key(&extension.name) {
+            return Err(SnapshotError::extension_already_exists(&extension.name));
+        }
+        self.extensions.insert(extension.name.clone(), extension);
+        return Ok(());
+    }
+    pub fn remove_extension(&mut self, extension: &str) -> Result<Extension, SnapshotError> {
+        self.extensions
+            .shift_remove(extension)
+            .ok_or(SnapshotError::extension_not_found(extension))
+    }
+
+    pub fn iter_publications(&self) -> impl Iterator<Item = &Publication> {
+        self.publications.values()
+    }
+    pub fn get_publication(&self, publication: &str) -> Result<&Publication, SnapshotError> {
+        self.publications
+            .get(publication)
+            .ok_or(SnapshotError::publication_not_found(publication))
+    }
+    pub fn get_publication_mut(
+        &mut self,
+        publication: &str,
+    ) -> Result<&mut Publication, SnapshotError> {
+        self.publications
+            .get_mut(publication)
+            .ok_or(SnapshotError::publication_not_found(publication))
+    }
+    pub fn has_publication(&self, publication: &str) -> bool {
+        self.publications.contains_key(publication)
+    }
+    pub fn add_publication(&mut self, publication: Publication) -> Result<(), SnapshotError> {
+        if self.publications.contains_key(&publication.name) {
+            return Err(SnapshotError::publication_already_exists(&publication.name));
+        }
+        self.publications
+            .insert(publication.name.clone(), publication);
+        return Ok(());
+    }
+    pub fn remove_publication(&mut self, publication: &str) -> Result<Publication, SnapshotError> {
+        self.publications
+            .shift_remove(publication)
+            .ok_or(SnapshotError::publication_not_found(publication))
+    }
+
+    pub fn iter_renames(&self) -> impl Iterator<Item = &Rename> {
+        self.renames.iter()
+    }
+    pub fn add_rename(&mut self, rename: Rename) {
+        self.renames.push(rename);
+    }
+
     pub fn merge(&mut self, other: Database) -> Result<(), SnapshotError> {
+        for (_, extension) in other.extensions {
+            self.add_extension(extension)?;
+        }
+        for (_, publication) in other.publications {
+            self.add_publication(publication)?;
+        }
         for (schema_name, schema) in other.schemas {
             if self.has_schema(&schema_name) {
                 self.schemas.get_mut(&schema_name).unwrap().merge_schema(schema)?;
@@ -53,6 +138,239 @@ impl Database {
                 self.add_schema(schema)?;
             }
         }
+        self.renames.extend(other.renames);
         Ok(())
     }
+
+    /// Applies the ownership settings of a profile before this snapshot is
+    /// compared against `source`: ownership is either left unmanaged on both
+    /// sides, or new objects default to `default_owner`.
+    pub fn apply_ownership(
+        &mut self,
+        source: &mut Database,
+        ignore_ownership: bool,
+        default_owner: Option<&str>,
+    ) {
+        if ignore_ownership {
+            source.clear_owners();
+            self.clear_owners();
+        } else if let Some(owner) = default_owner {
+            self.set_default_owner(source, owner);
+        }
+    }
+
+    /// Hands the objects `source` doesn't have yet to `owner`, unless they
+    /// name an owner of their own.
+    pub fn set_default_owner(&mut self, source: &Database, owner: &str) {
+        for schema in self.schemas.values_mut() {
+            let s = source.get_schema(&schema.name).ok();
+            if s.is_none() && schema.owner.is_none() {
+                schema.owner = Some(owner.to_string());
+            }
+            for relation in schema.relations.values_mut() {
+                let exists = s.map_or(false, |s| s.has_relation(relation.get_name()));
+                match relation.get_owner_mut() {
+                    Some(o) if !exists && o.is_none() => *o = Some(owner.to_string()),
+                    _ => {}
+                }
+            }
+            for function in schema.functions.values_mut() {
+                let exists = s.map_or(false, |s| s.has_function(&function.name));
+                if !exists && function.owner.is_none() {
+                    function.owner = Some(owner.to_string());
+                }
+            }
+            for procedure in schema.procedures.values_mut() {
+                let exists = s.map_or(false, |s| s.has_procedure(&procedure.name));
+                if !exists && procedure.owner.is_none() {
+                    procedure.owner = Some(owner.to_string());
+                }
+            }
+        }
+    }
+
+    /// Forgets the owner of every object, leaving ownership unmanaged.
+    pub fn clear_owners(&mut self) {
+        for schema in self.schemas.values_mut() {
+            schema.owner = None;
+            for relation in schema.relations.values_mut() {
+                if let Some(owner) = relation.get_owner_mut() {
+                    *owner = None;
+                }
+            }
+            for function in schema.functions.values_mut() {
+                function.owner = None;
+            }
+            for procedure in schema.procedures.values_mut() {
+                procedure.owner = None;
+            }
+        }
+    }
+
+    /// Renames a schema along with every reference to it.
+    pub fn rename_schema(&mut self, schema: &str, name: &str) -> Result<(), SnapshotError> {
+        if self.has_schema(name) {
+            return Err(SnapshotError::schema_already_exists(name));
+        }
+        rename_entry(&mut self.schemas, schema, name)
+            .ok_or(SnapshotError::schema_not_found(schema))?
+            .relocate(name);
+
+        for extension in self.extensions.values_mut() {
+            if extension.schema_name == schema {
+                extension.schema_name = name.to_string();
+            }
+        }
+        for fk in self.foreign_keys_mut() {
+            if fk.target_schema == schema {
+                fk.target_schema = name.to_string();
+            }
+        }
+        for partition_of in self.partition_bounds_mut() {
+            if partition_of.parent_schema == schema {
+                partition_of.parent_schema = name.to_string();
+            }
+        }
+        for inheritance in self.inheritances_mut() {
+            if inheritance.parent_schema == schema {
+                inheritance.parent_schema = name.to_string();
+            }
+        }
+        for publication in self.publications.values_mut() {
+            for table in publication.tables.iter_mut() {
+                if table.schema_name == schema {
+                    table.schema_name = name.to_string();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Renames a relation along with every reference to it.
+    pub fn rename_relation(
+        &mut self,
+        schema: &str,
+        relation: &str,
+        name: &str,
+    ) -> Result<(), SnapshotError> {
+        self.get_schema_mut(schema)?.rename_relation(relation, name)?;
+
+        for fk in self.foreign_keys_mut() {
+            if fk.target_schema == schema && fk.target_table == relation {
+                fk.target_table = name.to_string();
+            }
+        }
+        for partition_of in self.partition_bounds_mut() {
+            if partition_of.parent_schema == schema && partition_of.parent == relation {
+                partition_of.parent = name.to_string();
+            }
+        }
+        for inheritance in self.inheritances_mut() {
+            if inheritance.parent_schema == schema && inheritance.parent == relation {
+                inheritance.parent = name.to_string();
+            }
+        }
+        for publication in self.publications.values_mut() {
+            for table in publication.tables.iter_mut() {
+                if table.schema_name == schema && table.table_name == relation {
+                    table.table_name = name.to_string();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Renames a column along with every reference to it. Expressions only
+    /// follow when they consist of the bare column name.
+    pub fn rename_column(
+        &mut self,
+        schema: &str,
+        table: &str,
+        column: &str,
+        name: &str,
+    ) -> Result<(), SnapshotError> {
+        let s = self.get_schema_mut(schema)?;
+        s.get_relation_mut(table)?
+            .as_table_mut()?
+            .rename_column(column, name)?;
+
+        for relation in s.relations.values_mut() {
+            match relation {
+                Relation::Index(index) if index.table_name == table => {
+                    for key in index.keys.iter_mut().filter(|k| k.expression == column) {
+                        key.expression = name.to_string();
+                    }
+                    rename_in(&mut index.include, column, name);
+                }
+                Relation::Sequence(sequence)
+                    if sequence.owned_by_table.as_deref() == Some(table)
+                        && sequence.owned_by_column.as_deref() == Some(column) =>
+                {
+                    sequence.owned_by_column = Some(name.to_string());
+                }
+                _ => {}
+            }
+        }
+        for fk in self.foreign_keys_mut() {
+            if fk.target_schema == schema && fk.target_table == table {
+                rename_in(&mut fk.target_columns, column, name);
+            }
+        }
+        for publication in self.publications.values_mut() {
+            for t in publication.tables.iter_mut() {
+                if t.schema_name == schema && t.table_name == table {
+                    rename_in(&mut t.columns, column, name);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn rename_constraint(
+        &mut self,
+        schema: &str,
+        table: &str,
+        constraint: &str,
+        name: &str,
+    ) -> Result<(), SnapshotError> {
+        self.get_schema_mut(schema)?
+            .get_relation_mut(table)?
+            .as_table_mut()?
+            .rename_constraint(constraint, name)
+    }
+
+    fn foreign_keys_mut(&mut self) -> impl Iterator<Item = &mut ForeignKey> {
+        self.schemas
+            .values_mut()
+            .flat_map(|s| s.relations.values_mut())
+            .filter_map(|r| match r {
+                Relation::Table(table) => Some(table.constraints.values_mut()),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|c| match c {
+                Constraint::ForeignKey(fk) => Some(fk),
+                _ => None,
+            })
+    }
+
+    fn partition_bounds_mut(&mut self) -> impl Iterator<Item = &mut PartitionBound> {
+        self.schemas
+            .values_mut()
+            .flat_map(|s| s.relations.values_mut())
+            .filter_map(|r| match r {
+                Relation::Table(table) => table.partition_of.as_mut(),
+                _ => None,
+            })
+    }
+
+    fn inheritances_mut(&mut self) -> impl Iterator<Item = &mut Inheritance> {
+        self.schemas
+            .values_mut()
+            .flat_map(|s| s.relations.values_mut())
+            .flat_map(|r| match r {
+                Relation::Table(table) => table.inherits.iter_mut(),
+                _ => [].iter_mut(),
+            })
+    }
 }
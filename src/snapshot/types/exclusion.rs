@@ -0,0 +1,23 @@
+#[derive(Debug, PartialEq, Clone)]
+pub struct Exclusion {
+    pub schema_name: String,
+    pub table_name: String,
+    pub name: String,
+
+    /// Index method backing the constraint, e.g. `gist`.
+    pub method: String,
+    pub elements: Vec<ExclusionElement>,
+    pub predicate: Option<String>,
+
+    pub deferrable: bool,
+    pub initially_deferred: bool,
+
+    pub comment: String,
+}
+
+/// `<expression> WITH <operator>` pair of an exclusion constraint.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ExclusionElement {
+    pub expression: String,
+    pub operator: String,
+}
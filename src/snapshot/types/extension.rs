@@ -0,0 +1,13 @@
+#[derive(PartialEq, Debug, Clone)]
+pub struct Extension {
+    pub name: String,
+    pub schema_name: String,
+    /// `None` accepts whichever version is installed.
+    pub version: Option<String>,
+}
+
+impl Extension {
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+}
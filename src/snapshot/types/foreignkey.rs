@@ -1,4 +1,4 @@
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct ForeignKey {
     pub schema_name: String,
     pub table_name: String,
@@ -14,4 +14,6 @@ pub struct ForeignKey {
 
     pub deferrable: bool,
     pub initially_deferred: bool,
+
+    pub comment: String,
 }
@@ -1,4 +1,6 @@
-#[derive(Debug, PartialEq)]
+use super::Grant;
+
+#[derive(Debug, PartialEq, Clone)]
 pub struct Function {
     pub schema_name: String,
     pub name: String,
@@ -8,6 +10,11 @@ pub struct Function {
     pub returns: String,
 
     pub volatility: String,
+
+    pub owner: Option<String>,
+    pub comment: String,
+    pub grants: Vec<Grant>,
+    pub revokes: Vec<Grant>,
 }
 
 impl Function {
@@ -0,0 +1,58 @@
+/// A privilege held by a role on an object, `public` standing for every role.
+/// Objects list the privileges granted on top of the ones they start out with
+/// as `grants`, and the ones they start out with but were taken away as
+/// `revokes`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub struct Grant {
+    pub grantee: String,
+    pub privilege: String,
+}
+
+/// A privilege granted on objects of one type created later in a schema.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub struct DefaultGrant {
+    pub object_type: String,
+    pub grantee: String,
+    pub privilege: String,
+}
+
+impl Grant {
+    pub fn new(grantee: &str, privilege: &str) -> Self {
+        Self {
+            grantee: grantee.to_string(),
+            privilege: privilege.to_string(),
+        }
+    }
+}
+
+impl DefaultGrant {
+    pub fn new(object_type: &str, grantee: &str, privilege: &str) -> Self {
+        Self {
+            object_type: object_type.to_string(),
+            grantee: grantee.to_string(),
+            privilege: privilege.to_string(),
+        }
+    }
+}
+
+// Grants are kept sorted, so that snapshots compare equal whatever order they
+// were declared or granted in. Returns false if the grant was already present.
+pub fn insert_grant<T: Ord>(grants: &mut Vec<T>, grant: T) -> bool {
+    match grants.binary_search(&grant) {
+        Ok(_) => false,
+        Err(pos) => {
+            grants.insert(pos, grant);
+            true
+        }
+    }
+}
+
+pub fn remove_grant<T: Ord>(grants: &mut Vec<T>, grant: &T) -> bool {
+    match grants.binary_search(grant) {
+        Ok(pos) => {
+            grants.remove(pos);
+            true
+        }
+        Err(_) => false,
+    }
+}
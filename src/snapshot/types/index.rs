@@ -1,10 +1,32 @@
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Index {
     pub schema_name: String,
     pub name: String,
-    
+
     pub table_name: String,
     pub unique: bool,
+    pub nulls_not_distinct: bool,
     pub method: String,
-    pub key_expressions: Vec<String>,
+    pub keys: Vec<IndexKey>,
+    pub include: Vec<String>,
+    pub predicate: Option<String>,
+    /// `name=value` pairs, as found in `pg_class.reloptions`
+    pub storage_parameters: Vec<String>,
+    pub tablespace: Option<String>,
+
+    pub comment: String,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct IndexKey {
+    /// Column name, or expression
+    pub expression: String,
+    /// Collation other than the key's default one
+    pub collation: Option<String>,
+    /// Operator class other than the key's default one
+    pub opclass: Option<String>,
+    /// `ASC` or `DESC`
+    pub order: String,
+    /// `FIRST` or `LAST`
+    pub nulls: String,
 }
@@ -0,0 +1,17 @@
+#[derive(Debug, PartialEq, Clone)]
+pub struct MaterializedView {
+    pub schema_name: String,
+    pub name: String,
+    pub query: String,
+
+    pub with_data: bool,
+    pub storage_parameters: Vec<String>,
+
+    /// Whether the view should be refreshed once it and its indexes have been
+    /// created. This is a planning hint, not catalog state, so it is never
+    /// compared when diffing.
+    pub refresh: bool,
+
+    pub owner: Option<String>,
+    pub comment: String,
+}
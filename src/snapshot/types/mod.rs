@@ -2,13 +2,22 @@ mod check;
 mod column;
 mod constraint;
 mod database;
+mod exclusion;
+mod extension;
 mod foreignkey;
 mod function;
+mod grant;
 mod index;
+mod materializedview;
+mod policy;
 mod primarykey;
+mod procedure;
+mod publication;
 mod relation;
+mod rename;
 mod schema;
 mod sequence;
+mod statistics;
 mod table;
 mod unique;
 mod view;
@@ -17,13 +26,22 @@ pub use check::*;
 pub use column::*;
 pub use constraint::*;
 pub use database::*;
+pub use exclusion::*;
+pub use extension::*;
 pub use foreignkey::*;
 pub use function::*;
+pub use grant::*;
 pub use index::*;
+pub use materializedview::*;
+pub use policy::*;
 pub use primarykey::*;
+pub use procedure::*;
+pub use publication::*;
 pub use relation::*;
+pub use rename::*;
 pub use schema::*;
 pub use sequence::*;
+pub use statistics::*;
 pub use table::*;
 pub use unique::*;
 pub use view::*;
@@ -0,0 +1,21 @@
+/// Row-level security policy of a table.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Policy {
+    pub schema_name: String,
+    pub table_name: String,
+    pub name: String,
+
+    /// `ALL`, `SELECT`, `INSERT`, `UPDATE` or `DELETE`
+    pub command: String,
+    pub permissive: bool,
+    /// Roles the policy applies to, `public` standing for every role.
+    pub roles: Vec<String>,
+    pub using: Option<String>,
+    pub with_check: Option<String>,
+}
+
+impl Policy {
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+}
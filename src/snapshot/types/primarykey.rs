@@ -1,4 +1,4 @@
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 pub struct PrimaryKey {
     pub schema_name: String,
     pub table_name: String,
@@ -8,4 +8,6 @@ pub struct PrimaryKey {
 
     pub deferrable: bool,
     pub initially_deferred: bool,
+
+    pub comment: String,
 }
@@ -0,0 +1,17 @@
+#[derive(Debug, PartialEq, Clone)]
+pub struct Procedure {
+    pub schema_name: String,
+    pub name: String,
+
+    pub arguments: String,
+    pub body: String,
+    pub language: String,
+
+    pub owner: Option<String>,
+}
+
+impl Procedure {
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+}
@@ -0,0 +1,28 @@
+#[derive(PartialEq, Debug, Clone)]
+pub struct Publication {
+    pub name: String,
+    pub tables: Vec<PublicationTable>,
+}
+
+impl Publication {
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_table(&self, schema: &str, table: &str) -> Option<&PublicationTable> {
+        self.tables
+            .iter()
+            .find(|t| t.schema_name == schema && t.table_name == table)
+    }
+}
+
+/// Table published along with its column list and row filter.
+#[derive(PartialEq, Debug, Clone)]
+pub struct PublicationTable {
+    pub schema_name: String,
+    pub table_name: String,
+    /// Published columns, every column when empty.
+    pub columns: Vec<String>,
+    /// `WHERE` expression as printed by `pg_get_expr`.
+    pub row_filter: Option<String>,
+}
@@ -1,11 +1,12 @@
 use crate::snapshot::SnapshotError;
 
-use super::{Index, Sequence, Table, View};
+use super::{Index, MaterializedView, Sequence, Table, View};
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 pub enum Relation {
-    Table(Table),
+    Table(Box<Table>),
     View(View),
+    MaterializedView(MaterializedView),
     Index(Index),
     Sequence(Sequence),
 }
@@ -15,6 +16,7 @@ impl Relation {
         match self {
             Relation::Table(table) => &table.schema_name,
             Relation::View(view) => &view.schema_name,
+            Relation::MaterializedView(view) => &view.schema_name,
             Relation::Index(index) => &index.schema_name,
             Relation::Sequence(sequence) => &sequence.schema_name,
         }
@@ -24,11 +26,33 @@ impl Relation {
         match self {
             Relation::Table(table) => &table.name,
             Relation::View(view) => &view.name,
+            Relation::MaterializedView(view) => &view.name,
             Relation::Index(index) => &index.name,
             Relation::Sequence(sequence) => &sequence.name,
         }
     }
 
+    pub fn get_comment_mut(&mut self) -> &mut String {
+        match self {
+            Relation::Table(table) => &mut table.comment,
+            Relation::View(view) => &mut view.comment,
+            Relation::MaterializedView(view) => &mut view.comment,
+            Relation::Index(index) => &mut index.comment,
+            Relation::Sequence(sequence) => &mut sequence.comment,
+        }
+    }
+
+    // indexes belong to their table
+    pub fn get_owner_mut(&mut self) -> Option<&mut Option<String>> {
+        match self {
+            Relation::Table(table) => Some(&mut table.owner),
+            Relation::View(view) => Some(&mut view.owner),
+            Relation::MaterializedView(view) => Some(&mut view.owner),
+            Relation::Sequence(sequence) => Some(&mut sequence.owner),
+            Relation::Index(_) => None,
+        }
+    }
+
     pub fn as_table(&self) -> Result<&Table, SnapshotError> {
         if let Relation::Table(table) = self {
             return Ok(table);
@@ -53,6 +77,26 @@ impl Relation {
         }
         Err(self.format_unexpected_type_error("view"))
     }
+    pub fn as_materialized_view(&self) -> Result<&MaterializedView, SnapshotError> {
+        if let Relation::MaterializedView(view) = self {
+            return Ok(view);
+        }
+        Err(self.format_unexpected_type_error("materialized view"))
+    }
+    pub fn as_materialized_view_mut(&mut self) -> Result<&mut MaterializedView, SnapshotError> {
+        if let Relation::MaterializedView(view) = self {
+            return Ok(view);
+        }
+        Err(self.format_unexpected_type_error("materialized view"))
+    }
+    /// Checks that the relation can carry indexes, i.e. that it is a table or a
+    /// materialized view.
+    pub fn as_indexable(&self) -> Result<&Relation, SnapshotError> {
+        match self {
+            Relation::Table(_) | Relation::MaterializedView(_) => Ok(self),
+            _ => Err(self.format_unexpected_type_error("table or materialized view")),
+        }
+    }
     pub fn as_index(&self) -> Result<&Index, SnapshotError> {
         if let Relation::Index(index) = self {
             return Ok(index);
@@ -78,10 +122,24 @@ impl Relation {
         Err(self.format_unexpected_type_error("sequence"))
     }
 
+    /// Moves the relation to another schema or name.
+    pub fn relocate(&mut self, schema_name: &str, name: &str) {
+        let (s, n) = match self {
+            Relation::Table(table) => return table.relocate(schema_name, name),
+            Relation::View(view) => (&mut view.schema_name, &mut view.name),
+            Relation::MaterializedView(view) => (&mut view.schema_name, &mut view.name),
+            Relation::Index(index) => (&mut index.schema_name, &mut index.name),
+            Relation::Sequence(sequence) => (&mut sequence.schema_name, &mut sequence.name),
+        };
+        *s = schema_name.to_string();
+        *n = name.to_string();
+    }
+
     fn get_type(&self) -> &'static str {
         match self {
             Relation::Table(_) => "table",
             Relation::View(_) => "view",
+            Relation::MaterializedView(_) => "materialized view",
             Relation::Index(_) => "index",
             Relation::Sequence(_) => "sequence",
         }
@@ -99,7 +157,7 @@ impl Relation {
 
 impl Into<Relation> for Table {
     fn into(self) -> Relation {
-        Relation::Table(self)
+        Relation::Table(Box::new(self))
     }
 }
 impl Into<Relation> for View {
@@ -107,6 +165,11 @@ impl Into<Relation> for View {
         Relation::View(self)
     }
 }
+impl Into<Relation> for MaterializedView {
+    fn into(self) -> Relation {
+        Relation::MaterializedView(self)
+    }
+}
 impl Into<Relation> for Index {
     fn into(self) -> Relation {
         Relation::Index(self)
@@ -0,0 +1,111 @@
+use std::fmt::{Display, Formatter};
+
+use indexmap::IndexMap;
+
+/// Hint that an object of the target used to go by another name, so that
+/// diffing renames it instead of dropping and creating it again.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Rename {
+    pub target: RenameTarget,
+    pub from: String,
+    pub to: String,
+}
+
+/// Kind of the renamed object, along with where it lives under its new name.
+#[derive(PartialEq, Debug, Clone)]
+pub enum RenameTarget {
+    Schema,
+    /// Tables, views, materialized views, sequences and indexes alike
+    Relation {
+        schema: String,
+    },
+    Column {
+        schema: String,
+        table: String,
+    },
+    Constraint {
+        schema: String,
+        table: String,
+    },
+}
+
+impl Rename {
+    pub fn schema(from: &str, to: &str) -> Self {
+        Self {
+            target: RenameTarget::Schema,
+            from: from.to_string(),
+            to: to.to_string(),
+        }
+    }
+
+    pub fn relation(schema: &str, from: &str, to: &str) -> Self {
+        Self {
+            target: RenameTarget::Relation {
+                schema: schema.to_string(),
+            },
+            from: from.to_string(),
+            to: to.to_string(),
+        }
+    }
+
+    pub fn column(schema: &str, table: &str, from: &str, to: &str) -> Self {
+        Self {
+            target: RenameTarget::Column {
+                schema: schema.to_string(),
+                table: table.to_string(),
+            },
+            from: from.to_string(),
+            to: to.to_string(),
+        }
+    }
+
+    pub fn constraint(schema: &str, table: &str, from: &str, to: &str) -> Self {
+        Self {
+            target: RenameTarget::Constraint {
+                schema: schema.to_string(),
+                table: table.to_string(),
+            },
+            from: from.to_string(),
+            to: to.to_string(),
+        }
+    }
+}
+
+impl Display for Rename {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match &self.target {
+            RenameTarget::Schema => write!(f, "schema {} to {}", self.from, self.to),
+            RenameTarget::Relation { schema } => {
+                write!(f, "{}.{} to {}", schema, self.from, self.to)
+            }
+            RenameTarget::Column { schema, table } => write!(
+                f,
+                "column {} of {}.{} to {}",
+                self.from, schema, table, self.to
+            ),
+            RenameTarget::Constraint { schema, table } => write!(
+                f,
+                "constraint {} of {}.{} to {}",
+                self.from, schema, table, self.to
+            ),
+        }
+    }
+}
+
+/// Moves the entry under `from` to `to`, keeping its position.
+pub(super) fn rename_entry<'a, V>(
+    map: &'a mut IndexMap<String, V>,
+    from: &str,
+    to: &str,
+) -> Option<&'a mut V> {
+    let (index, _, value) = map.shift_remove_full(from)?;
+    map.insert(to.to_string(), value);
+    map.move_index(map.len() - 1, index);
+    map.get_index_mut(index).map(|(_, v)| v)
+}
+
+pub(super) fn rename_in(names: &mut [String], from: &str, to: &str) {
+    for name in names.iter_mut().filter(|n| *n == from) {
+        *name = to.to_string();
+    }
+}
@@ -1,22 +1,35 @@
-use std::collections::HashMap;
+use indexmap::IndexMap;
 
-use crate::snapshot::error::SnapshotError;
+use crate::snapshot::{defaults::get_default_description, error::SnapshotError};
 
-use super::{Function, Relation};
+use super::{insert_grant, rename_entry, DefaultGrant, Function, Grant, Procedure, Relation};
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 pub struct Schema {
     pub name: String,
-    pub relations: HashMap<String, Relation>,
-    pub functions: HashMap<String, Function>,
+    pub relations: IndexMap<String, Relation>,
+    pub functions: IndexMap<String, Function>,
+    pub procedures: IndexMap<String, Procedure>,
+
+    pub owner: Option<String>,
+    pub comment: String,
+    pub grants: Vec<Grant>,
+    pub revokes: Vec<Grant>,
+    pub default_grants: Vec<DefaultGrant>,
 }
 
 impl Schema {
     pub fn new(name: &str) -> Self {
         Self {
             name: name.to_string(),
-            relations: HashMap::new(),
-            functions: HashMap::new(),
+            relations: IndexMap::new(),
+            functions: IndexMap::new(),
+            procedures: IndexMap::new(),
+            owner: None,
+            comment: get_default_description(),
+            grants: Vec::new(),
+            revokes: Vec::new(),
+            default_grants: Vec::new(),
         }
     }
 
@@ -51,9 +64,38 @@ impl Schema {
     }
     pub fn remove_relation(&mut self, rel_name: &str) -> Result<Relation, SnapshotError> {
         self.relations
-            .remove(rel_name)
+            .shift_remove(rel_name)
             .ok_or(SnapshotError::relation_not_found(&self.name, rel_name))
     }
+    /// Renames a relation along with the references other relations of the
+    /// schema hold to it.
+    pub fn rename_relation(&mut self, rel_name: &str, name: &str) -> Result<(), SnapshotError> {
+        if self.has_relation(name) {
+            return Err(SnapshotError::relation_already_exists(&self.name, name));
+        }
+        rename_entry(&mut self.relations, rel_name, name)
+            .ok_or(SnapshotError::relation_not_found(&self.name, rel_name))?
+            .relocate(&self.name, name);
+
+        let renamed = |n: &mut String| {
+            if n == rel_name {
+                *n = name.to_string();
+            }
+        };
+        for relation in self.relations.values_mut() {
+            match relation {
+                Relation::Table(table) => {
+                    table.replica_identity_index.iter_mut().for_each(renamed);
+                }
+                Relation::Index(index) => renamed(&mut index.table_name),
+                Relation::Sequence(sequence) => {
+                    sequence.owned_by_table.iter_mut().for_each(renamed);
+                }
+                Relation::View(_) | Relation::MaterializedView(_) => {}
+            }
+        }
+        Ok(())
+    }
     // function
     pub fn get_function(&self, function: &str) -> Result<&Function, SnapshotError> {
         self.functions
@@ -84,17 +126,85 @@ impl Schema {
     }
     pub fn remove_function(&mut self, function: &str) -> Result<Function, SnapshotError> {
         self.functions
-            .remove(function)
+            .shift_remove(function)
             .ok_or(SnapshotError::function_not_found(&self.name, function))
     }
 
+    // procedure
+    pub fn get_procedure(&self, procedure: &str) -> Result<&Procedure, SnapshotError> {
+        self.procedures
+            .get(procedure)
+            .ok_or(SnapshotError::procedure_not_found(&self.name, procedure))
+    }
+    pub fn iter_procedures(&self) -> impl Iterator<Item = &Procedure> {
+        self.procedures.values()
+    }
+    pub fn get_procedure_mut(&mut self, procedure: &str) -> Result<&mut Procedure, SnapshotError> {
+        self.procedures
+            .get_mut(procedure)
+            .ok_or(SnapshotError::procedure_not_found(&self.name, procedure))
+    }
+    pub fn has_procedure(&self, procedure: &str) -> bool {
+        self.procedures.contains_key(procedure)
+    }
+    pub fn add_procedure(&mut self, procedure: Procedure) -> Result<(), SnapshotError> {
+        let procedure_name = procedure.get_name();
+        if self.procedures.contains_key(procedure_name) {
+            return Err(SnapshotError::procedure_already_exists(
+                &self.name,
+                procedure_name,
+            ));
+        }
+        self.procedures.insert(procedure_name.to_owned(), procedure);
+        return Ok(());
+    }
+    pub fn remove_procedure(&mut self, procedure: &str) -> Result<Procedure, SnapshotError> {
+        self.procedures
+            .shift_remove(procedure)
+            .ok_or(SnapshotError::procedure_not_found(&self.name, procedure))
+    }
+
+    /// Moves the schema, along with everything in it, to another name.
+    pub fn relocate(&mut self, name: &str) {
+        self.name = name.to_string();
+
+        for relation in self.relations.values_mut() {
+            let relation_name = relation.get_name().to_string();
+            relation.relocate(name, &relation_name);
+        }
+        for function in self.functions.values_mut() {
+            function.schema_name = name.to_string();
+        }
+        for procedure in self.procedures.values_mut() {
+            procedure.schema_name = name.to_string();
+        }
+    }
+
     pub fn merge_schema(&mut self, source: Schema) -> Result<(), SnapshotError> {
+        if source.owner.is_some() {
+            self.owner = source.owner;
+        }
+        if source.comment != get_default_description() {
+            self.comment = source.comment;
+        }
+        for grant in source.grants {
+            insert_grant(&mut self.grants, grant);
+        }
+        for revoke in source.revokes {
+            insert_grant(&mut self.revokes, revoke);
+        }
+        for grant in source.default_grants {
+            insert_grant(&mut self.default_grants, grant);
+        }
         for (_, v) in source.relations {
             self.add_relation(v)?;
         }
         for (_, v) in source.functions {
             self.add_function(v)?;
         }
+        for (_, v) in source.procedures {
+            self.add_procedure(v)?;
+        }
         Ok(())
     }
 }
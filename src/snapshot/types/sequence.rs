@@ -1,4 +1,6 @@
-#[derive(Debug, PartialEq)]
+use super::Grant;
+
+#[derive(Debug, PartialEq, Clone)]
 pub struct Sequence {
     pub schema_name: String,
     pub name: String,
@@ -12,4 +14,9 @@ pub struct Sequence {
     pub cycle: bool,
     pub owned_by_table: Option<String>,
     pub owned_by_column: Option<String>,
+
+    pub owner: Option<String>,
+    pub comment: String,
+    pub grants: Vec<Grant>,
+    pub revokes: Vec<Grant>,
 }
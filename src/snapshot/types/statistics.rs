@@ -0,0 +1,20 @@
+/// Extended statistics object collected over columns of a table.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Statistics {
+    pub schema_name: String,
+    pub table_name: String,
+    pub name: String,
+
+    /// `ndistinct`, `dependencies` and/or `mcv`
+    pub kinds: Vec<String>,
+    /// Column names, or expressions wrapped in parentheses.
+    pub expressions: Vec<String>,
+    /// Statistics target, the system default one when missing.
+    pub target: Option<i64>,
+}
+
+impl Statistics {
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+}
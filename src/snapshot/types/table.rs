@@ -1,16 +1,68 @@
-use std::collections::HashMap;
+use indexmap::IndexMap;
 
-use crate::snapshot::error::SnapshotError;
+use crate::snapshot::{
+    defaults::{get_default_description, get_table_replica_identity},
+    error::SnapshotError,
+};
 
-use super::{Column, Constraint};
+use super::{rename_entry, rename_in, Column, Constraint, Grant, Policy, Statistics};
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 pub struct Table {
     pub schema_name: String,
     pub name: String,
 
     pub columns: Vec<Column>,
-    pub constraints: HashMap<String, Constraint>,
+    pub constraints: IndexMap<String, Constraint>,
+
+    pub partition_by: Option<PartitionKey>,
+    pub partition_of: Option<PartitionBound>,
+    pub inherits: Vec<Inheritance>,
+
+    pub unlogged: bool,
+    /// `WITH (...)` options, each as `name=value`.
+    pub storage_parameters: Vec<String>,
+    pub tablespace: Option<String>,
+
+    /// `default`, `full`, `nothing` or `index`, the latter using
+    /// `replica_identity_index`.
+    pub replica_identity: String,
+    pub replica_identity_index: Option<String>,
+
+    pub row_level_security: bool,
+    pub force_row_level_security: bool,
+    pub policies: IndexMap<String, Policy>,
+
+    pub statistics: IndexMap<String, Statistics>,
+
+    pub owner: Option<String>,
+    pub comment: String,
+    pub grants: Vec<Grant>,
+    pub revokes: Vec<Grant>,
+}
+
+/// `PARTITION BY` clause of a partitioned table.
+#[derive(PartialEq, Debug, Clone)]
+pub struct PartitionKey {
+    /// `range`, `list` or `hash`
+    pub strategy: String,
+    pub key_expressions: Vec<String>,
+}
+
+/// Parent and bound of a partition.
+#[derive(PartialEq, Debug, Clone)]
+pub struct PartitionBound {
+    pub parent_schema: String,
+    pub parent: String,
+    /// Bound spec as printed by `pg_get_expr`, e.g. `FOR VALUES FROM (1) TO (10)` or `DEFAULT`.
+    pub bound: String,
+}
+
+/// Parent a table inherits from.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Inheritance {
+    pub parent_schema: String,
+    pub parent: String,
 }
 
 impl Table {
@@ -19,7 +71,23 @@ impl Table {
             schema_name: schema_name.to_string(),
             name: name.to_string(),
             columns: Vec::new(),
-            constraints: HashMap::new(),
+            constraints: IndexMap::new(),
+            partition_by: None,
+            partition_of: None,
+            inherits: Vec::new(),
+            unlogged: false,
+            storage_parameters: Vec::new(),
+            tablespace: None,
+            replica_identity: get_table_replica_identity(),
+            replica_identity_index: None,
+            row_level_security: false,
+            force_row_level_security: false,
+            policies: IndexMap::new(),
+            statistics: IndexMap::new(),
+            owner: None,
+            comment: get_default_description(),
+            grants: Vec::new(),
+            revokes: Vec::new(),
         }
     }
 
@@ -75,6 +143,30 @@ impl Table {
         Ok(self.columns.remove(index))
     }
 
+    /// Renames a column along with the constraints, partition key and
+    /// statistics naming it.
+    pub fn rename_column(&mut self, column: &str, name: &str) -> Result<(), SnapshotError> {
+        if self.has_column(name) {
+            return Err(SnapshotError::column_already_exists(
+                &self.schema_name,
+                &self.name,
+                name,
+            ));
+        }
+        self.get_column_mut(column)?.name = name.to_string();
+
+        for constraint in self.constraints.values_mut() {
+            constraint.rename_column(column, name);
+        }
+        if let Some(partition_by) = &mut self.partition_by {
+            rename_in(&mut partition_by.key_expressions, column, name);
+        }
+        for statistics in self.statistics.values_mut() {
+            rename_in(&mut statistics.expressions, column, name);
+        }
+        Ok(())
+    }
+
     // constraint
     pub fn iter_constraints(&self) -> impl Iterator<Item = &Constraint> {
         self.constraints.values()
@@ -117,11 +209,144 @@ impl Table {
     }
     pub fn remove_constraint(&mut self, constraint: &str) -> Result<Constraint, SnapshotError> {
         self.constraints
-            .remove(constraint)
+            .shift_remove(constraint)
+            .ok_or(SnapshotError::constraint_not_found(
+                &self.schema_name,
+                &self.name,
+                constraint,
+            ))
+    }
+
+    pub fn rename_constraint(&mut self, constraint: &str, name: &str) -> Result<(), SnapshotError> {
+        if self.has_constraint(name) {
+            return Err(SnapshotError::constraint_already_exists(
+                &self.schema_name,
+                &self.name,
+                name,
+            ));
+        }
+        rename_entry(&mut self.constraints, constraint, name)
             .ok_or(SnapshotError::constraint_not_found(
                 &self.schema_name,
                 &self.name,
                 constraint,
+            ))?
+            .relocate(&self.schema_name, &self.name, name);
+        Ok(())
+    }
+
+    // policy
+    pub fn iter_policies(&self) -> impl Iterator<Item = &Policy> {
+        self.policies.values()
+    }
+    pub fn get_policy(&self, policy: &str) -> Result<&Policy, SnapshotError> {
+        self.policies
+            .get(policy)
+            .ok_or(SnapshotError::policy_not_found(
+                &self.schema_name,
+                &self.name,
+                policy,
+            ))
+    }
+    pub fn get_policy_mut(&mut self, policy: &str) -> Result<&mut Policy, SnapshotError> {
+        self.policies
+            .get_mut(policy)
+            .ok_or(SnapshotError::policy_not_found(
+                &self.schema_name,
+                &self.name,
+                policy,
             ))
     }
+    pub fn add_policy(&mut self, policy: Policy) -> Result<(), SnapshotError> {
+        let policy_name = policy.get_name().to_owned();
+        if self.policies.contains_key(&policy_name) {
+            return Err(SnapshotError::policy_already_exists(
+                &self.schema_name,
+                &self.name,
+                &policy_name,
+            ));
+        }
+        self.policies.insert(policy_name, policy);
+        return Ok(());
+    }
+    pub fn remove_policy(&mut self, policy: &str) -> Result<Policy, SnapshotError> {
+        self.policies
+            .shift_remove(policy)
+            .ok_or(SnapshotError::policy_not_found(
+                &self.schema_name,
+                &self.name,
+                policy,
+            ))
+    }
+
+    // statistics
+    pub fn iter_statistics(&self) -> impl Iterator<Item = &Statistics> {
+        self.statistics.values()
+    }
+    pub fn get_statistics(&self, statistics: &str) -> Result<&Statistics, SnapshotError> {
+        self.statistics
+            .get(statistics)
+            .ok_or(SnapshotError::statistics_not_found(
+                &self.schema_name,
+                &self.name,
+                statistics,
+            ))
+    }
+    pub fn get_statistics_mut(
+        &mut self,
+        statistics: &str,
+    ) -> Result<&mut Statistics, SnapshotError> {
+        self.statistics
+            .get_mut(statistics)
+            .ok_or(SnapshotError::statistics_not_found(
+                &self.schema_name,
+                &self.name,
+                statistics,
+            ))
+    }
+    pub fn add_statistics(&mut self, statistics: Statistics) -> Result<(), SnapshotError> {
+        let statistics_name = statistics.get_name().to_owned();
+        if self.statistics.contains_key(&statistics_name) {
+            return Err(SnapshotError::statistics_already_exists(
+                &self.schema_name,
+                &self.name,
+                &statistics_name,
+            ));
+        }
+        self.statistics.insert(statistics_name, statistics);
+        return Ok(());
+    }
+    pub fn remove_statistics(&mut self, statistics: &str) -> Result<Statistics, SnapshotError> {
+        self.statistics
+            .shift_remove(statistics)
+            .ok_or(SnapshotError::statistics_not_found(
+                &self.schema_name,
+                &self.name,
+                statistics,
+            ))
+    }
+
+    /// Moves the table, along with everything it holds, to another schema or
+    /// name.
+    pub fn relocate(&mut self, schema_name: &str, name: &str) {
+        self.schema_name = schema_name.to_string();
+        self.name = name.to_string();
+
+        for column in self.columns.iter_mut() {
+            column.schema_name = schema_name.to_string();
+            column.table_name = name.to_string();
+        }
+        for constraint in self.constraints.values_mut() {
+            let constraint_name = constraint.get_name().to_string();
+            constraint.relocate(schema_name, name, &constraint_name);
+        }
+        for policy in self.policies.values_mut() {
+            policy.schema_name = schema_name.to_string();
+            policy.table_name = name.to_string();
+        }
+        for statistics in self.statistics.values_mut() {
+            statistics.schema_name = schema_name.to_string();
+            statistics.table_name = name.to_string();
+        }
+    }
 }
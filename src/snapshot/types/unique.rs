@@ -1,4 +1,4 @@
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Unique {
     pub schema_name: String,
     pub table_name: String,
@@ -8,4 +8,6 @@ pub struct Unique {
 
     pub deferrable: bool,
     pub initially_deferred: bool,
+
+    pub comment: String,
 }
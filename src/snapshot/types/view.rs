@@ -1,6 +1,9 @@
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct View {
     pub schema_name: String,
     pub name: String,
     pub query: String,
+
+    pub owner: Option<String>,
+    pub comment: String,
 }
@@ -8,6 +8,14 @@ pub fn sql_qa(schema: &str, relation: &str) -> String {
     format!("{}.{}", sql_qn(schema), sql_qn(relation))
 }
 
+// collation name, qualified when outside of pg_catalog
+pub fn sql_collation(collation: &str) -> String {
+    match collation.split_once('.') {
+        Some((schema, name)) => sql_qa(schema, name),
+        None => sql_qn(collation),
+    }
+}
+
 // quoted list
 pub fn sql_ql<I, S>(names: I) -> String
 where
@@ -32,3 +40,26 @@ where
         .collect::<Vec<_>>()
         .join(", ")
 }
+
+// dollar quoted body
+pub fn sql_dq(body: &str) -> String {
+    let mut tag = String::new();
+    while body.contains(&format!("${}$", tag)) {
+        tag.push_str("podo");
+    }
+    format!("${}${}${}$", tag, body, tag)
+}
+
+// string literal
+pub fn sql_sl(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+// role name, `public` being the pseudo-role every role is a member of
+pub fn sql_role(role: &str) -> String {
+    if role == "public" {
+        "PUBLIC".to_string()
+    } else {
+        sql_qn(role)
+    }
+}